@@ -1,21 +1,17 @@
-use crate::ToWrappedPyObject;
+use crate::contourpoint::f32_to_float;
+use crate::{convert_attr, extract_color, extract_identifier};
+use crate::{FromWrappedPyObject, ToWrappedPyObject};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 
 impl ToWrappedPyObject for norad::Anchor {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Anchor").unwrap();
         let kwargs = [
-            ("x", self.x.to_object(py)),
-            ("y", self.y.to_object(py)),
+            ("x", f32_to_float(self.x, py)),
+            ("y", f32_to_float(self.y, py)),
             ("name", self.name.to_object(py)),
-            (
-                "color",
-                self.color
-                    .as_ref()
-                    .map(|c| c.to_rgba_string())
-                    .to_object(py),
-            ),
+            ("color", self.color.to_wrapped_object(loader, py)),
             (
                 "identifier",
                 self.identifier()
@@ -27,3 +23,16 @@ impl ToWrappedPyObject for norad::Anchor {
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
+
+impl FromWrappedPyObject for norad::Anchor {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        Ok(norad::Anchor::new(
+            convert_attr(obj, "x", PyAny::extract)?,
+            convert_attr(obj, "y", PyAny::extract)?,
+            convert_attr(obj, "name", PyAny::extract)?,
+            extract_color(obj)?,
+            extract_identifier(obj)?,
+            None,
+        ))
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::SystemTime;
+
+use crate::error::LoadError;
+use crate::reader::Selection;
+
+/// The fonts parsed with `use_cache=True`, for as long as they are unchanged.
+static CACHE: LazyLock<Mutex<HashMap<Key, Entry>>> = LazyLock::new(Default::default);
+
+/// What a cached font was parsed from: the UFO, as it was on disk, and the
+/// options that change what is parsed.
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct Key {
+    path: PathBuf,
+    modified: SystemTime,
+    lenient: bool,
+    decompose: bool,
+    skip_lib: bool,
+    /// The parts of the UFO norad was asked to read: its layers, lib, groups,
+    /// kerning and features.
+    request: [bool; 5],
+    layers: Option<Vec<String>>,
+    glyphs: Option<Vec<String>>,
+}
+
+/// A cached font, along with the problems skipped while parsing it so they can
+/// be warned about again.
+#[derive(Clone)]
+pub(crate) struct Entry {
+    pub(crate) ufo: norad::Font,
+    pub(crate) skipped: Vec<LoadError>,
+}
+
+impl Key {
+    /// The key for parsing the UFO directory `ufo` as it is now.
+    pub(crate) fn new(
+        ufo: &Path,
+        lenient: bool,
+        decompose: bool,
+        skip_lib: bool,
+        request: norad::DataRequest,
+        selection: &Selection,
+    ) -> Result<Self, LoadError> {
+        let sorted = |names: &HashSet<String>| {
+            let mut names: Vec<String> = names.iter().cloned().collect();
+            names.sort();
+            names
+        };
+        Ok(Key {
+            path: ufo.canonicalize()?,
+            modified: last_modified(ufo)?,
+            lenient,
+            decompose,
+            skip_lib,
+            request: [
+                request.layers,
+                request.lib,
+                request.groups,
+                request.kerning,
+                request.features,
+            ],
+            layers: selection.layers.as_ref().map(sorted),
+            glyphs: selection.glyphs.as_ref().map(sorted),
+        })
+    }
+}
+
+/// The latest modification time of `path` or anything below it. A directory's
+/// own time only changes when entries are added or removed, so the files in it
+/// are checked too.
+fn last_modified(path: &Path) -> std::io::Result<SystemTime> {
+    let metadata = std::fs::metadata(path)?;
+    let mut modified = metadata.modified()?;
+    if metadata.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            modified = modified.max(last_modified(&entry?.path())?);
+        }
+    }
+    Ok(modified)
+}
+
+/// The cached font for `key`, if there is one.
+pub(crate) fn get(key: &Key) -> Option<Entry> {
+    CACHE.lock().unwrap().get(key).cloned()
+}
+
+/// Caches `entry` as parsed for `key`, replacing any older version of the UFO.
+pub(crate) fn insert(key: Key, entry: Entry) {
+    let mut cache = CACHE.lock().unwrap();
+    cache.retain(|k, _| k.path != key.path || k.modified == key.modified);
+    cache.insert(key, entry);
+}
+
+/// Empties the cache.
+pub(crate) fn clear() {
+    CACHE.lock().unwrap().clear();
+}
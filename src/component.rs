@@ -1,23 +1,18 @@
-use crate::ToWrappedPyObject;
+use crate::contourpoint::{coordinate_to_object, transform_to_object};
+use crate::error::{Category, LoadError};
+use crate::{convert_attr, extract_identifier, IondriveError};
+use crate::{FromWrappedPyObject, ToWrappedPyObject};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 
 impl ToWrappedPyObject for norad::Component {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Component").unwrap();
         let kwargs = [
             ("baseGlyph", self.base.to_object(py)),
             (
                 "transformation",
-                vec![
-                    self.transform.x_scale,
-                    self.transform.xy_scale,
-                    self.transform.yx_scale,
-                    self.transform.y_scale,
-                    self.transform.x_offset,
-                    self.transform.y_offset,
-                ]
-                .to_object(py),
+                transform_to_object(&self.transform, coordinate_to_object, py),
             ),
             (
                 "identifier",
@@ -30,3 +25,60 @@ impl ToWrappedPyObject for norad::Component {
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
+
+impl FromWrappedPyObject for norad::Component {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let base: String = convert_attr(obj, "baseGlyph", PyAny::extract)?;
+        Ok(norad::Component::new(
+            base.into(),
+            convert_attr(obj, "transformation", unwrap_transformation)?,
+            extract_identifier(obj)?,
+            None,
+        ))
+    }
+}
+
+/// Converts a six-value transformation sequence, as used by components and images.
+pub(crate) fn unwrap_transformation(t: &PyAny) -> PyResult<norad::AffineTransform> {
+    match t.extract::<Vec<f32>>()?[..] {
+        [x_scale, xy_scale, yx_scale, y_scale, x_offset, y_offset] => Ok(norad::AffineTransform {
+            x_scale,
+            xy_scale,
+            yx_scale,
+            y_scale,
+            x_offset,
+            y_offset,
+        }),
+        _ => Err(IondriveError::new_err("expected six transformation values")),
+    }
+}
+
+/// Checks that the base glyph of every component in `ufo` is in the layer of its
+/// composite, failing with a list of the composites referring to missing glyphs.
+pub(crate) fn check_bases(ufo: &norad::Font) -> Result<(), LoadError> {
+    let mut missing = Vec::new();
+    for layer in ufo.layers.iter() {
+        for glyph in layer.iter() {
+            for component in &glyph.components {
+                if !layer.contains_glyph(&component.base) {
+                    missing.push(format!(
+                        "'{}' uses '{}' in layer '{}'",
+                        glyph.name,
+                        component.base,
+                        layer.name()
+                    ));
+                }
+            }
+        }
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(LoadError::new(
+        Category::Structure,
+        format!(
+            "components with missing base glyphs: {}",
+            missing.join(", ")
+        ),
+    ))
+}
@@ -1,9 +1,10 @@
-use crate::ToWrappedPyObject;
+use crate::{convert_attr, extract_identifier};
+use crate::{FromWrappedPyObject, ToWrappedPyObject};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 
 impl ToWrappedPyObject for norad::Contour {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Contour").unwrap();
         let kwargs = [
             ("points", self.points.to_wrapped_object(loader, py)),
@@ -17,3 +18,13 @@ impl ToWrappedPyObject for norad::Contour {
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
+
+impl FromWrappedPyObject for norad::Contour {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        Ok(norad::Contour::new(
+            convert_attr(obj, "points", Vec::from_wrapped_object)?,
+            extract_identifier(obj)?,
+            None,
+        ))
+    }
+}
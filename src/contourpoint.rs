@@ -1,4 +1,7 @@
-use crate::{MyToPyObject, ToWrappedPyObject};
+use std::cell::Cell;
+
+use crate::{convert_attr, extract_identifier, IondriveError};
+use crate::{FromWrappedPyObject, MyToPyObject, ToWrappedPyObject};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 
@@ -15,12 +18,23 @@ impl MyToPyObject for norad::PointType {
     }
 }
 
+impl FromWrappedPyObject for norad::PointType {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        match obj.extract::<Option<&str>>()? {
+            None => Ok(norad::PointType::OffCurve),
+            Some(typ) => typ
+                .parse()
+                .map_err(|_| IondriveError::new_err(format!("unknown point type '{}'", typ))),
+        }
+    }
+}
+
 impl ToWrappedPyObject for norad::ContourPoint {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Point").unwrap();
         let kwargs = [
-            ("x", f32_to_int_or_float(self.x, py)),
-            ("y", f32_to_int_or_float(self.y, py)),
+            ("x", coordinate_to_object(self.x, py)),
+            ("y", coordinate_to_object(self.y, py)),
             ("type", self.typ.to_object(py)),
             ("smooth", self.smooth.to_object(py)),
             ("name", self.name.to_object(py)),
@@ -35,12 +49,90 @@ impl ToWrappedPyObject for norad::ContourPoint {
     }
 }
 
+impl FromWrappedPyObject for norad::ContourPoint {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        Ok(norad::ContourPoint::new(
+            convert_attr(obj, "x", PyAny::extract)?,
+            convert_attr(obj, "y", PyAny::extract)?,
+            convert_attr(obj, "type", norad::PointType::from_wrapped_object)?,
+            convert_attr(obj, "smooth", PyAny::extract)?,
+            convert_attr(obj, "name", PyAny::extract)?,
+            extract_identifier(obj)?,
+            None,
+        ))
+    }
+}
+
+thread_local! {
+    /// Whether the point and component coordinates wrapped on this thread are
+    /// given as ints where whole, as set by `integerizing`.
+    static INTEGERIZE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Puts back the previous `INTEGERIZE` when dropped, even if wrapping panics.
+struct Integerizing(bool);
+
+impl Drop for Integerizing {
+    fn drop(&mut self) {
+        INTEGERIZE.with(|cell| cell.set(self.0));
+    }
+}
+
+/// Calls `f`, with the point and component coordinates it wraps given as ints
+/// where whole if `integerize`, and as floats otherwise.
+pub(crate) fn integerizing<T>(integerize: bool, f: impl FnOnce() -> T) -> T {
+    let _previous = Integerizing(INTEGERIZE.with(|cell| cell.replace(integerize)));
+    f()
+}
+
+/// Whether point and component coordinates are being given as ints where whole.
+pub(crate) fn is_integerizing() -> bool {
+    INTEGERIZE.with(Cell::get)
+}
+
+/// Converts the coordinate `v` of a point or component as for
+/// `f32_to_int_or_float` within `integerizing(true, ...)`, and as for
+/// `f32_to_float` otherwise.
+pub(crate) fn coordinate_to_object(v: f32, py: Python) -> PyObject {
+    match is_integerizing() {
+        true => f32_to_int_or_float(v, py),
+        false => f32_to_float(v, py),
+    }
+}
+
 /// Converts value to a Python integer if it can be considered one, otherwise a
-/// Python float.
-fn f32_to_int_or_float(v: f32, py: Python) -> PyObject {
-    if (v - v.round()).abs() < std::f32::EPSILON {
+/// Python float as for `f32_to_float`.
+pub(crate) fn f32_to_int_or_float(v: f32, py: Python) -> PyObject {
+    if (v - v.round()).abs() < f32::EPSILON {
         (v as i32).to_object(py)
     } else {
-        v.to_object(py)
+        f32_to_float(v, py)
     }
 }
+
+/// Converts value to a Python float.
+///
+/// The float is the one closest to the shortest decimal that reads back as `v`,
+/// rather than `v` widened to 64 bits, so that a coordinate such as `0.1` in the
+/// source comes out as the same `0.1` that Python reads from it.
+pub(crate) fn f32_to_float(v: f32, py: Python) -> PyObject {
+    v.to_string()
+        .parse::<f64>()
+        .unwrap_or(v as f64)
+        .to_object(py)
+}
+
+/// Converts the six values of an affine transformation with `convert`.
+pub(crate) fn transform_to_object(
+    t: &norad::AffineTransform,
+    convert: fn(f32, Python) -> PyObject,
+    py: Python,
+) -> PyObject {
+    [
+        t.x_scale, t.xy_scale, t.yx_scale, t.y_scale, t.x_offset, t.y_offset,
+    ]
+    .iter()
+    .map(|v| convert(*v, py))
+    .collect::<Vec<_>>()
+    .to_object(py)
+}
@@ -0,0 +1,226 @@
+use std::path::{Path, PathBuf};
+
+use pyo3::exceptions::{PyAttributeError, PyImportError};
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyBytes, PyDict};
+
+use crate::error::LoadError;
+use crate::IondriveError;
+
+/// A directory of files in a UFO that norad does not read.
+pub(crate) struct StoreKind {
+    /// The directory name within the UFO.
+    dirname: &'static str,
+    /// The loader class the store is wrapped as.
+    cls_name: &'static str,
+    /// Whether the files must be directly in the directory, as images must.
+    flat: bool,
+}
+
+pub(crate) static DATA: StoreKind = StoreKind {
+    dirname: "data",
+    cls_name: "DataSet",
+    flat: false,
+};
+
+pub(crate) static IMAGES: StoreKind = StoreKind {
+    dirname: "images",
+    cls_name: "ImageSet",
+    flat: true,
+};
+
+impl StoreKind {
+    /// The attribute of a font object holding the store.
+    pub(crate) fn attribute(&self) -> &'static str {
+        self.dirname
+    }
+
+    /// Checks that `name` is a valid file name for this store.
+    fn check_name(&self, name: &str) -> PyResult<()> {
+        let parts: Vec<&str> = name.split('/').collect();
+        let valid = match self.flat {
+            true => parts.len() == 1,
+            false => true,
+        } && !Path::new(name).is_absolute()
+            && parts.iter().all(|part| !part.is_empty() && *part != "..");
+        match valid {
+            true => Ok(()),
+            false if self.flat => Err(IondriveError::new_err(format!(
+                "{} is not a valid {} file name: files must be directly in the {} directory",
+                name, self.dirname, self.dirname
+            ))),
+            false => Err(IondriveError::new_err(format!(
+                "{} is not a valid {} file name",
+                name, self.dirname
+            ))),
+        }
+    }
+}
+
+/// The files of one of a UFO's stores.
+pub(crate) enum Store {
+    /// The files are read from the UFO directory at this path when wrapped,
+    /// lazily if the loader supports it.
+    Ufo(PathBuf),
+    /// The files have been read already, as `(relative path, contents)` pairs.
+    Files(Vec<(String, Vec<u8>)>),
+}
+
+impl Store {
+    /// Reads all files of the store from the UFO directory `ufo`, for UFOs that
+    /// are removed again before wrapping, such as unpacked archives.
+    pub(crate) fn read(ufo: &Path, kind: &StoreKind) -> PyResult<Self> {
+        read_files(ufo, kind).map(Store::Files)
+    }
+
+    /// All files of the store, as `(relative path, contents)` pairs.
+    pub(crate) fn files(&self, kind: &StoreKind) -> PyResult<Vec<(String, Vec<u8>)>> {
+        match self {
+            Store::Ufo(ufo) => read_files(ufo, kind),
+            Store::Files(files) => Ok(files.clone()),
+        }
+    }
+
+    /// Wraps the store as an instance of the loader's class for it, such as
+    /// ufoLib2's `DataSet`.
+    ///
+    /// The files of a UFO on disk are left unread until they are accessed if the
+    /// class can read them through fontTools' `UFOReader`, as ufoLib2's can.
+    pub(crate) fn to_wrapped_object(
+        &self,
+        kind: &StoreKind,
+        loader: &PyAny,
+        py: Python,
+    ) -> PyResult<PyObject> {
+        let cls = loader.getattr(kind.cls_name)?;
+        let data = PyDict::new(py);
+        match self {
+            Store::Ufo(ufo) => {
+                let root = ufo.join(kind.dirname);
+                let names = list_files(&root, kind)?;
+                if names.is_empty() {
+                    return Ok(cls.call0()?.into());
+                }
+                if let Some(store) = read_lazily(cls, ufo, py)? {
+                    return Ok(store.into());
+                }
+                for name in names {
+                    let contents = read_file(&root.join(&name))?;
+                    data.set_item(name, PyBytes::new(py, &contents))?;
+                }
+            }
+            Store::Files(files) => {
+                for (name, contents) in files {
+                    data.set_item(name, PyBytes::new(py, contents))?;
+                }
+            }
+        }
+        Ok(cls
+            .call((), Some([("data", data)].into_py_dict(py)))?
+            .into())
+    }
+}
+
+/// Reads all files of the store `kind` from the UFO directory `ufo`.
+fn read_files(ufo: &Path, kind: &StoreKind) -> PyResult<Vec<(String, Vec<u8>)>> {
+    let root = ufo.join(kind.dirname);
+    let mut files = Vec::new();
+    for name in list_files(&root, kind)? {
+        let contents = read_file(&root.join(&name))?;
+        files.push((name, contents));
+    }
+    Ok(files)
+}
+
+/// Reads the file of a store at `path`, raising `IondriveIOError` naming it if it
+/// cannot be read.
+fn read_file(path: &Path) -> PyResult<Vec<u8>> {
+    std::fs::read(path).map_err(|e| LoadError::from(e).in_file(path).into())
+}
+
+/// Creates the store through `cls.read` with a lazy fontTools `UFOReader`, if
+/// both are available. Errors other than their being missing are raised.
+fn read_lazily<'py>(cls: &'py PyAny, ufo: &Path, py: Python<'py>) -> PyResult<Option<&'py PyAny>> {
+    let missing =
+        |e: &PyErr| e.is_instance::<PyImportError>(py) || e.is_instance::<PyAttributeError>(py);
+    let read = match cls.getattr("read") {
+        Ok(read) => read,
+        Err(e) if missing(&e) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let reader_cls = match py
+        .import("fontTools.ufoLib")
+        .and_then(|m| m.getattr("UFOReader"))
+    {
+        Ok(reader_cls) => reader_cls,
+        Err(e) if missing(&e) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let reader = reader_cls.call((ufo,), Some([("validate", false)].into_py_dict(py)))?;
+    let store = read.call((reader,), Some([("lazy", true)].into_py_dict(py)))?;
+    Ok(Some(store))
+}
+
+/// Lists the files below `root` as `/`-separated paths relative to it, in name
+/// order. A missing directory has no files.
+fn list_files(root: &Path, kind: &StoreKind) -> PyResult<Vec<String>> {
+    let mut names = Vec::new();
+    if root.is_dir() {
+        collect_files(root, "", &mut names)?;
+    }
+    names.sort();
+    for name in &names {
+        kind.check_name(name)?;
+    }
+    Ok(names)
+}
+
+fn collect_files(dir: &Path, prefix: &str, names: &mut Vec<String>) -> PyResult<()> {
+    let mut ancestors = Vec::new();
+    collect_files_below(dir, prefix, &mut ancestors, names)
+}
+
+/// Adds the files below `dir` to `names`, where `ancestors` holds the canonical
+/// paths of the directories `dir` was reached through.
+fn collect_files_below(
+    dir: &Path,
+    prefix: &str,
+    ancestors: &mut Vec<PathBuf>,
+    names: &mut Vec<String>,
+) -> PyResult<()> {
+    ancestors.push(dir.canonicalize()?);
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        // Symbolic links to directories are followed, as norad follows those to
+        // layer directories, except those back into a directory being listed,
+        // which would never end.
+        let path = entry.path();
+        if path.is_dir() {
+            if ancestors.contains(&path.canonicalize()?) {
+                continue;
+            }
+            collect_files_below(&path, &format!("{}/", name), ancestors, names)?;
+        } else {
+            names.push(name);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+
+/// Writes the files of `store`, a mapping of relative paths to bytes, into the
+/// directory for it in the UFO at `ufo`.
+pub(crate) fn write_store(store: &PyAny, ufo: &Path, kind: &StoreKind) -> PyResult<()> {
+    let root = ufo.join(kind.dirname);
+    for item in store.call_method0("items")?.iter()? {
+        let (name, contents): (String, &[u8]) = item?.extract()?;
+        kind.check_name(&name)?;
+        let path = root.join(&name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, contents)?;
+    }
+    Ok(())
+}
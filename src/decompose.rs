@@ -0,0 +1,96 @@
+use rayon::prelude::*;
+
+use norad::{AffineTransform, Contour, ContourPoint, Glyph, GlyphName, Layer};
+
+/// Replaces the components of every glyph in `ufo` with the contours of their
+/// base glyphs, which are looked up in the glyph's own layer. Components of base
+/// glyphs are resolved recursively, with their transformations combined.
+///
+/// The copied contours and points have no identifiers, as those would no longer
+/// be unique within the glyph.
+pub(crate) fn decompose(ufo: &mut norad::Font) -> Result<(), String> {
+    let layer_names: Vec<_> = ufo.layers.names().cloned().collect();
+    for layer_name in layer_names {
+        let layer = ufo.layers.get_mut(&layer_name).unwrap();
+        let composites: Vec<_> = layer.iter().filter(|g| !g.components.is_empty()).collect();
+        let decomposed = composites
+            .par_iter()
+            .map(|glyph| {
+                let mut stack = vec![glyph.name.clone()];
+                let contours =
+                    component_contours(layer, glyph, AffineTransform::default(), &mut stack)?;
+                Ok((glyph.name.clone(), contours))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        for (name, contours) in decomposed {
+            let glyph = layer.get_glyph_mut(&*name).unwrap();
+            glyph.contours.extend(contours);
+            glyph.components.clear();
+        }
+    }
+    Ok(())
+}
+
+/// Collects the contours that the components of `glyph` stand for, transformed
+/// by `transform`. `stack` holds the glyphs being decomposed, to detect cycles.
+fn component_contours(
+    layer: &Layer,
+    glyph: &Glyph,
+    transform: AffineTransform,
+    stack: &mut Vec<GlyphName>,
+) -> Result<Vec<Contour>, String> {
+    let mut contours = Vec::new();
+    for component in &glyph.components {
+        if stack.contains(&component.base) {
+            return Err(format!(
+                "glyph '{}' has a cyclic component reference to '{}'",
+                stack[0], component.base
+            ));
+        }
+        let base = layer.get_glyph(&*component.base).ok_or_else(|| {
+            format!(
+                "glyph '{}' has a component referencing missing glyph '{}'",
+                glyph.name, component.base
+            )
+        })?;
+        let combined = combine(component.transform, transform);
+        contours.extend(base.contours.iter().map(|c| transform_contour(c, combined)));
+        stack.push(base.name.clone());
+        contours.extend(component_contours(layer, base, combined, stack)?);
+        stack.pop();
+    }
+    Ok(contours)
+}
+
+/// The transformation applying `inner` and then `outer`.
+fn combine(inner: AffineTransform, outer: AffineTransform) -> AffineTransform {
+    AffineTransform {
+        x_scale: outer.x_scale * inner.x_scale + outer.yx_scale * inner.xy_scale,
+        xy_scale: outer.xy_scale * inner.x_scale + outer.y_scale * inner.xy_scale,
+        yx_scale: outer.x_scale * inner.yx_scale + outer.yx_scale * inner.y_scale,
+        y_scale: outer.xy_scale * inner.yx_scale + outer.y_scale * inner.y_scale,
+        x_offset: outer.x_scale * inner.x_offset + outer.yx_scale * inner.y_offset + outer.x_offset,
+        y_offset: outer.xy_scale * inner.x_offset + outer.y_scale * inner.y_offset + outer.y_offset,
+    }
+}
+
+fn transform_contour(contour: &Contour, transform: AffineTransform) -> Contour {
+    let points = contour
+        .points
+        .iter()
+        .map(|p| {
+            let mut point = ContourPoint::new(
+                p.x,
+                p.y,
+                p.typ.clone(),
+                p.smooth,
+                p.name.clone(),
+                None,
+                None,
+            );
+            point.transform(transform);
+            point
+        })
+        .collect();
+    Contour::new(points, None, None)
+}
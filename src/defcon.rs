@@ -0,0 +1,263 @@
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyBytes, PyDict};
+
+use crate::contourpoint::{
+    coordinate_to_object, f32_to_float, f32_to_int_or_float, transform_to_object,
+};
+use crate::data::{Store, StoreKind};
+use crate::info::info_attributes;
+use crate::{kerning_pairs, MyToPyObject};
+
+/// Builds a font with the `Font` class of `loader`, for object models such as
+/// defcon's, whose objects are created empty and filled in through their methods
+/// rather than constructed from keyword arguments.
+///
+/// Only `loader.Font` is used: the other objects are created through the font,
+/// so that they belong to it. Glyph outlines are drawn into each glyph's point
+/// pen.
+pub(crate) fn wrap_font(
+    ufo: &norad::Font,
+    layer_order: Option<&[String]>,
+    stores: &[(&Store, &StoreKind)],
+    loader: &PyAny,
+    py: Python,
+) -> PyResult<PyObject> {
+    let font = loader.getattr("Font")?.call0()?;
+    fill_layers(font.getattr("layers")?, &ufo.layers, layer_order, py)?;
+    if let Some(font_info) = &ufo.font_info {
+        let info = font.getattr("info")?;
+        for (name, value) in info_attributes(font_info, py) {
+            if !value.is_none(py) {
+                info.setattr(name, value)?;
+            }
+        }
+        for guideline in font_info.guidelines.iter().flatten() {
+            info.call_method1("appendGuideline", (guideline_dict(guideline, py),))?;
+        }
+    }
+    if let Some(groups) = &ufo.groups {
+        font.getattr("groups")?
+            .call_method1("update", (groups.to_object(py),))?;
+    }
+    font.getattr("kerning")?
+        .call_method1("update", (kerning_pairs(ufo.kerning.as_ref(), py),))?;
+    if let Some(features) = &ufo.features {
+        font.getattr("features")?.setattr("text", features)?;
+    }
+    // The lib goes last, so that its glyph order replaces the one the font
+    // keeps track of as glyphs are added.
+    font.getattr("lib")?
+        .call_method1("update", (ufo.lib.to_object(py),))?;
+    for (store, kind) in stores {
+        let target = font.getattr(kind.attribute())?;
+        for (name, contents) in store.files(kind)? {
+            target.set_item(name, PyBytes::new(py, &contents))?;
+        }
+    }
+    Ok(font.into())
+}
+
+/// Adds `layers` to the font's empty `layer_set`, reusing and renaming the layer
+/// the font starts out with as the default layer, and orders them by the
+/// `layer_order` of their names if given.
+fn fill_layers(
+    layer_set: &PyAny,
+    layers: &norad::LayerSet,
+    layer_order: Option<&[String]>,
+    py: Python,
+) -> PyResult<()> {
+    let default_name = layers.default_layer().name();
+    for layer in layers.iter() {
+        let name: &str = layer.name().as_ref();
+        let existing = match layer.name() == default_name {
+            true => Some(layer_set.getattr("defaultLayer")?).filter(|l| !l.is_none()),
+            false => None,
+        };
+        let wrapped = match existing {
+            Some(existing) => {
+                if existing.getattr("name")?.extract::<&str>()? != name {
+                    existing.setattr("name", name)?;
+                }
+                existing
+            }
+            None => {
+                let created = layer_set.call_method1("newLayer", (name,))?;
+                if layer.name() == default_name {
+                    layer_set.setattr("defaultLayer", created)?;
+                }
+                created
+            }
+        };
+        fill_layer(wrapped, layer, py)?;
+    }
+    let order: Vec<&str> = match layer_order {
+        Some(order) => order.iter().map(String::as_str).collect(),
+        None => layers.iter().map(|l| l.name().as_ref()).collect(),
+    };
+    layer_set.setattr("layerOrder", order)
+}
+
+fn fill_layer(wrapped: &PyAny, layer: &norad::Layer, py: Python) -> PyResult<()> {
+    if let Some(color) = &layer.color {
+        wrapped.setattr("color", color.to_rgba_string())?;
+    }
+    wrapped
+        .getattr("lib")?
+        .call_method1("update", (layer.lib.to_object(py),))?;
+    for glyph in layer.iter() {
+        wrapped.call_method1("newGlyph", (&*glyph.name,))?;
+        fill_glyph(wrapped.get_item(&*glyph.name)?, glyph, py)?;
+    }
+    Ok(())
+}
+
+fn fill_glyph(wrapped: &PyAny, glyph: &norad::Glyph, py: Python) -> PyResult<()> {
+    wrapped.setattr("width", f32_to_int_or_float(glyph.width, py))?;
+    wrapped.setattr("height", f32_to_int_or_float(glyph.height, py))?;
+    let unicodes: Vec<u32> = glyph.codepoints.iter().map(|c| *c as u32).collect();
+    wrapped.setattr("unicodes", unicodes)?;
+    if let Some(note) = &glyph.note {
+        wrapped.setattr("note", note)?;
+    }
+    wrapped
+        .getattr("lib")?
+        .call_method1("update", (crate::glyph_lib(glyph).to_object(py),))?;
+    for anchor in &glyph.anchors {
+        wrapped.call_method1("appendAnchor", (anchor_dict(anchor, py),))?;
+    }
+    for guideline in &glyph.guidelines {
+        wrapped.call_method1("appendGuideline", (guideline_dict(guideline, py),))?;
+    }
+    if let Some(image) = &glyph.image {
+        wrapped.setattr("image", image_dict(image, py))?;
+    }
+    draw_outline(wrapped.call_method0("getPointPen")?, glyph, py)
+}
+
+/// Draws the contours and components of `glyph` into a point pen.
+fn draw_outline(pen: &PyAny, glyph: &norad::Glyph, py: Python) -> PyResult<()> {
+    for contour in &glyph.contours {
+        let kwargs = identifier_kwargs(contour.identifier(), py);
+        pen.call_method("beginPath", (), Some(kwargs))?;
+        for point in &contour.points {
+            let kwargs = identifier_kwargs(point.identifier(), py);
+            kwargs.set_item("segmentType", point.typ.to_object(py))?;
+            kwargs.set_item("smooth", point.smooth)?;
+            kwargs.set_item("name", &point.name)?;
+            let pt = (
+                coordinate_to_object(point.x, py),
+                coordinate_to_object(point.y, py),
+            );
+            pen.call_method("addPoint", (pt,), Some(kwargs))?;
+        }
+        pen.call_method0("endPath")?;
+    }
+    for component in &glyph.components {
+        let transformation = transform_to_object(&component.transform, coordinate_to_object, py);
+        let kwargs = identifier_kwargs(component.identifier(), py);
+        pen.call_method(
+            "addComponent",
+            (&*component.base, transformation),
+            Some(kwargs),
+        )?;
+    }
+    Ok(())
+}
+
+/// The anchor as a dictionary of its GLIF attributes, without those not set.
+pub(crate) fn anchor_dict<'py>(anchor: &norad::Anchor, py: Python<'py>) -> &'py PyDict {
+    without_none(
+        py,
+        [
+            ("x", f32_to_float(anchor.x, py)),
+            ("y", f32_to_float(anchor.y, py)),
+            ("name", anchor.name.to_object(py)),
+            (
+                "color",
+                anchor
+                    .color
+                    .as_ref()
+                    .map(|c| c.to_rgba_string())
+                    .to_object(py),
+            ),
+            (
+                "identifier",
+                anchor.identifier().map(|i| i.as_str()).to_object(py),
+            ),
+        ],
+    )
+}
+
+/// The guideline as a dictionary of its GLIF attributes, without those not set.
+pub(crate) fn guideline_dict<'py>(guideline: &norad::Guideline, py: Python<'py>) -> &'py PyDict {
+    let (x, y, angle) = match guideline.line {
+        norad::Line::Vertical(x) => (Some(x), None, None),
+        norad::Line::Horizontal(y) => (None, Some(y), None),
+        norad::Line::Angle { x, y, degrees } => (Some(x), Some(y), Some(degrees)),
+    };
+    without_none(
+        py,
+        [
+            ("x", x.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("y", y.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("angle", angle.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("name", guideline.name.to_object(py)),
+            (
+                "color",
+                guideline
+                    .color
+                    .as_ref()
+                    .map(|c| c.to_rgba_string())
+                    .to_object(py),
+            ),
+            (
+                "identifier",
+                guideline.identifier().map(|i| i.as_str()).to_object(py),
+            ),
+        ],
+    )
+}
+
+/// The image as a dictionary of its GLIF attributes, without those not set.
+pub(crate) fn image_dict<'py>(image: &norad::Image, py: Python<'py>) -> &'py PyDict {
+    without_none(
+        py,
+        [
+            ("fileName", image.file_name.to_object(py)),
+            ("xScale", f32_to_int_or_float(image.transform.x_scale, py)),
+            ("xyScale", f32_to_int_or_float(image.transform.xy_scale, py)),
+            ("yxScale", f32_to_int_or_float(image.transform.yx_scale, py)),
+            ("yScale", f32_to_int_or_float(image.transform.y_scale, py)),
+            ("xOffset", f32_to_int_or_float(image.transform.x_offset, py)),
+            ("yOffset", f32_to_int_or_float(image.transform.y_offset, py)),
+            (
+                "color",
+                image
+                    .color
+                    .as_ref()
+                    .map(|c| c.to_rgba_string())
+                    .to_object(py),
+            ),
+        ],
+    )
+}
+
+/// The `identifier` keyword argument of a point pen call, if there is one.
+fn identifier_kwargs<'py>(identifier: Option<&norad::Identifier>, py: Python<'py>) -> &'py PyDict {
+    let kwargs = PyDict::new(py);
+    if let Some(identifier) = identifier {
+        kwargs.set_item("identifier", identifier.as_str()).unwrap();
+    }
+    kwargs
+}
+
+/// A dictionary of the `items` that are not `None`.
+pub(crate) fn without_none<'py, const N: usize>(
+    py: Python<'py>,
+    items: [(&str, PyObject); N],
+) -> &'py PyDict {
+    Vec::from(items)
+        .into_iter()
+        .filter(|(_, value)| !value.is_none(py))
+        .into_py_dict(py)
+}
@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+use pyo3::prelude::*;
+
+use crate::error::{Category, LoadError};
+use crate::IondriveError;
+
+/// Reads the designspace file at `path` as a fontTools `DesignSpaceDocument`.
+pub(crate) fn read_document<'py>(py: Python<'py>, path: &Path) -> PyResult<&'py PyAny> {
+    let cls = py
+        .import("fontTools.designspaceLib")
+        .and_then(|m| m.getattr("DesignSpaceDocument"))
+        .map_err(|_| IondriveError::new_err("loading a designspace needs fontTools"))?;
+    cls.call_method1("fromfile", (path,)).map_err(|e| {
+        LoadError::new(
+            Category::Parse,
+            format!("{}: {}", path.display(), e.pvalue(py)),
+        )
+        .into()
+    })
+}
+
+/// Resolves the UFO of each source in `document`, relative to the directory of
+/// the designspace file at `path`. Raises `IondriveIOError` naming the source if
+/// its UFO does not exist.
+pub(crate) fn source_paths(document: &PyAny, path: &Path) -> PyResult<Vec<PathBuf>> {
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut paths = Vec::new();
+    for (index, source) in document.getattr("sources")?.iter()?.enumerate() {
+        let source = source?;
+        let filename: Option<String> = source.getattr("filename")?.extract()?;
+        let resolved = match filename {
+            Some(filename) => Some(dir.join(filename)),
+            None => source.getattr("path")?.extract::<Option<PathBuf>>()?,
+        };
+        let missing = match &resolved {
+            Some(resolved) if resolved.exists() => None,
+            Some(resolved) => Some(format!("refers to missing UFO {}", resolved.display())),
+            None => Some("has no filename".to_string()),
+        };
+        if let Some(missing) = missing {
+            return Err(LoadError::new(
+                Category::Io,
+                format!(
+                    "{}: {} {}",
+                    path.display(),
+                    describe_source(source, index)?,
+                    missing
+                ),
+            )
+            .with_kind("MissingUfoDir")
+            .into());
+        }
+        paths.extend(resolved);
+    }
+    Ok(paths)
+}
+
+/// Names the `<source>` element for `source`, the `index`th in the document.
+fn describe_source(source: &PyAny, index: usize) -> PyResult<String> {
+    let name: Option<String> = source.getattr("name")?.extract()?;
+    Ok(match name {
+        Some(name) => format!("<source name=\"{}\">", name),
+        None => format!("<source> number {}", index + 1),
+    })
+}
@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use pyo3::basic::CompareOp;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::contourpoint::f32_to_int_or_float;
+use crate::info::info_attributes;
+use crate::{load_font, LoadOptions};
+
+/// Compares the UFOs at `a` and `b`, each a directory or a `.ufoz` archive, and
+/// describes what changed from `a` to `b` in plain Python values.
+pub(crate) fn diff(py: Python, a: &Path, b: &Path) -> PyResult<PyObject> {
+    let options = LoadOptions::default();
+    let a = load_font(py, a, norad::DataRequest::all(), &options)?.ufo;
+    let b = load_font(py, b, norad::DataRequest::all(), &options)?.ufo;
+    let report = PyDict::new(py);
+    report.set_item("layers", layers(&a.layers, &b.layers, py)?)?;
+    report.set_item("glyphs", glyphs(&a.layers, &b.layers, py)?)?;
+    report.set_item("info", info(&a, &b, py)?)?;
+    report.set_item("kerning", kerning(&a, &b, py)?)?;
+    Ok(report.into())
+}
+
+/// The names of the layers added and removed.
+fn layers<'py>(a: &norad::LayerSet, b: &norad::LayerSet, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let names = |layers: &norad::LayerSet| -> Vec<String> {
+        layers.iter().map(|l| l.name().to_string()).collect()
+    };
+    let (a, b) = (names(a), names(b));
+    let changes = PyDict::new(py);
+    changes.set_item(
+        "added",
+        b.iter().filter(|n| !a.contains(n)).collect::<Vec<_>>(),
+    )?;
+    changes.set_item(
+        "removed",
+        a.iter().filter(|n| !b.contains(n)).collect::<Vec<_>>(),
+    )?;
+    Ok(changes)
+}
+
+/// The glyphs added, removed and changed in each layer that both fonts have and
+/// that has any, by layer name. Glyph names are sorted.
+fn glyphs<'py>(a: &norad::LayerSet, b: &norad::LayerSet, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let by_layer = PyDict::new(py);
+    for layer_a in a.iter() {
+        let layer_b = match b.get(layer_a.name()) {
+            Some(layer) => layer,
+            None => continue,
+        };
+        let names = |layer: &norad::Layer| -> BTreeSet<String> {
+            layer.iter().map(|g| g.name.to_string()).collect()
+        };
+        let (names_a, names_b) = (names(layer_a), names(layer_b));
+        let added: Vec<&String> = names_b.difference(&names_a).collect();
+        let removed: Vec<&String> = names_a.difference(&names_b).collect();
+        let changed: Vec<&String> = names_a
+            .intersection(&names_b)
+            .filter(|name| layer_a.get_glyph(name.as_str()) != layer_b.get_glyph(name.as_str()))
+            .collect();
+        if added.is_empty() && removed.is_empty() && changed.is_empty() {
+            continue;
+        }
+        let changes = PyDict::new(py);
+        changes.set_item("added", added)?;
+        changes.set_item("removed", removed)?;
+        changes.set_item("changed", changed)?;
+        by_layer.set_item(layer_a.name().as_ref(), changes)?;
+    }
+    Ok(by_layer)
+}
+
+/// The `[old, new]` values of the info attributes that changed, by attribute
+/// name. Attributes that are not set are `None`.
+fn info<'py>(a: &norad::Font, b: &norad::Font, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let empty = norad::FontInfo::default();
+    let values_a = info_attributes(a.font_info.as_ref().unwrap_or(&empty), py);
+    let values_b = info_attributes(b.font_info.as_ref().unwrap_or(&empty), py);
+    let changes = PyDict::new(py);
+    for ((name, old), (_, new)) in values_a.into_iter().zip(values_b) {
+        let same = old.as_ref(py).rich_compare(new.as_ref(py), CompareOp::Eq)?;
+        if !same.is_true()? {
+            changes.set_item(name, vec![old, new])?;
+        }
+    }
+    Ok(changes)
+}
+
+/// The kerning pairs added and removed, as `[first, second, value]`, and those
+/// whose value changed, as `[first, second, old, new]`, sorted by pair.
+fn kerning<'py>(a: &norad::Font, b: &norad::Font, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let (pairs_a, pairs_b) = (pairs(a.kerning.as_ref()), pairs(b.kerning.as_ref()));
+    // Lists rather than tuples, so that the report is the same after a JSON
+    // round trip.
+    let entry = |(first, second): (&str, &str), values: &[f32]| -> PyObject {
+        let mut entry = vec![first.to_object(py), second.to_object(py)];
+        entry.extend(values.iter().map(|v| f32_to_int_or_float(*v, py)));
+        entry.to_object(py)
+    };
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for (&pair, &new) in &pairs_b {
+        match pairs_a.get(&pair) {
+            None => added.push(entry(pair, &[new])),
+            Some(&old) if old != new => changed.push(entry(pair, &[old, new])),
+            Some(_) => (),
+        }
+    }
+    let removed: Vec<PyObject> = pairs_a
+        .iter()
+        .filter(|(pair, _)| !pairs_b.contains_key(pair))
+        .map(|(&pair, &old)| entry(pair, &[old]))
+        .collect();
+    let changes = PyDict::new(py);
+    changes.set_item("added", added)?;
+    changes.set_item("removed", removed)?;
+    changes.set_item("changed", changed)?;
+    Ok(changes)
+}
+
+/// The kerning values by `(first, second)` pair.
+fn pairs(kerning: Option<&norad::Kerning>) -> BTreeMap<(&str, &str), f32> {
+    kerning
+        .into_iter()
+        .flatten()
+        .flat_map(|(first, seconds)| {
+            seconds
+                .iter()
+                .map(move |(second, value)| ((first.as_str(), second.as_str()), *value))
+        })
+        .collect()
+}
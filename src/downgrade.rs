@@ -0,0 +1,181 @@
+use std::path::Path;
+
+use norad::{Anchor, Component, Contour, ContourPoint, Glyph, PointType};
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::data;
+use crate::error::LoadError;
+use crate::reader::{self, DEFAULT_GLYPHS_DIRNAME, LAYER_CONTENTS_FILE, METAINFO_FILE};
+use crate::IondriveError;
+
+/// Converts `ufo` to what UFO 2 can hold, describing the first part of it that
+/// UFO 2 cannot.
+///
+/// The anchors of the glyphs become contours of a single named move point, as
+/// ufoLib writes them in GLIF 1, and the identifiers of their contours, points
+/// and components are dropped. As norad writes only GLIF 2, the glyphs are
+/// made GLIF 1 glyphs by `finish_format_2`. Layers besides the default
+/// layer, layer colors and libs, guidelines, images, object libs, anchor colors
+/// and the fontinfo keys new in UFO 3 have no place in UFO 2.
+pub(crate) fn to_format_2(ufo: &mut norad::Font) -> Result<(), String> {
+    let default_name = ufo.layers.default_layer().name().clone();
+    if let Some(layer) = ufo.layers.iter().find(|l| *l.name() != default_name) {
+        return Err(format!("layer '{}' is not the default layer", layer.name()));
+    }
+    let layer = ufo.layers.default_layer_mut();
+    if layer.color.is_some() {
+        return Err("the default layer has a color".to_string());
+    }
+    if !layer.lib.is_empty() {
+        return Err("the default layer has a lib".to_string());
+    }
+    if let Some(key) = ufo.font_info.as_ref().and_then(format_3_info_key) {
+        return Err(format!("fontinfo has {}", key));
+    }
+    for glyph in layer.iter_mut() {
+        downgrade_glyph(glyph).map_err(|what| format!("glyph '{}' has {}", glyph.name, what))?;
+    }
+    Ok(())
+}
+
+/// The first key set in `info` that UFO 2 does not have.
+fn format_3_info_key(info: &norad::FontInfo) -> Option<&'static str> {
+    let keys = [
+        ("guidelines", info.guidelines.is_some()),
+        (
+            "openTypeGaspRangeRecords",
+            info.open_type_gasp_range_records.is_some(),
+        ),
+        ("openTypeNameRecords", info.open_type_name_records.is_some()),
+        (
+            "openTypeNameWWSFamilyName",
+            info.open_type_name_wws_family_name.is_some(),
+        ),
+        (
+            "openTypeNameWWSSubfamilyName",
+            info.open_type_name_wws_subfamily_name.is_some(),
+        ),
+        ("woffMajorVersion", info.woff_major_version.is_some()),
+        ("woffMinorVersion", info.woff_minor_version.is_some()),
+        (
+            "woffMetadataUniqueID",
+            info.woff_metadata_unique_id.is_some(),
+        ),
+        ("woffMetadataVendor", info.woff_metadata_vendor.is_some()),
+        ("woffMetadataCredits", info.woff_metadata_credits.is_some()),
+        (
+            "woffMetadataDescription",
+            info.woff_metadata_description.is_some(),
+        ),
+        ("woffMetadataLicense", info.woff_metadata_license.is_some()),
+        (
+            "woffMetadataCopyright",
+            info.woff_metadata_copyright.is_some(),
+        ),
+        (
+            "woffMetadataTrademark",
+            info.woff_metadata_trademark.is_some(),
+        ),
+        (
+            "woffMetadataLicensee",
+            info.woff_metadata_licensee.is_some(),
+        ),
+        (
+            "woffMetadataExtensions",
+            info.woff_metadata_extensions.is_some(),
+        ),
+    ];
+    keys.iter().find(|(_, set)| *set).map(|(key, _)| *key)
+}
+
+/// Leaves `glyph` with only what GLIF 1 can hold, or says what of it GLIF 1
+/// cannot hold.
+fn downgrade_glyph(glyph: &mut Glyph) -> Result<(), &'static str> {
+    if !glyph.guidelines.is_empty() {
+        return Err("guidelines");
+    }
+    if glyph.image.is_some() {
+        return Err("an image");
+    }
+    if glyph.anchors.iter().any(|a| a.color.is_some()) {
+        return Err("an anchor color");
+    }
+    let has_object_lib = glyph.anchors.iter().any(|a| a.lib().is_some())
+        || glyph.components.iter().any(|c| c.lib().is_some())
+        || glyph
+            .contours
+            .iter()
+            .any(|c| c.lib().is_some() || c.points.iter().any(|p| p.lib().is_some()));
+    if has_object_lib {
+        return Err("an object lib");
+    }
+    let contours = glyph.contours.drain(..).map(|contour| {
+        let points = contour
+            .points
+            .into_iter()
+            .map(|p| ContourPoint::new(p.x, p.y, p.typ, p.smooth, p.name, None, None));
+        Contour::new(points.collect(), None, None)
+    });
+    let anchors = glyph.anchors.drain(..).map(anchor_contour);
+    glyph.contours = contours.chain(anchors).collect();
+    for component in &mut glyph.components {
+        *component = Component::new(component.base.clone(), component.transform, None, None);
+    }
+    Ok(())
+}
+
+/// The single point contour that stands for `anchor` in a GLIF 1 glyph.
+fn anchor_contour(anchor: Anchor) -> Contour {
+    let point = ContourPoint::new(
+        anchor.x,
+        anchor.y,
+        PointType::Move,
+        false,
+        anchor.name,
+        None,
+        None,
+    );
+    Contour::new(vec![point], None, None)
+}
+
+/// Checks that the data and images stores of `font` are empty, as UFO 2 has no
+/// directories for them.
+pub(crate) fn check_stores(font: &PyAny) -> PyResult<()> {
+    for kind in [&data::DATA, &data::IMAGES] {
+        if let Ok(store) = font.getattr(kind.attribute()) {
+            if store.call_method0("items")?.iter()?.next().is_some() {
+                return Err(IondriveError::new_err(format!(
+                    "cannot save as UFO 2: UFO 2 has no {} directory",
+                    kind.attribute()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Makes the UFO 3 directory norad wrote at `ufo` from a font converted by
+/// `to_format_2` a UFO 2 directory, with a metainfo.plist of format version 2,
+/// no layercontents.plist and GLIF 1 files.
+pub(crate) fn finish_format_2(ufo: &Path) -> Result<(), LoadError> {
+    let path = ufo.join(METAINFO_FILE);
+    let mut metainfo = plist::Value::from_file(&path)?;
+    if let Some(dict) = metainfo.as_dictionary_mut() {
+        dict.insert("formatVersion".to_string(), 2.into());
+        dict.remove("formatVersionMinor");
+    }
+    metainfo.to_file_xml(&path)?;
+    std::fs::remove_file(ufo.join(LAYER_CONTENTS_FILE))?;
+    let dir = ufo.join(DEFAULT_GLYPHS_DIRNAME);
+    reader::read_glyph_contents(&dir)?
+        .into_par_iter()
+        .try_for_each(|(_, file_name)| {
+            let path = dir.join(file_name);
+            let glif = std::fs::read_to_string(&path)?;
+            // norad writes the format last in the glyph element, after the
+            // escaped name, so the first match is the glyph's.
+            let glif = glif.replacen(" format=\"2\">", " format=\"1\">", 1);
+            std::fs::write(&path, glif).map_err(LoadError::from)
+        })
+}
@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+
+use pyo3::prelude::*;
+
+use crate::error::{Category, LoadError};
+
+/// The plists at the root of a UFO that norad reads along with the font, which
+/// are read as latin-1 when they are not UTF-8.
+static FONT_PLISTS: &[&str] = &[
+    "metainfo.plist",
+    "fontinfo.plist",
+    "lib.plist",
+    "groups.plist",
+    "kerning.plist",
+];
+
+/// The other files at the root of a UFO that norad reads along with the font.
+static OTHER_FONT_FILES: &[&str] = &["features.fea"];
+
+/// A copy of the font-level files of a UFO in a temporary directory, with the
+/// plists that are not UTF-8 read as latin-1 and written as UTF-8. The directory
+/// is removed when this is dropped.
+pub(crate) struct Transcoded {
+    dir: PathBuf,
+    /// The names of the plists that were read as latin-1.
+    pub(crate) files: Vec<&'static str>,
+}
+
+impl Transcoded {
+    /// The directory holding the copy, to be read in place of the UFO.
+    pub(crate) fn path(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Drop for Transcoded {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Copies the font-level files of the UFO at `ufo` with its plists in UTF-8, if
+/// any of them is in another encoding. `None` if all are UTF-8 already.
+///
+/// A plist that is neither UTF-8, with or without a byte order mark, nor UTF-16
+/// with one, nor a binary plist is taken to be latin-1, which any bytes decode
+/// as.
+pub(crate) fn transcode_font_plists(ufo: &Path) -> Result<Option<Transcoded>, LoadError> {
+    let latin1: Vec<&'static str> = FONT_PLISTS
+        .iter()
+        .copied()
+        .filter(|name| match std::fs::read(ufo.join(name)) {
+            Ok(data) => is_latin1(&data),
+            Err(_) => false,
+        })
+        .collect();
+    if latin1.is_empty() {
+        return Ok(None);
+    }
+    // The UFO is parsed without the GIL, which is only taken back to make the
+    // directory.
+    let dir = Python::with_gil(|py| {
+        let mkdtemp = || py.import("tempfile")?.call_method0("mkdtemp")?.extract();
+        mkdtemp().map_err(|e: PyErr| LoadError::new(Category::Io, e.pvalue(py).to_string()))
+    })?;
+    let transcoded = Transcoded { dir, files: latin1 };
+    for name in FONT_PLISTS.iter().chain(OTHER_FONT_FILES) {
+        let source = ufo.join(name);
+        if !source.exists() {
+            continue;
+        }
+        let target = transcoded.dir.join(name);
+        match transcoded.files.contains(name) {
+            true => std::fs::write(target, latin1_to_utf8(&std::fs::read(source)?))?,
+            false => std::fs::copy(source, target).map(|_| ())?,
+        }
+    }
+    Ok(Some(transcoded))
+}
+
+/// Whether `data`, the contents of a plist, is in neither of the encodings the
+/// plist parser reads.
+fn is_latin1(data: &[u8]) -> bool {
+    let utf16 = data.starts_with(b"\xff\xfe") || data.starts_with(b"\xfe\xff");
+    !data.starts_with(b"bplist") && !utf16 && std::str::from_utf8(data).is_err()
+}
+
+/// Decodes `data` as latin-1 and encodes it as UTF-8.
+fn latin1_to_utf8(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .map(|&b| b as char)
+        .collect::<String>()
+        .into_bytes()
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{IondriveError, IondriveIOError, IondriveParseError, IondriveStructureError};
+
+/// The kind of a load failure, which decides the exception it is raised as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Category {
+    /// A file or directory is missing or cannot be read.
+    Io,
+    /// A file is not well-formed XML or plist, or not valid GLIF.
+    Parse,
+    /// The files parse, but what they describe is not a valid font.
+    Structure,
+    /// Anything else, raised as `IondriveError` itself.
+    Other,
+}
+
+/// A failure to load a font, kept apart from Python so it can be reported from
+/// outside the parsing threads.
+///
+/// It is displayed as `file:line: message` when the failing file and line are
+/// known.
+#[derive(Clone, Debug)]
+pub(crate) struct LoadError {
+    pub(crate) category: Category,
+    message: String,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    /// The `kind` of the exception raised for it, naming the norad error it
+    /// comes from or is like.
+    pub(crate) kind: Option<&'static str>,
+}
+
+impl LoadError {
+    pub(crate) fn new(category: Category, message: impl Into<String>) -> Self {
+        LoadError {
+            category,
+            message: message.into(),
+            file: None,
+            line: None,
+            kind: None,
+        }
+    }
+
+    /// The same error, raised with `kind` as its `kind`.
+    pub(crate) fn with_kind(mut self, kind: &'static str) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// The same error with its message prefixed by `context`.
+    pub(crate) fn context(self, context: impl fmt::Display) -> Self {
+        LoadError {
+            kind: self.kind,
+            ..LoadError::new(self.category, format!("{}: {}", context, self))
+        }
+    }
+
+    /// The same error, naming `file` as the failing file unless it names one
+    /// already.
+    pub(crate) fn in_file(mut self, file: &Path) -> Self {
+        if self.file.is_none() {
+            self.file = Some(file.to_path_buf());
+        }
+        self
+    }
+
+    /// The same error with the failing file named relative to `root`, such as the
+    /// UFO it is in.
+    pub(crate) fn relative_to(mut self, root: &Path) -> Self {
+        if let Some(relative) = self.file.as_ref().and_then(|f| f.strip_prefix(root).ok()) {
+            self.file = Some(relative.to_path_buf());
+        }
+        self
+    }
+
+    /// The same error without the failing file, for files that only exist
+    /// temporarily. The line is kept.
+    pub(crate) fn without_file(mut self) -> Self {
+        self.file = None;
+        self
+    }
+
+    /// The error as a dictionary of its `kind`, `file` and `line`, each `None` if
+    /// not known, and its `message`.
+    pub(crate) fn to_diagnostic(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("kind", self.kind)?;
+        dict.set_item("file", self.file.as_ref().map(|f| f.to_string_lossy()))?;
+        dict.set_item("line", self.line)?;
+        dict.set_item("message", &self.message)?;
+        Ok(dict.into())
+    }
+
+    /// Whether this is a parse error that does not say which file failed.
+    pub(crate) fn is_unlocated_parse_error(&self) -> bool {
+        self.category == Category::Parse && self.file.is_none()
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: {}", file.display(), line, self.message),
+            (Some(file), None) => write!(f, "{}: {}", file.display(), self.message),
+            (None, Some(line)) => write!(f, "line {}: {}", line, self.message),
+            (None, None) => self.message.fmt(f),
+        }
+    }
+}
+
+impl From<norad::Error> for LoadError {
+    fn from(error: norad::Error) -> Self {
+        use norad::Error::*;
+        if let Glif(norad::error::GlifError {
+            path,
+            position,
+            kind,
+        }) = &error
+        {
+            let mut message = kind.to_string();
+            if is_point_type_error(kind) {
+                let contents = path.as_ref().and_then(|p| std::fs::read_to_string(p).ok());
+                if let Some((name, index)) =
+                    contents.and_then(|c| crate::glif::contour_at(&c, *position))
+                {
+                    message = format!("glyph '{}', contour {}: {}", name, index, message);
+                }
+            }
+            let mut located = LoadError::new(Category::Parse, message).with_kind("InvalidGlif");
+            located.line = path.as_ref().and_then(|p| line_at(p, *position));
+            located.file = path.clone();
+            return located;
+        }
+        let category = match &error {
+            IoError(_) | MissingFile(_) | MissingUfoDir(_) => Category::Io,
+            PlistError(e) => plist_category(e),
+            ParseError(_) => Category::Parse,
+            MissingDefaultLayer
+            | MissingLayer(_)
+            | DuplicateLayer(_)
+            | InvalidColor(_)
+            | DuplicateGlyph { .. }
+            | MissingGlyph { .. }
+            | InvalidFontInfo
+            | FontInfoUpconversion
+            | InvalidGroups(_)
+            | GroupsUpconversionFailure(_)
+            | ExpectedPlistDictionary(_)
+            | ExpectedPlistString
+            | ExpectedPositiveValue => Category::Structure,
+            _ => Category::Other,
+        };
+        LoadError::new(category, error.to_string()).with_kind(norad_kind(&error))
+    }
+}
+
+/// The `kind` of the exception raised for `error`, which is the name of its
+/// variant, except for those wrapping errors of other crates.
+fn norad_kind(error: &norad::Error) -> &'static str {
+    use norad::Error::*;
+    match error {
+        DowngradeUnsupported => "DowngradeUnsupported",
+        PreexistingPublicObjectLibsKey => "PreexistingPublicObjectLibsKey",
+        MissingDefaultLayer => "MissingDefaultLayer",
+        MissingLayer(_) => "MissingLayer",
+        DuplicateLayer(_) => "DuplicateLayer",
+        InvalidColor(_) => "InvalidColor",
+        DuplicateGlyph { .. } => "DuplicateGlyph",
+        MissingGlyph { .. } => "MissingGlyph",
+        IoError(_) => "Io",
+        ParseError(_) => "InvalidXml",
+        Glif(_) => "InvalidGlif",
+        GlifWrite(_) => "GlifWrite",
+        PlistError(_) => "InvalidPlist",
+        InvalidFontInfo => "InvalidFontInfo",
+        FontInfoUpconversion => "FontInfoUpconversion",
+        InvalidGroups(_) => "InvalidGroups",
+        GroupsUpconversionFailure(_) => "GroupsUpconversionFailure",
+        ExpectedPlistDictionary(_) => "ExpectedPlistDictionary",
+        ExpectedPlistString => "ExpectedPlistString",
+        ExpectedPositiveValue => "ExpectedPositiveValue",
+        MissingFile(_) => "MissingFile",
+        MissingUfoDir(_) => "MissingUfoDir",
+    }
+}
+
+impl From<plist::Error> for LoadError {
+    fn from(error: plist::Error) -> Self {
+        let category = plist_category(&error);
+        let kind = match category {
+            Category::Io => "Io",
+            _ => "InvalidPlist",
+        };
+        LoadError::new(category, error.to_string()).with_kind(kind)
+    }
+}
+
+/// Whether `kind` is one of the errors norad raises for points whose types break
+/// the GLIF rules, such as a `move` point that does not start its contour.
+fn is_point_type_error(kind: &norad::error::ErrorKind) -> bool {
+    use norad::error::ErrorKind::*;
+    matches!(
+        kind,
+        UnexpectedMove
+            | UnexpectedSmooth
+            | UnexpectedPointAfterOffCurve
+            | TooManyOffCurves
+            | TrailingOffCurves
+    )
+}
+
+/// The 1-based line of the byte offset `position` in the file at `path`.
+fn line_at(path: &Path, position: usize) -> Option<usize> {
+    let contents = std::fs::read(path).ok()?;
+    let before = contents.get(..position.min(contents.len()))?;
+    Some(before.iter().filter(|b| **b == b'\n').count() + 1)
+}
+
+/// A plist that ends early is malformed rather than unreadable, even though the
+/// plist crate reports running out of bytes as an IO error.
+fn plist_category(error: &plist::Error) -> Category {
+    match error.as_io().map(std::io::Error::kind) {
+        Some(std::io::ErrorKind::UnexpectedEof) | None => Category::Parse,
+        Some(_) => Category::Io,
+    }
+}
+
+impl From<std::io::Error> for LoadError {
+    fn from(error: std::io::Error) -> Self {
+        LoadError::new(Category::Io, error.to_string()).with_kind("Io")
+    }
+}
+
+impl From<LoadError> for PyErr {
+    fn from(error: LoadError) -> PyErr {
+        let err = match error.category {
+            Category::Io => IondriveIOError::new_err(error.to_string()),
+            Category::Parse => IondriveParseError::new_err(error.to_string()),
+            Category::Structure => IondriveStructureError::new_err(error.to_string()),
+            Category::Other => IondriveError::new_err(error.to_string()),
+        };
+        if let Some(kind) = error.kind {
+            Python::with_gil(|py| err.pvalue(py).setattr("kind", kind)).unwrap();
+        }
+        err
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::{Category, LoadError};
+
+/// The feature file of a UFO.
+pub(crate) static FEATURES_FILE: &str = "features.fea";
+
+/// Reads the feature file of the UFO directory `ufo`, which is empty if there is
+/// none.
+pub(crate) fn read_features(ufo: &Path) -> Result<String, LoadError> {
+    if !ufo.is_dir() {
+        return Err(LoadError::new(
+            Category::Io,
+            format!("{} directory was not found", ufo.display()),
+        )
+        .with_kind("MissingUfoDir"));
+    }
+    let path = ufo.join(FEATURES_FILE);
+    match path.exists() {
+        true => std::fs::read_to_string(&path).map_err(|e| LoadError::from(e).in_file(&path)),
+        false => Ok(String::new()),
+    }
+}
+
+/// Replaces each `include(...)` statement in `features` with the contents of the
+/// file it names, recursively, so the result no longer depends on other files.
+///
+/// Relative include paths are looked up in each directory of `search` in turn.
+/// Includes in comments and strings are left alone.
+pub(crate) fn resolve_includes(features: &str, search: &[&Path]) -> Result<String, LoadError> {
+    resolve(features, search, &mut Vec::new())
+}
+
+/// Resolves the includes in `text`, which was included through the files in
+/// `stack`, so that a file including itself is caught.
+fn resolve(text: &str, search: &[&Path], stack: &mut Vec<PathBuf>) -> Result<String, LoadError> {
+    let mut resolved = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((start, end, name)) = next_include(rest) {
+        resolved.push_str(&rest[..start]);
+        let path = find(name, search)?;
+        if stack.contains(&path) {
+            let mut cycle: Vec<String> = stack.iter().map(|p| p.display().to_string()).collect();
+            cycle.push(path.display().to_string());
+            return Err(LoadError::new(
+                Category::Structure,
+                format!("features.fea: cyclic include: {}", cycle.join(" -> ")),
+            ));
+        }
+        let included = std::fs::read_to_string(&path).map_err(|e| {
+            LoadError::new(
+                Category::Io,
+                format!(
+                    "features.fea: cannot read include {}: {}",
+                    path.display(),
+                    e
+                ),
+            )
+            .with_kind("Io")
+        })?;
+        stack.push(path);
+        resolved.push_str(&resolve(&included, search, stack)?);
+        stack.pop();
+        rest = &rest[end..];
+    }
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+/// The path of the file included as `name`, looking up relative names in the
+/// directories of `search`.
+fn find(name: &str, search: &[&Path]) -> Result<PathBuf, LoadError> {
+    let name = Path::new(name);
+    let candidates: Vec<PathBuf> = match name.is_absolute() {
+        true => vec![name.to_path_buf()],
+        false => search.iter().map(|dir| dir.join(name)).collect(),
+    };
+    candidates
+        .iter()
+        .find(|candidate| candidate.is_file())
+        .map(|found| found.canonicalize())
+        .transpose()?
+        .ok_or_else(|| {
+            LoadError::new(
+                Category::Io,
+                format!("features.fea: included file {} not found", name.display()),
+            )
+            .with_kind("MissingFile")
+        })
+}
+
+/// Finds the first `include(...)` statement in `text` outside comments and
+/// strings, returning where it starts and ends, including any `;` after it, and
+/// the path it names.
+fn next_include(text: &str) -> Option<(usize, usize, &str)> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                i = text[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            }
+            b'"' => {
+                i = text[i + 1..].find('"').map_or(bytes.len(), |n| i + n + 2);
+            }
+            b'i' if text[i..].starts_with("include") && (i == 0 || !is_name_char(bytes[i - 1])) => {
+                let after = i + "include".len();
+                let open = after + text[after..].len() - text[after..].trim_start().len();
+                if bytes.get(open) == Some(&b'(') {
+                    let close = open + text[open..].find(')')?;
+                    let mut end = close + 1;
+                    let semicolon = end + text[end..].len() - text[end..].trim_start().len();
+                    if bytes.get(semicolon) == Some(&b';') {
+                        end = semicolon + 1;
+                    }
+                    return Some((i, end, text[open + 1..close].trim()));
+                }
+                i = after;
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Whether `c` may be part of a feature file name, so that `include` preceded by
+/// it is not the keyword.
+fn is_name_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, b'_' | b'.' | b'-' | b'@' | b'\\')
+}
@@ -0,0 +1,289 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pyo3::prelude::*;
+
+use crate::error::LoadError;
+use crate::IondriveError;
+
+static GLIF_FILE: &str = "glyph.glif";
+static GLIF_INDENT: &str = "  ";
+
+/// Numbers the temporary GLIF files made by this process.
+static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// Parses the GLIF XML `glif` with norad, naming the glyph `glyph_name` if given
+/// instead of after its `name` attribute, which may then also be missing.
+///
+/// norad only parses GLIF files, so the XML is written to a temporary directory
+/// that is removed again before returning.
+pub(crate) fn parse_glif(
+    py: Python,
+    glif: &str,
+    glyph_name: Option<&str>,
+) -> PyResult<norad::Glyph> {
+    let xml = match glyph_name {
+        Some(name) => with_glyph_name(glif, name),
+        None => glif.to_string(),
+    };
+    let temp_dir: PathBuf = py.import("tempfile")?.call_method0("mkdtemp")?.extract()?;
+    let path = temp_dir.join(GLIF_FILE);
+    let result = std::fs::write(&path, &xml)
+        .map_err(|e| IondriveError::new_err(e.to_string()))
+        .and_then(|_| norad::Glyph::load(&path).map_err(glif_error));
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    let mut glyph = result?;
+    restore_note(&mut glyph, &xml);
+    if let Some(name) = glyph_name {
+        glyph.name = name.into();
+    }
+    Ok(glyph)
+}
+
+/// Serializes `glyph` as GLIF XML in norad's formatting, indented by two spaces.
+pub(crate) fn dump_glif(glyph: &norad::Glyph) -> PyResult<String> {
+    let options = norad::WriteOptions::default().whitespace(GLIF_INDENT);
+    let xml = glyph
+        .encode_xml_with_options(&options)
+        .map_err(|e| IondriveError::new_err(e.to_string()))?;
+    String::from_utf8(xml).map_err(|e| IondriveError::new_err(e.to_string()))
+}
+
+/// Describes a norad parse error without the temporary file it was read from.
+fn glif_error(error: norad::Error) -> PyErr {
+    LoadError::from(error).without_file().into()
+}
+
+/// Adds a `name` attribute to the `<glyph>` element of `glif` if it has none, as
+/// norad requires one.
+fn with_glyph_name(glif: &str, name: &str) -> String {
+    let start = match find_glyph_element(glif) {
+        Some(start) => start,
+        None => return glif.to_string(),
+    };
+    let tag_end = glif[start..].find('>').map_or(glif.len(), |i| start + i);
+    let has_name = glif[start..tag_end]
+        .split(|c: char| c.is_whitespace())
+        .any(|attribute| attribute.starts_with("name=") || attribute == "name");
+    if has_name {
+        return glif.to_string();
+    }
+    let insert_at = start + "<glyph".len();
+    format!(
+        "{} name=\"{}\"{}",
+        &glif[..insert_at],
+        escape_attribute(name),
+        &glif[insert_at..]
+    )
+}
+
+/// The offset of the `<glyph` start tag in `glif`, if any.
+fn find_glyph_element(glif: &str) -> Option<usize> {
+    find_start_tag(glif, "glyph")
+}
+
+/// The offset of the first start tag of the element `name` in `glif`, outside
+/// comments and CDATA sections, if any.
+fn find_start_tag(glif: &str, name: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(start) = glif[from..].find('<').map(|i| from + i) {
+        let rest = &glif[start + 1..];
+        if let Some((_, end)) = [("!--", "-->"), ("![CDATA[", "]]>")]
+            .iter()
+            .find(|(open, _)| rest.starts_with(open))
+        {
+            from = start + rest.find(end)? + end.len();
+            continue;
+        }
+        let is_tag = rest.strip_prefix(name).is_some_and(|after| {
+            after
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_whitespace() || c == '>' || c == '/')
+        });
+        if is_tag {
+            return Some(start);
+        }
+        from = start + 1;
+    }
+    None
+}
+
+/// The raw value of the attribute `name` of the start tag `tag`, if it has one.
+fn attribute_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let value = tag
+        .split(|c: char| c.is_whitespace())
+        .find_map(|attribute| attribute.strip_prefix(name)?.strip_prefix('='))?;
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    value[1..].split(quote).next()
+}
+
+/// The advance width of the glyph in `glif`, read from its `<advance>` element
+/// without parsing the rest, or 0 if it has none or the element has no `width`.
+pub(crate) fn advance_width(glif: &str) -> Result<f32, String> {
+    let start = match find_start_tag(glif, "advance") {
+        Some(start) => start,
+        None => return Ok(0.0),
+    };
+    let end = glif[start..].find('>').map_or(glif.len(), |i| start + i);
+    let tag = glif[start..end].trim_end_matches('/');
+    match attribute_value(tag, "width") {
+        Some(width) => width
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid advance width '{}'", width)),
+        None => Ok(0.0),
+    }
+}
+
+/// Parses the GLIF XML `glif` with norad after bringing the angles of its
+/// guidelines outside 0 to 360 degrees, which norad rejects, into `[0, 360)`.
+///
+/// Gives the glyph along with the index of each guideline changed and its angle
+/// as it was, or `None` if no angle needed changing or the glyph still fails to
+/// parse.
+pub(crate) fn parse_with_normalized_angles(
+    glif: &str,
+) -> Option<(norad::Glyph, Vec<(usize, f32)>)> {
+    let mut normalized = String::with_capacity(glif.len());
+    let mut changed = Vec::new();
+    let mut copied = 0;
+    let mut from = 0;
+    let mut index = 0;
+    while let Some(start) = find_start_tag(&glif[from..], "guideline").map(|i| from + i) {
+        let end = glif[start..].find('>').map_or(glif.len(), |i| start + i);
+        let tag = &glif[start..end];
+        let angle = attribute_value(tag, "angle")
+            .and_then(|value| Some((value, value.trim().parse::<f32>().ok()?)))
+            .filter(|(_, degrees)| !(0.0..=360.0).contains(degrees));
+        if let Some((value, degrees)) = angle {
+            let offset = start + (value.as_ptr() as usize - tag.as_ptr() as usize);
+            normalized.push_str(&glif[copied..offset]);
+            normalized.push_str(&degrees.rem_euclid(360.0).to_string());
+            copied = offset + value.len();
+            changed.push((index, degrees));
+        }
+        index += 1;
+        from = end;
+    }
+    if changed.is_empty() {
+        return None;
+    }
+    normalized.push_str(&glif[copied..]);
+    let path = write_temp_file(&normalized).ok()?;
+    let glyph = norad::Glyph::load(&path);
+    let _ = std::fs::remove_file(&path);
+    Some((glyph.ok()?, changed))
+}
+
+/// Writes `contents` to a new file in the temporary directory, numbered after
+/// the files made before it, and gives its path. The GIL may not be held, so
+/// Python's `tempfile` is not used; a file that already exists is never opened,
+/// so another user's file cannot be written through.
+fn write_temp_file(contents: &str) -> std::io::Result<PathBuf> {
+    loop {
+        let path = std::env::temp_dir().join(format!(
+            "iondrive-{}-{}.glif",
+            std::process::id(),
+            NEXT_FILE.fetch_add(1, Ordering::Relaxed)
+        ));
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// The name of the glyph in `glif` and the index of the contour that the byte
+/// offset `position` falls in, for errors about the points of a contour.
+pub(crate) fn contour_at(glif: &str, position: usize) -> Option<(String, usize)> {
+    let start = find_glyph_element(glif)?;
+    let tag = &glif[start..start + glif[start..].find('>')?];
+    let name = attribute_value(tag, "name")?;
+    let end = (0..=position.min(glif.len())).rfind(|i| glif.is_char_boundary(*i))?;
+    let index = glif[..end].matches("<contour").count().checked_sub(1)?;
+    Some((unescape_text(name)?, index))
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+/// Puts back the whitespace around the note of `glyph` that norad trims, from
+/// the GLIF XML `glif` it was parsed from.
+///
+/// Notes with markup in them, such as CDATA sections or comments, are left as
+/// norad read them.
+pub(crate) fn restore_note(glyph: &mut norad::Glyph, glif: &str) {
+    let note = match &glyph.note {
+        Some(note) => note,
+        None => return,
+    };
+    let text = match note_text(glif) {
+        Some(text) if !text.contains('<') => text,
+        _ => return,
+    };
+    if let Some(text) = unescape_text(text) {
+        if text != *note && trim_xml_whitespace(&text) == note {
+            glyph.note = Some(text);
+        }
+    }
+}
+
+/// The raw text between `<note>` and `</note>` in `glif`, if there is a note.
+fn note_text(glif: &str) -> Option<&str> {
+    let start = glif.match_indices("<note").map(|(i, _)| i).find(|i| {
+        glif[i + "<note".len()..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_whitespace() || c == '>')
+    })?;
+    let open_end = start + glif[start..].find('>')? + 1;
+    let close = open_end + glif[open_end..].find("</note>")?;
+    Some(&glif[open_end..close])
+}
+
+/// Replaces the predefined entities and character references of XML text.
+fn unescape_text(text: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        unescaped.push_str(&rest[..amp]);
+        let end = amp + rest[amp..].find(';')?;
+        let c = match &rest[amp + 1..end] {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            reference => {
+                let code = match reference.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => reference.strip_prefix('#')?.parse().ok()?,
+                };
+                char::from_u32(code)?
+            }
+        };
+        unescaped.push(c);
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+    Some(unescaped)
+}
+
+/// `text` without the XML whitespace at either end, as norad's parser trims it.
+fn trim_xml_whitespace(text: &str) -> &str {
+    text.trim_matches(|c| matches!(c, ' ' | '\t' | '\r' | '\n'))
+}
@@ -1,9 +1,11 @@
-use crate::ToWrappedPyObject;
+use crate::contourpoint::f32_to_float;
+use crate::{convert_attr, extract_color, extract_identifier, IondriveError};
+use crate::{FromWrappedPyObject, ToWrappedPyObject};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
 
 impl ToWrappedPyObject for norad::Guideline {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Guideline").unwrap();
         let (x, y, angle) = match self.line {
             norad::Line::Vertical(x) => (Some(x), None, None),
@@ -12,16 +14,10 @@ impl ToWrappedPyObject for norad::Guideline {
         };
         let kwargs = [
             ("name", self.name.to_object(py)),
-            ("x", x.to_object(py)),
-            ("y", y.to_object(py)),
-            ("angle", angle.to_object(py)),
-            (
-                "color",
-                self.color
-                    .as_ref()
-                    .map(|c| c.to_rgba_string())
-                    .to_object(py),
-            ),
+            ("x", x.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("y", y.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("angle", angle.map(|v| f32_to_float(v, py)).to_object(py)),
+            ("color", self.color.to_wrapped_object(loader, py)),
             (
                 "identifier",
                 self.identifier()
@@ -33,3 +29,28 @@ impl ToWrappedPyObject for norad::Guideline {
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
+
+impl FromWrappedPyObject for norad::Guideline {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let x = convert_attr(obj, "x", PyAny::extract)?;
+        let y = convert_attr(obj, "y", PyAny::extract)?;
+        let angle = convert_attr(obj, "angle", PyAny::extract)?;
+        let line = match (x, y, angle) {
+            (Some(x), None, None) => norad::Line::Vertical(x),
+            (None, Some(y), None) => norad::Line::Horizontal(y),
+            (Some(x), Some(y), Some(degrees)) => norad::Line::Angle { x, y, degrees },
+            _ => {
+                return Err(IondriveError::new_err(
+                    "a guideline needs either x, y or all of x, y and angle",
+                ))
+            }
+        };
+        Ok(norad::Guideline::new(
+            line,
+            convert_attr(obj, "name", PyAny::extract)?,
+            extract_color(obj)?,
+            extract_identifier(obj)?,
+            None,
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::component::unwrap_transformation;
+use crate::contourpoint::{f32_to_int_or_float, transform_to_object};
+use crate::ToWrappedPyObject;
+use crate::{convert_attr, extract_color};
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+impl ToWrappedPyObject for norad::Image {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
+        let cls = loader.getattr("Image").unwrap();
+        let kwargs = [
+            ("fileName", self.file_name.to_object(py)),
+            (
+                "transformation",
+                transform_to_object(&self.transform, f32_to_int_or_float, py),
+            ),
+            ("color", self.color.to_wrapped_object(loader, py)),
+        ]
+        .into_py_dict(py);
+        cls.call((), Some(kwargs)).unwrap().into()
+    }
+}
+
+/// Converts a glyph's image, which ufoLib2 represents as an `Image` without a file
+/// name when there is none.
+pub(crate) fn unwrap_image(obj: &PyAny) -> PyResult<Option<norad::Image>> {
+    if obj.is_none() {
+        return Ok(None);
+    }
+    let file_name: Option<std::path::PathBuf> = convert_attr(obj, "fileName", PyAny::extract)?;
+    match file_name {
+        Some(file_name) => Ok(Some(norad::Image {
+            file_name,
+            color: extract_color(obj)?,
+            transform: convert_attr(obj, "transformation", unwrap_transformation)?,
+        })),
+        None => Ok(None),
+    }
+}
@@ -1,10 +1,10 @@
-use crate::MyToPyObject;
-use crate::ToWrappedPyObject;
+use crate::{convert_attr, IondriveError};
+use crate::{FromWrappedPyObject, MyFromPyObject, MyToPyObject, ToWrappedPyObject};
 use norad::fontinfo::StyleMapStyle;
 use norad::IntegerOrFloat;
 use norad::NonNegativeIntegerOrFloat;
 use pyo3::prelude::*;
-use pyo3::types::IntoPyDict;
+use pyo3::types::{IntoPyDict, PyDict};
 
 impl MyToPyObject for StyleMapStyle {
     fn to_object(&self, py: Python) -> PyObject {
@@ -94,351 +94,552 @@ impl MyToPyObject for NonNegativeIntegerOrFloat {
 }
 
 impl ToWrappedPyObject for norad::FontInfo {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Info").unwrap();
-        let kwargs = [
-            ("ascender", self.ascender.to_object(py)),
-            ("capHeight", self.cap_height.to_object(py)),
-            ("copyright", self.copyright.to_object(py)),
-            ("descender", self.descender.to_object(py)),
-            ("familyName", self.family_name.to_object(py)),
-            ("guidelines", self.guidelines.to_wrapped_object(loader, py)),
-            ("italicAngle", self.italic_angle.to_object(py)),
-            (
-                "macintoshFONDFamilyID",
-                self.macintosh_fond_family_id.to_object(py),
-            ),
-            ("macintoshFONDName", self.macintosh_fond_name.to_object(py)),
-            ("note", self.note.to_object(py)),
-            (
-                "openTypeGaspRangeRecords",
-                self.open_type_gasp_range_records.to_object(py),
-            ),
-            (
-                "openTypeHeadCreated",
-                self.open_type_head_created.to_object(py),
-            ),
-            ("openTypeHeadFlags", self.open_type_head_flags.to_object(py)),
-            (
-                "openTypeHeadLowestRecPPEM",
-                self.open_type_head_lowest_rec_ppem.to_object(py),
-            ),
-            (
-                "openTypeHheaAscender",
-                self.open_type_hhea_ascender.to_object(py),
-            ),
-            (
-                "openTypeHheaCaretOffset",
-                self.open_type_hhea_caret_offset.to_object(py),
-            ),
-            (
-                "openTypeHheaCaretSlopeRise",
-                self.open_type_hhea_caret_slope_rise.to_object(py),
-            ),
-            (
-                "openTypeHheaCaretSlopeRun",
-                self.open_type_hhea_caret_slope_run.to_object(py),
-            ),
-            (
-                "openTypeHheaDescender",
-                self.open_type_hhea_descender.to_object(py),
-            ),
-            (
-                "openTypeHheaLineGap",
-                self.open_type_hhea_line_gap.to_object(py),
-            ),
-            (
-                "openTypeNameCompatibleFullName",
-                self.open_type_name_compatible_full_name
-                    .as_ref()
-                    .to_object(py),
-            ),
-            (
-                "openTypeNameDescription",
-                self.open_type_name_description.to_object(py),
-            ),
-            (
-                "openTypeNameDesignerURL",
-                self.open_type_name_designer_url.to_object(py),
-            ),
-            (
-                "openTypeNameDesigner",
-                self.open_type_name_designer.to_object(py),
-            ),
-            (
-                "openTypeNameLicense",
-                self.open_type_name_license.to_object(py),
-            ),
-            (
-                "openTypeNameLicenseURL",
-                self.open_type_name_license_url.to_object(py),
-            ),
-            (
-                "openTypeNameManufacturer",
-                self.open_type_name_manufacturer.to_object(py),
-            ),
-            (
-                "openTypeNameManufacturerURL",
-                self.open_type_name_manufacturer_url.to_object(py),
-            ),
-            (
-                "openTypeNamePreferredFamilyName",
-                self.open_type_name_preferred_family_name.to_object(py),
-            ),
-            (
-                "openTypeNamePreferredSubfamilyName",
-                self.open_type_name_preferred_subfamily_name.to_object(py),
-            ),
-            (
-                "openTypeNameRecords",
-                self.open_type_name_records.to_object(py),
-            ),
-            (
-                "openTypeNameSampleText",
-                self.open_type_name_sample_text.to_object(py),
-            ),
-            (
-                "openTypeNameUniqueID",
-                self.open_type_name_unique_id.to_object(py),
-            ),
-            (
-                "openTypeNameVersion",
-                self.open_type_name_version.to_object(py),
-            ),
-            (
-                "openTypeNameWWSFamilyName",
-                self.open_type_name_wws_family_name.to_object(py),
-            ),
-            (
-                "openTypeNameWWSSubfamilyName",
-                self.open_type_name_wws_subfamily_name.to_object(py),
-            ),
-            (
-                "openTypeOS2CodePageRanges",
-                self.open_type_os2_code_page_ranges.to_object(py),
-            ),
-            (
-                "openTypeOS2FamilyClass",
-                self.open_type_os2_family_class.to_object(py),
-            ),
-            ("openTypeOS2Panose", self.open_type_os2_panose.to_object(py)),
-            (
-                "openTypeOS2Selection",
-                self.open_type_os2_selection.to_object(py),
-            ),
-            (
-                "openTypeOS2StrikeoutPosition",
-                self.open_type_os2_strikeout_position.to_object(py),
-            ),
-            (
-                "openTypeOS2StrikeoutSize",
-                self.open_type_os2_strikeout_size.to_object(py),
-            ),
-            (
-                "openTypeOS2SubscriptXOffset",
-                self.open_type_os2_subscript_x_offset.to_object(py),
-            ),
-            (
-                "openTypeOS2SubscriptXSize",
-                self.open_type_os2_subscript_x_size.to_object(py),
-            ),
-            (
-                "openTypeOS2SubscriptYOffset",
-                self.open_type_os2_subscript_y_offset.to_object(py),
-            ),
-            (
-                "openTypeOS2SubscriptYSize",
-                self.open_type_os2_subscript_y_size.to_object(py),
-            ),
-            (
-                "openTypeOS2SuperscriptXOffset",
-                self.open_type_os2_superscript_x_offset.to_object(py),
-            ),
-            (
-                "openTypeOS2SuperscriptXSize",
-                self.open_type_os2_superscript_x_size.to_object(py),
-            ),
-            (
-                "openTypeOS2SuperscriptYOffset",
-                self.open_type_os2_superscript_y_offset.to_object(py),
-            ),
-            (
-                "openTypeOS2SuperscriptYSize",
-                self.open_type_os2_superscript_y_size.to_object(py),
-            ),
-            ("openTypeOS2Type", self.open_type_os2_type.to_object(py)),
-            (
-                "openTypeOS2TypoAscender",
-                self.open_type_os2_typo_ascender.to_object(py),
-            ),
-            (
-                "openTypeOS2TypoDescender",
-                self.open_type_os2_typo_descender.to_object(py),
-            ),
-            (
-                "openTypeOS2TypoLineGap",
-                self.open_type_os2_typo_line_gap.to_object(py),
-            ),
-            (
-                "openTypeOS2UnicodeRanges",
-                self.open_type_os2_unicode_ranges.to_object(py),
-            ),
-            (
-                "openTypeOS2VendorID",
-                self.open_type_os2_vendor_id.to_object(py),
-            ),
-            (
-                "openTypeOS2WeightClass",
-                self.open_type_os2_weight_class.to_object(py),
-            ),
-            (
-                "openTypeOS2WidthClass",
-                self.open_type_os2_width_class
-                    .map(|x| x as u8)
-                    .to_object(py),
-            ),
-            (
-                "openTypeOS2WinAscent",
-                self.open_type_os2_win_ascent.to_object(py),
-            ),
-            (
-                "openTypeOS2WinDescent",
-                self.open_type_os2_win_descent.to_object(py),
-            ),
-            (
-                "openTypeVheaCaretOffset",
-                self.open_type_vhea_caret_offset.to_object(py),
-            ),
-            (
-                "openTypeVheaCaretSlopeRise",
-                self.open_type_vhea_caret_slope_rise.to_object(py),
-            ),
-            (
-                "openTypeVheaCaretSlopeRun",
-                self.open_type_vhea_caret_slope_run.to_object(py),
-            ),
-            (
-                "openTypeVheaVertTypoAscender",
-                self.open_type_vhea_vert_typo_ascender.to_object(py),
-            ),
-            (
-                "openTypeVheaVertTypoDescender",
-                self.open_type_vhea_vert_typo_descender.to_object(py),
-            ),
-            (
-                "openTypeVheaVertTypoLineGap",
-                self.open_type_vhea_vert_typo_line_gap.to_object(py),
-            ),
-            (
-                "postscriptBlueFuzz",
-                self.postscript_blue_fuzz.to_object(py),
-            ),
-            (
-                "postscriptBlueScale",
-                self.postscript_blue_scale.to_object(py),
-            ),
-            (
-                "postscriptBlueShift",
-                self.postscript_blue_shift.to_object(py),
-            ),
-            (
-                "postscriptBlueValues",
-                self.postscript_blue_values.to_object(py),
-            ),
-            (
-                "postscriptDefaultCharacter",
-                self.postscript_default_character.to_object(py),
-            ),
-            (
-                "postscriptDefaultWidthX",
-                self.postscript_default_width_x.to_object(py),
-            ),
-            (
-                "postscriptFamilyBlues",
-                self.postscript_family_blues.to_object(py),
-            ),
-            (
-                "postscriptFamilyOtherBlues",
-                self.postscript_family_other_blues.to_object(py),
-            ),
-            (
-                "postscriptFontName",
-                self.postscript_font_name.to_object(py),
-            ),
-            (
-                "postscriptForceBold",
-                self.postscript_force_bold.to_object(py),
-            ),
-            (
-                "postscriptFullName",
-                self.postscript_full_name.to_object(py),
-            ),
-            (
-                "postscriptIsFixedPitch",
-                self.postscript_is_fixed_pitch.to_object(py),
-            ),
-            (
-                "postscriptNominalWidthX",
-                self.postscript_nominal_width_x.to_object(py),
-            ),
-            (
-                "postscriptOtherBlues",
-                self.postscript_other_blues.to_object(py),
-            ),
-            (
-                "postscriptSlantAngle",
-                self.postscript_slant_angle.to_object(py),
-            ),
-            (
-                "postscriptStemSnapH",
-                self.postscript_stem_snap_h.to_object(py),
-            ),
-            (
-                "postscriptStemSnapV",
-                self.postscript_stem_snap_v.to_object(py),
-            ),
-            (
-                "postscriptUnderlinePosition",
-                self.postscript_underline_position.to_object(py),
-            ),
-            (
-                "postscriptUnderlineThickness",
-                self.postscript_underline_thickness.to_object(py),
-            ),
-            (
-                "postscriptUniqueID",
-                self.postscript_unique_id.to_object(py),
-            ),
-            (
-                "postscriptWeightName",
-                self.postscript_weight_name.to_object(py),
-            ),
-            (
-                "postscriptWindowsCharacterSet",
-                self.postscript_windows_character_set
-                    .map(|x| x as u8)
-                    .to_object(py),
-            ),
-            (
-                "styleMapFamilyName",
-                self.style_map_family_name.to_object(py),
-            ),
-            ("styleMapStyleName", self.style_map_style_name.to_object(py)),
-            ("styleName", self.style_name.to_object(py)),
-            ("trademark", self.trademark.to_object(py)),
-            ("unitsPerEm", self.units_per_em.to_object(py)),
-            ("versionMajor", self.version_major.to_object(py)),
-            ("versionMinor", self.version_minor.to_object(py)),
-            // ("woffMajorVersion", self.woff_major_version.to_object(py)),
-            // ("woffMinorVersion", self.woff_minor_version.to_object(py)),
-            ("xHeight", self.x_height.to_object(py)),
-            ("year", self.year.to_object(py)),
-        ]
-        .into_py_dict(py);
+        let kwargs = info_attributes(self, py).into_py_dict(py);
+        kwargs
+            .set_item("guidelines", self.guidelines.to_wrapped_object(loader, py))
+            .unwrap();
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
 
+/// The `Info` attributes and their values, `None` for those `info` does not set,
+/// except for the guidelines, which are objects of the loader.
+pub(crate) fn info_attributes(info: &norad::FontInfo, py: Python) -> Vec<(&'static str, PyObject)> {
+    [
+        ("ascender", info.ascender.to_object(py)),
+        ("capHeight", info.cap_height.to_object(py)),
+        ("copyright", info.copyright.to_object(py)),
+        ("descender", info.descender.to_object(py)),
+        ("familyName", info.family_name.to_object(py)),
+        ("italicAngle", info.italic_angle.to_object(py)),
+        (
+            "macintoshFONDFamilyID",
+            info.macintosh_fond_family_id.to_object(py),
+        ),
+        ("macintoshFONDName", info.macintosh_fond_name.to_object(py)),
+        ("note", info.note.to_object(py)),
+        (
+            "openTypeGaspRangeRecords",
+            info.open_type_gasp_range_records.to_object(py),
+        ),
+        (
+            "openTypeHeadCreated",
+            info.open_type_head_created.to_object(py),
+        ),
+        ("openTypeHeadFlags", info.open_type_head_flags.to_object(py)),
+        (
+            "openTypeHeadLowestRecPPEM",
+            info.open_type_head_lowest_rec_ppem.to_object(py),
+        ),
+        (
+            "openTypeHheaAscender",
+            info.open_type_hhea_ascender.to_object(py),
+        ),
+        (
+            "openTypeHheaCaretOffset",
+            info.open_type_hhea_caret_offset.to_object(py),
+        ),
+        (
+            "openTypeHheaCaretSlopeRise",
+            info.open_type_hhea_caret_slope_rise.to_object(py),
+        ),
+        (
+            "openTypeHheaCaretSlopeRun",
+            info.open_type_hhea_caret_slope_run.to_object(py),
+        ),
+        (
+            "openTypeHheaDescender",
+            info.open_type_hhea_descender.to_object(py),
+        ),
+        (
+            "openTypeHheaLineGap",
+            info.open_type_hhea_line_gap.to_object(py),
+        ),
+        (
+            "openTypeNameCompatibleFullName",
+            info.open_type_name_compatible_full_name
+                .as_ref()
+                .to_object(py),
+        ),
+        (
+            "openTypeNameDescription",
+            info.open_type_name_description.to_object(py),
+        ),
+        (
+            "openTypeNameDesignerURL",
+            info.open_type_name_designer_url.to_object(py),
+        ),
+        (
+            "openTypeNameDesigner",
+            info.open_type_name_designer.to_object(py),
+        ),
+        (
+            "openTypeNameLicense",
+            info.open_type_name_license.to_object(py),
+        ),
+        (
+            "openTypeNameLicenseURL",
+            info.open_type_name_license_url.to_object(py),
+        ),
+        (
+            "openTypeNameManufacturer",
+            info.open_type_name_manufacturer.to_object(py),
+        ),
+        (
+            "openTypeNameManufacturerURL",
+            info.open_type_name_manufacturer_url.to_object(py),
+        ),
+        (
+            "openTypeNamePreferredFamilyName",
+            info.open_type_name_preferred_family_name.to_object(py),
+        ),
+        (
+            "openTypeNamePreferredSubfamilyName",
+            info.open_type_name_preferred_subfamily_name.to_object(py),
+        ),
+        (
+            "openTypeNameRecords",
+            info.open_type_name_records.to_object(py),
+        ),
+        (
+            "openTypeNameSampleText",
+            info.open_type_name_sample_text.to_object(py),
+        ),
+        (
+            "openTypeNameUniqueID",
+            info.open_type_name_unique_id.to_object(py),
+        ),
+        (
+            "openTypeNameVersion",
+            info.open_type_name_version.to_object(py),
+        ),
+        (
+            "openTypeNameWWSFamilyName",
+            info.open_type_name_wws_family_name.to_object(py),
+        ),
+        (
+            "openTypeNameWWSSubfamilyName",
+            info.open_type_name_wws_subfamily_name.to_object(py),
+        ),
+        (
+            "openTypeOS2CodePageRanges",
+            info.open_type_os2_code_page_ranges.to_object(py),
+        ),
+        (
+            "openTypeOS2FamilyClass",
+            info.open_type_os2_family_class.to_object(py),
+        ),
+        ("openTypeOS2Panose", info.open_type_os2_panose.to_object(py)),
+        (
+            "openTypeOS2Selection",
+            info.open_type_os2_selection.to_object(py),
+        ),
+        (
+            "openTypeOS2StrikeoutPosition",
+            info.open_type_os2_strikeout_position.to_object(py),
+        ),
+        (
+            "openTypeOS2StrikeoutSize",
+            info.open_type_os2_strikeout_size.to_object(py),
+        ),
+        (
+            "openTypeOS2SubscriptXOffset",
+            info.open_type_os2_subscript_x_offset.to_object(py),
+        ),
+        (
+            "openTypeOS2SubscriptXSize",
+            info.open_type_os2_subscript_x_size.to_object(py),
+        ),
+        (
+            "openTypeOS2SubscriptYOffset",
+            info.open_type_os2_subscript_y_offset.to_object(py),
+        ),
+        (
+            "openTypeOS2SubscriptYSize",
+            info.open_type_os2_subscript_y_size.to_object(py),
+        ),
+        (
+            "openTypeOS2SuperscriptXOffset",
+            info.open_type_os2_superscript_x_offset.to_object(py),
+        ),
+        (
+            "openTypeOS2SuperscriptXSize",
+            info.open_type_os2_superscript_x_size.to_object(py),
+        ),
+        (
+            "openTypeOS2SuperscriptYOffset",
+            info.open_type_os2_superscript_y_offset.to_object(py),
+        ),
+        (
+            "openTypeOS2SuperscriptYSize",
+            info.open_type_os2_superscript_y_size.to_object(py),
+        ),
+        ("openTypeOS2Type", info.open_type_os2_type.to_object(py)),
+        (
+            "openTypeOS2TypoAscender",
+            info.open_type_os2_typo_ascender.to_object(py),
+        ),
+        (
+            "openTypeOS2TypoDescender",
+            info.open_type_os2_typo_descender.to_object(py),
+        ),
+        (
+            "openTypeOS2TypoLineGap",
+            info.open_type_os2_typo_line_gap.to_object(py),
+        ),
+        (
+            "openTypeOS2UnicodeRanges",
+            info.open_type_os2_unicode_ranges.to_object(py),
+        ),
+        (
+            "openTypeOS2VendorID",
+            info.open_type_os2_vendor_id.to_object(py),
+        ),
+        (
+            "openTypeOS2WeightClass",
+            info.open_type_os2_weight_class.to_object(py),
+        ),
+        (
+            "openTypeOS2WidthClass",
+            info.open_type_os2_width_class
+                .map(|x| x as u8)
+                .to_object(py),
+        ),
+        (
+            "openTypeOS2WinAscent",
+            info.open_type_os2_win_ascent.to_object(py),
+        ),
+        (
+            "openTypeOS2WinDescent",
+            info.open_type_os2_win_descent.to_object(py),
+        ),
+        (
+            "openTypeVheaCaretOffset",
+            info.open_type_vhea_caret_offset.to_object(py),
+        ),
+        (
+            "openTypeVheaCaretSlopeRise",
+            info.open_type_vhea_caret_slope_rise.to_object(py),
+        ),
+        (
+            "openTypeVheaCaretSlopeRun",
+            info.open_type_vhea_caret_slope_run.to_object(py),
+        ),
+        (
+            "openTypeVheaVertTypoAscender",
+            info.open_type_vhea_vert_typo_ascender.to_object(py),
+        ),
+        (
+            "openTypeVheaVertTypoDescender",
+            info.open_type_vhea_vert_typo_descender.to_object(py),
+        ),
+        (
+            "openTypeVheaVertTypoLineGap",
+            info.open_type_vhea_vert_typo_line_gap.to_object(py),
+        ),
+        (
+            "postscriptBlueFuzz",
+            info.postscript_blue_fuzz.to_object(py),
+        ),
+        (
+            "postscriptBlueScale",
+            info.postscript_blue_scale.to_object(py),
+        ),
+        (
+            "postscriptBlueShift",
+            info.postscript_blue_shift.to_object(py),
+        ),
+        (
+            "postscriptBlueValues",
+            info.postscript_blue_values.to_object(py),
+        ),
+        (
+            "postscriptDefaultCharacter",
+            info.postscript_default_character.to_object(py),
+        ),
+        (
+            "postscriptDefaultWidthX",
+            info.postscript_default_width_x.to_object(py),
+        ),
+        (
+            "postscriptFamilyBlues",
+            info.postscript_family_blues.to_object(py),
+        ),
+        (
+            "postscriptFamilyOtherBlues",
+            info.postscript_family_other_blues.to_object(py),
+        ),
+        (
+            "postscriptFontName",
+            info.postscript_font_name.to_object(py),
+        ),
+        (
+            "postscriptForceBold",
+            info.postscript_force_bold.to_object(py),
+        ),
+        (
+            "postscriptFullName",
+            info.postscript_full_name.to_object(py),
+        ),
+        (
+            "postscriptIsFixedPitch",
+            info.postscript_is_fixed_pitch.to_object(py),
+        ),
+        (
+            "postscriptNominalWidthX",
+            info.postscript_nominal_width_x.to_object(py),
+        ),
+        (
+            "postscriptOtherBlues",
+            info.postscript_other_blues.to_object(py),
+        ),
+        (
+            "postscriptSlantAngle",
+            info.postscript_slant_angle.to_object(py),
+        ),
+        (
+            "postscriptStemSnapH",
+            info.postscript_stem_snap_h.to_object(py),
+        ),
+        (
+            "postscriptStemSnapV",
+            info.postscript_stem_snap_v.to_object(py),
+        ),
+        (
+            "postscriptUnderlinePosition",
+            info.postscript_underline_position.to_object(py),
+        ),
+        (
+            "postscriptUnderlineThickness",
+            info.postscript_underline_thickness.to_object(py),
+        ),
+        (
+            "postscriptUniqueID",
+            info.postscript_unique_id.to_object(py),
+        ),
+        (
+            "postscriptWeightName",
+            info.postscript_weight_name.to_object(py),
+        ),
+        (
+            "postscriptWindowsCharacterSet",
+            info.postscript_windows_character_set
+                .map(|x| x as u8)
+                .to_object(py),
+        ),
+        (
+            "styleMapFamilyName",
+            info.style_map_family_name.to_object(py),
+        ),
+        ("styleMapStyleName", info.style_map_style_name.to_object(py)),
+        ("styleName", info.style_name.to_object(py)),
+        ("trademark", info.trademark.to_object(py)),
+        ("unitsPerEm", info.units_per_em.to_object(py)),
+        ("versionMajor", info.version_major.to_object(py)),
+        ("versionMinor", info.version_minor.to_object(py)),
+        // ("woffMajorVersion", info.woff_major_version.to_object(py)),
+        // ("woffMinorVersion", info.woff_minor_version.to_object(py)),
+        ("xHeight", info.x_height.to_object(py)),
+        ("year", info.year.to_object(py)),
+    ]
+    .into()
+}
+
+/// Sets the attributes of `info` that are not set and have a default that does
+/// not depend on the rest of the font, as `load(populate_defaults=True)` lists
+/// them: those of the OpenType specification for the `head`, `hhea` and `OS/2`
+/// tables, of the Type 1 specification for the PostScript hinting values, and of
+/// the CFF specification for the glyph widths.
+pub(crate) fn populate_defaults(info: &mut norad::FontInfo) {
+    info.units_per_em
+        .get_or_insert_with(|| NonNegativeIntegerOrFloat::new(1000.0).unwrap());
+    info.version_major.get_or_insert(0);
+    info.version_minor.get_or_insert(0);
+    info.italic_angle.get_or_insert(IntegerOrFloat::from(0));
+    info.open_type_head_flags.get_or_insert_with(Vec::new);
+    info.open_type_head_lowest_rec_ppem.get_or_insert(6);
+    info.open_type_hhea_caret_offset.get_or_insert(0);
+    info.open_type_hhea_line_gap.get_or_insert(0);
+    info.open_type_os2_weight_class.get_or_insert(400);
+    info.open_type_os2_width_class
+        .get_or_insert(norad::fontinfo::Os2WidthClass::Normal);
+    info.open_type_os2_selection.get_or_insert_with(Vec::new);
+    info.open_type_os2_type.get_or_insert_with(Vec::new);
+    info.postscript_blue_values.get_or_insert_with(Vec::new);
+    info.postscript_other_blues.get_or_insert_with(Vec::new);
+    info.postscript_family_blues.get_or_insert_with(Vec::new);
+    info.postscript_family_other_blues
+        .get_or_insert_with(Vec::new);
+    info.postscript_stem_snap_h.get_or_insert_with(Vec::new);
+    info.postscript_stem_snap_v.get_or_insert_with(Vec::new);
+    info.postscript_blue_fuzz
+        .get_or_insert(IntegerOrFloat::from(1));
+    info.postscript_blue_shift
+        .get_or_insert(IntegerOrFloat::from(7));
+    info.postscript_blue_scale.get_or_insert(0.039625);
+    info.postscript_force_bold.get_or_insert(false);
+    info.postscript_is_fixed_pitch.get_or_insert(false);
+    info.postscript_default_width_x
+        .get_or_insert(IntegerOrFloat::from(0));
+    info.postscript_nominal_width_x
+        .get_or_insert(IntegerOrFloat::from(0));
+}
+
+/// The `Info` attributes read back by `save`, excluding the separately converted
+/// `guidelines`.
+pub(crate) static INFO_ATTRIBUTES: &[&str] = &[
+    "ascender",
+    "capHeight",
+    "copyright",
+    "descender",
+    "familyName",
+    "italicAngle",
+    "macintoshFONDFamilyID",
+    "macintoshFONDName",
+    "note",
+    "openTypeGaspRangeRecords",
+    "openTypeHeadCreated",
+    "openTypeHeadFlags",
+    "openTypeHeadLowestRecPPEM",
+    "openTypeHheaAscender",
+    "openTypeHheaCaretOffset",
+    "openTypeHheaCaretSlopeRise",
+    "openTypeHheaCaretSlopeRun",
+    "openTypeHheaDescender",
+    "openTypeHheaLineGap",
+    "openTypeNameCompatibleFullName",
+    "openTypeNameDescription",
+    "openTypeNameDesignerURL",
+    "openTypeNameDesigner",
+    "openTypeNameLicense",
+    "openTypeNameLicenseURL",
+    "openTypeNameManufacturer",
+    "openTypeNameManufacturerURL",
+    "openTypeNamePreferredFamilyName",
+    "openTypeNamePreferredSubfamilyName",
+    "openTypeNameRecords",
+    "openTypeNameSampleText",
+    "openTypeNameUniqueID",
+    "openTypeNameVersion",
+    "openTypeNameWWSFamilyName",
+    "openTypeNameWWSSubfamilyName",
+    "openTypeOS2CodePageRanges",
+    "openTypeOS2FamilyClass",
+    "openTypeOS2Panose",
+    "openTypeOS2Selection",
+    "openTypeOS2StrikeoutPosition",
+    "openTypeOS2StrikeoutSize",
+    "openTypeOS2SubscriptXOffset",
+    "openTypeOS2SubscriptXSize",
+    "openTypeOS2SubscriptYOffset",
+    "openTypeOS2SubscriptYSize",
+    "openTypeOS2SuperscriptXOffset",
+    "openTypeOS2SuperscriptXSize",
+    "openTypeOS2SuperscriptYOffset",
+    "openTypeOS2SuperscriptYSize",
+    "openTypeOS2Type",
+    "openTypeOS2TypoAscender",
+    "openTypeOS2TypoDescender",
+    "openTypeOS2TypoLineGap",
+    "openTypeOS2UnicodeRanges",
+    "openTypeOS2VendorID",
+    "openTypeOS2WeightClass",
+    "openTypeOS2WidthClass",
+    "openTypeOS2WinAscent",
+    "openTypeOS2WinDescent",
+    "openTypeVheaCaretOffset",
+    "openTypeVheaCaretSlopeRise",
+    "openTypeVheaCaretSlopeRun",
+    "openTypeVheaVertTypoAscender",
+    "openTypeVheaVertTypoDescender",
+    "openTypeVheaVertTypoLineGap",
+    "postscriptBlueFuzz",
+    "postscriptBlueScale",
+    "postscriptBlueShift",
+    "postscriptBlueValues",
+    "postscriptDefaultCharacter",
+    "postscriptDefaultWidthX",
+    "postscriptFamilyBlues",
+    "postscriptFamilyOtherBlues",
+    "postscriptFontName",
+    "postscriptForceBold",
+    "postscriptFullName",
+    "postscriptIsFixedPitch",
+    "postscriptNominalWidthX",
+    "postscriptOtherBlues",
+    "postscriptSlantAngle",
+    "postscriptStemSnapH",
+    "postscriptStemSnapV",
+    "postscriptUnderlinePosition",
+    "postscriptUnderlineThickness",
+    "postscriptUniqueID",
+    "postscriptWeightName",
+    "postscriptWindowsCharacterSet",
+    "styleMapFamilyName",
+    "styleMapStyleName",
+    "styleName",
+    "trademark",
+    "unitsPerEm",
+    "versionMajor",
+    "versionMinor",
+    "xHeight",
+    "year",
+];
+
+/// Converts a list of records, which may be dicts or objects with attributes, into a
+/// plist array of dictionaries with the given fields.
+fn unwrap_records(records: &PyAny, fields: &[&str]) -> PyResult<plist::Value> {
+    let mut unwrapped = Vec::new();
+    for record in records.iter()? {
+        let record = record?;
+        let mut d = plist::Dictionary::new();
+        for &field in fields {
+            let value = match record.downcast::<PyDict>() {
+                Ok(dict) => dict.get_item(field).ok_or_else(|| {
+                    IondriveError::new_err(format!("record is missing '{}'", field))
+                })?,
+                Err(_) => record.getattr(field)?,
+            };
+            d.insert(field.into(), plist::Value::from_object(value)?);
+        }
+        unwrapped.push(plist::Value::Dictionary(d));
+    }
+    Ok(plist::Value::Array(unwrapped))
+}
+
+impl FromWrappedPyObject for norad::FontInfo {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let mut info = plist::Dictionary::new();
+        for &key in INFO_ATTRIBUTES {
+            let value = convert_attr(obj, key, |v| {
+                if v.is_none() {
+                    return Ok(None);
+                }
+                match key {
+                    "openTypeGaspRangeRecords" => {
+                        unwrap_records(v, &["rangeMaxPPEM", "rangeGaspBehavior"])
+                    }
+                    "openTypeNameRecords" => unwrap_records(
+                        v,
+                        &["nameID", "platformID", "encodingID", "languageID", "string"],
+                    ),
+                    _ => plist::Value::from_object(v),
+                }
+                .map(Some)
+            })?;
+            if let Some(value) = value {
+                info.insert(key.into(), value);
+            }
+        }
+        let mut font_info: norad::FontInfo = plist::from_value(&info.into())
+            .map_err(|e| IondriveError::new_err(format!("Info: {}", e)))?;
+        font_info.guidelines = convert_attr(obj, "guidelines", Option::from_wrapped_object)?;
+        Ok(font_info)
+    }
+}
+
 // TODO: Wrap all WOFF attributes. ufoLib2 does at the time of this writing not support them.
 
 //     // #[getter]
@@ -0,0 +1,45 @@
+use std::collections::BTreeSet;
+
+use crate::error::{Category, LoadError};
+
+/// The prefix of the kerning groups on the left, or first, side of a pair.
+static LEFT_PREFIX: &str = "public.kern1.";
+/// The prefix of the kerning groups on the right, or second, side of a pair.
+static RIGHT_PREFIX: &str = "public.kern2.";
+
+/// Checks that every kerning group the kerning of `ufo` refers to is in its
+/// groups, failing with a list of those that are not, left side first.
+pub(crate) fn check_groups(ufo: &norad::Font) -> Result<(), LoadError> {
+    let kerning = match &ufo.kerning {
+        Some(kerning) => kerning,
+        None => return Ok(()),
+    };
+    let exists = |name: &str| ufo.groups.as_ref().is_some_and(|g| g.contains_key(name));
+    let mut left = BTreeSet::new();
+    let mut right = BTreeSet::new();
+    for (first, pairs) in kerning {
+        if first.starts_with(LEFT_PREFIX) && !exists(first) {
+            left.insert(first.as_str());
+        }
+        for second in pairs.keys() {
+            if second.starts_with(RIGHT_PREFIX) && !exists(second) {
+                right.insert(second.as_str());
+            }
+        }
+    }
+    if left.is_empty() && right.is_empty() {
+        return Ok(());
+    }
+    let missing: Vec<String> = left
+        .iter()
+        .map(|name| format!("left group '{}'", name))
+        .chain(right.iter().map(|name| format!("right group '{}'", name)))
+        .collect();
+    Err(LoadError::new(
+        Category::Structure,
+        format!(
+            "kerning.plist: missing kerning groups: {}",
+            missing.join(", ")
+        ),
+    ))
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use pyo3::{PyIterProtocol, PySequenceProtocol};
+
+use crate::contourpoint;
+use crate::{IondriveError, ToWrappedPyObject};
+
+/// The glyphs of a layer parsed by norad, handed out to Python one at a time.
+///
+/// This serves as the glyph set of a lazily loaded ufoLib2 `Layer`, which holds
+/// a placeholder for each glyph until it is first accessed and then asks its
+/// glyph set to fill in the real `Glyph` with `readGlyph`. The layer caches the
+/// glyph from then on.
+///
+/// The norad layer is shared behind an `Arc` and never changed, so it stays alive
+/// for as long as any Python layer refers to it. Glyphs are only materialized
+/// while holding the GIL, so using a lazy font from several Python threads is as
+/// safe as using an eagerly loaded one; the same caveats about concurrent
+/// modification of the Python objects apply.
+#[pyclass(module = "iondrive")]
+pub(crate) struct GlyphSet {
+    layer: Arc<norad::Layer>,
+    loader: PyObject,
+    /// Whether whole coordinates are given as ints, as they were when the layer
+    /// was wrapped.
+    integerize: bool,
+}
+
+impl GlyphSet {
+    pub(crate) fn new(layer: Arc<norad::Layer>, loader: &PyAny) -> Self {
+        GlyphSet {
+            layer,
+            loader: loader.into(),
+            integerize: contourpoint::is_integerizing(),
+        }
+    }
+}
+
+#[pymethods]
+impl GlyphSet {
+    /// Fill in `glyph` with the glyph `glyph_name`. The point pen is not used, as
+    /// the outlines are set directly.
+    #[pyo3(name = "readGlyph")]
+    #[args(point_pen = "None")]
+    #[pyo3(text_signature = "($self, glyph_name, glyph, point_pen=None)")]
+    fn read_glyph(
+        &self,
+        py: Python,
+        glyph_name: &str,
+        glyph: &PyAny,
+        point_pen: Option<PyObject>,
+    ) -> PyResult<()> {
+        let _ = point_pen;
+        let source = self
+            .layer
+            .get_glyph(glyph_name)
+            .ok_or_else(|| PyKeyError::new_err(glyph_name.to_string()))?;
+        let wrapped = contourpoint::integerizing(self.integerize, || {
+            source.to_wrapped_object(self.loader.as_ref(py), py)
+        });
+        let wrapped = wrapped.as_ref(py);
+        for attribute in GLYPH_ATTRIBUTES {
+            glyph.setattr(*attribute, wrapped.getattr(*attribute)?)?;
+        }
+        Ok(())
+    }
+
+    /// The names of the glyphs in the layer.
+    #[pyo3(text_signature = "($self)")]
+    fn keys(&self) -> Vec<String> {
+        self.layer.iter().map(|g| g.name.to_string()).collect()
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for GlyphSet {
+    fn __len__(&self) -> usize {
+        self.layer.len()
+    }
+
+    fn __contains__(&self, glyph_name: &str) -> bool {
+        self.layer.contains_glyph(glyph_name)
+    }
+}
+
+/// The glyphs of a layer parsed by norad, wrapped one at a time as they are
+/// iterated over, in name order.
+///
+/// Only the glyph being handed out is wrapped, so a caller that drops each glyph
+/// before asking for the next one keeps no more than one of them as Python
+/// objects. The norad layer is shared behind an `Arc`, as for `GlyphSet`.
+#[pyclass(module = "iondrive")]
+pub(crate) struct GlyphIterator {
+    layer: Arc<norad::Layer>,
+    loader: PyObject,
+    names: Vec<norad::GlyphName>,
+    /// The index in `names` of the next glyph to hand out.
+    next: usize,
+}
+
+impl GlyphIterator {
+    pub(crate) fn new(layer: Arc<norad::Layer>, loader: &PyAny) -> Self {
+        let names = layer.iter().map(|g| g.name.clone()).collect();
+        GlyphIterator {
+            layer,
+            loader: loader.into(),
+            names,
+            next: 0,
+        }
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for GlyphIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<PyObject> {
+        let name = slf.names.get(slf.next)?.clone();
+        slf.next += 1;
+        let py = slf.py();
+        let glyph = slf.layer.get_glyph(&*name)?;
+        Some(glyph.to_wrapped_object(slf.loader.as_ref(py), py))
+    }
+}
+
+/// The attributes of a `Glyph` that `readGlyph` fills in.
+static GLYPH_ATTRIBUTES: &[&str] = &[
+    "width",
+    "height",
+    "unicodes",
+    "note",
+    "lib",
+    "image",
+    "anchors",
+    "guidelines",
+    "contours",
+    "components",
+];
+
+/// The value a lazily loaded layer of the loader holds in place of a glyph that
+/// has not been materialized yet.
+pub(crate) fn placeholder(loader: &PyAny) -> PyResult<&PyAny> {
+    loader
+        .getattr("layer")
+        .and_then(|layer| layer.getattr("_GLYPH_NOT_LOADED"))
+        .map_err(|_| {
+            IondriveError::new_err(format!(
+                "{} does not support lazily loaded layers",
+                loader
+                    .getattr("__name__")
+                    .and_then(PyAny::extract::<String>)
+                    .unwrap_or_else(|_| "the font objects module".to_string())
+            ))
+        })
+}
@@ -1,39 +1,148 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use pyo3::create_exception;
-use pyo3::exceptions::PyException;
+use pyo3::exceptions::{PyException, PyTypeError};
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict};
 use pyo3::wrap_pyfunction;
+use pyo3::PyNativeType;
+use rayon::prelude::*;
+
+use contourpoint::f32_to_int_or_float;
+use error::{Category, LoadError};
 
 mod anchor;
+mod cache;
 mod component;
 mod contour;
 mod contourpoint;
+mod data;
+mod decompose;
+mod defcon;
+mod designspace;
+mod diff;
+mod downgrade;
+mod encoding;
+mod error;
+mod features;
+mod glif;
 mod guideline;
+mod image;
 mod info;
+mod kerning;
+mod lazy;
+mod native;
+mod packed;
 mod plist;
+mod precision;
+mod progress;
+mod raw;
+mod reader;
+mod ufo_reader;
+mod ufoz;
+mod unknown;
+mod verify;
+mod writer;
 
 trait ToWrappedPyObject {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject;
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject;
 }
 
 trait MyToPyObject {
     fn to_object(&self, py: Python) -> PyObject;
 }
 
+trait FromWrappedPyObject: Sized {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self>;
+}
+
+trait MyFromPyObject: Sized {
+    fn from_object(obj: &PyAny) -> PyResult<Self>;
+}
+
+/// Looks up the attribute `name` on `obj` and converts it with `convert`.
+///
+/// Failures are reported as an `IondriveError` prefixed with the type and attribute
+/// name, so that errors from nested objects read like a path into the font.
+fn convert_attr<'a, T>(
+    obj: &'a PyAny,
+    name: &str,
+    convert: impl FnOnce(&'a PyAny) -> PyResult<T>,
+) -> PyResult<T> {
+    obj.getattr(name).and_then(convert).map_err(|e| {
+        let py = obj.py();
+        let type_name = obj.get_type().name().unwrap_or("object");
+        // Nested conversion errors already carry their own path, so only prefix them.
+        let reason = if e.is_instance::<IondriveError>(py) {
+            e.pvalue(py).to_string()
+        } else {
+            e.to_string()
+        };
+        IondriveError::new_err(format!("{}.{}: {}", type_name, name, reason))
+    })
+}
+
+fn extract_identifier(obj: &PyAny) -> PyResult<Option<norad::Identifier>> {
+    convert_attr(obj, "identifier", |i| {
+        i.extract::<Option<String>>()?
+            .map(|i| norad::Identifier::new(i).map_err(|e| IondriveError::new_err(e.to_string())))
+            .transpose()
+    })
+}
+
+/// Wraps a color as the loader's `Color`, constructed from the RGBA string, or as
+/// the string itself if the loader has no such class.
+impl ToWrappedPyObject for norad::Color {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
+        let rgba = self.to_rgba_string();
+        match loader.getattr("Color") {
+            Ok(cls) => cls.call1((rgba,)).unwrap().into(),
+            Err(_) => rgba.to_object(py),
+        }
+    }
+}
+
+/// Converts the `color` attribute of `obj`, which may be an RGBA string or any
+/// object whose string form is one, such as a `Color`.
+fn extract_color(obj: &PyAny) -> PyResult<Option<norad::Color>> {
+    convert_attr(obj, "color", |c| {
+        if c.is_none() {
+            return Ok(None);
+        }
+        c.str()?
+            .to_str()?
+            .parse()
+            .map(Some)
+            .map_err(|e: norad::error::InvalidColorString| IondriveError::new_err(e.to_string()))
+    })
+}
+
 impl<T> ToWrappedPyObject for Option<T>
 where
     T: ToWrappedPyObject,
 {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
-        return self
-            .as_ref()
-            .map_or(py.None(), |x| x.to_wrapped_object(loader, py));
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
+        self.as_ref()
+            .map_or(py.None(), |x| x.to_wrapped_object(loader, py))
+    }
+}
+
+impl<T> FromWrappedPyObject for Option<T>
+where
+    T: FromWrappedPyObject,
+{
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        if obj.is_none() {
+            Ok(None)
+        } else {
+            T::from_wrapped_object(obj).map(Some)
+        }
     }
 }
 
@@ -41,7 +150,7 @@ impl<T> ToWrappedPyObject for Vec<T>
 where
     T: ToWrappedPyObject,
 {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         self.iter()
             .map(|x| x.to_wrapped_object(loader, py))
             .collect::<Vec<PyObject>>()
@@ -49,6 +158,17 @@ where
     }
 }
 
+impl<T> FromWrappedPyObject for Vec<T>
+where
+    T: FromWrappedPyObject,
+{
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        obj.iter()?
+            .map(|x| x.and_then(T::from_wrapped_object))
+            .collect()
+    }
+}
+
 impl<T> MyToPyObject for Vec<T>
 where
     T: MyToPyObject,
@@ -90,20 +210,22 @@ where
 }
 
 impl ToWrappedPyObject for Arc<norad::Glyph> {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let cls = loader.getattr("Glyph").unwrap();
         let kwargs = [
             ("name", self.name.to_object(py)),
-            ("width", self.width.to_object(py)),
+            ("width", f32_to_int_or_float(self.width, py)),
+            ("height", f32_to_int_or_float(self.height, py)),
+            // In file order, as the first is the primary code point.
             (
                 "unicodes",
                 self.codepoints
                     .iter()
-                    .map(|l| (*l as u32).to_object(py))
-                    .collect::<Vec<PyObject>>()
+                    .map(|c| *c as u32)
+                    .collect::<Vec<u32>>()
                     .to_object(py),
             ),
-            ("lib", self.lib.to_object(py)),
+            ("lib", glyph_lib(self).to_object(py)),
             ("note", self.note.to_object(py)),
             ("anchors", self.anchors.to_wrapped_object(loader, py)),
             ("contours", self.contours.to_wrapped_object(loader, py)),
@@ -111,40 +233,262 @@ impl ToWrappedPyObject for Arc<norad::Glyph> {
             ("guidelines", self.guidelines.to_wrapped_object(loader, py)),
         ]
         .into_py_dict(py);
+        // Leave the image to the Glyph's own default when there is none.
+        if let Some(image) = &self.image {
+            kwargs
+                .set_item("image", image.to_wrapped_object(loader, py))
+                .unwrap();
+        }
         cls.call((), Some(kwargs)).unwrap().into()
     }
 }
 
+impl FromWrappedPyObject for norad::Glyph {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let name: String = convert_attr(obj, "name", PyAny::extract)?;
+        let mut glyph = norad::Glyph::new_named(name);
+        glyph.width = convert_attr(obj, "width", PyAny::extract)?;
+        glyph.height = convert_attr(obj, "height", PyAny::extract)?;
+        glyph.codepoints = convert_attr(obj, "unicodes", |u| {
+            u.extract::<Vec<u32>>()?
+                .into_iter()
+                .map(|c| {
+                    std::char::from_u32(c).ok_or_else(|| {
+                        IondriveError::new_err(format!("{:#X} is not a valid codepoint", c))
+                    })
+                })
+                .collect()
+        })?;
+        glyph.lib = convert_attr(obj, "lib", norad::Plist::from_object)?;
+        glyph.note = convert_attr(obj, "note", PyAny::extract)?;
+        glyph.anchors = convert_attr(obj, "anchors", Vec::from_wrapped_object)?;
+        glyph.contours = convert_attr(obj, "contours", Vec::from_wrapped_object)?;
+        glyph.components = convert_attr(obj, "components", Vec::from_wrapped_object)?;
+        glyph.guidelines = convert_attr(obj, "guidelines", Vec::from_wrapped_object)?;
+        glyph.image = convert_attr(obj, "image", image::unwrap_image)?;
+        take_object_libs(&mut glyph)?;
+        Ok(glyph)
+    }
+}
+
+/// The key of a glyph lib holding the libs of the glyph's anchors, guidelines,
+/// contours, points and components, by identifier.
+static OBJECT_LIBS_KEY: &str = "public.objectLibs";
+
+/// The parts of a UFO that the `read` option of `load` names.
+static UFO_PARTS: &[&str] = &[
+    "layers", "lib", "groups", "kerning", "features", "data", "images",
+];
+
+/// The lib of `glyph` as it is in the GLIF file and in ufoLib2's glyphs, with
+/// the libs norad moves from its `public.objectLibs` onto the objects put back.
+fn glyph_lib(glyph: &norad::Glyph) -> Cow<'_, norad::Plist> {
+    let mut object_libs = norad::Plist::new();
+    let mut add = |identifier: Option<&norad::Identifier>, lib: Option<&norad::Plist>| {
+        if let (Some(identifier), Some(lib)) = (identifier, lib) {
+            object_libs.insert(identifier.as_str().to_string(), lib.clone().into());
+        }
+    };
+    for anchor in &glyph.anchors {
+        add(anchor.identifier(), anchor.lib());
+    }
+    for guideline in &glyph.guidelines {
+        add(guideline.identifier(), guideline.lib());
+    }
+    for contour in &glyph.contours {
+        add(contour.identifier(), contour.lib());
+        for point in &contour.points {
+            add(point.identifier(), point.lib());
+        }
+    }
+    for component in &glyph.components {
+        add(component.identifier(), component.lib());
+    }
+    if object_libs.is_empty() {
+        return Cow::Borrowed(&glyph.lib);
+    }
+    let mut lib = glyph.lib.clone();
+    lib.insert(OBJECT_LIBS_KEY.to_string(), object_libs.into());
+    Cow::Owned(lib)
+}
+
+/// Moves the libs in the `public.objectLibs` of the lib of `glyph` onto the
+/// objects with their identifiers, as norad keeps them and requires to save the
+/// glyph. Libs of identifiers that no object has are dropped, as norad drops
+/// them when reading a GLIF file.
+fn take_object_libs(glyph: &mut norad::Glyph) -> PyResult<()> {
+    let mut object_libs = match glyph.lib.remove(OBJECT_LIBS_KEY) {
+        Some(::plist::Value::Dictionary(object_libs)) => object_libs,
+        Some(_) => {
+            return Err(IondriveError::new_err(format!(
+                "the {} of glyph '{}' is not a dictionary",
+                OBJECT_LIBS_KEY, glyph.name
+            )))
+        }
+        None => return Ok(()),
+    };
+    let name = glyph.name.clone();
+    let mut take = |identifier: Option<&norad::Identifier>| -> PyResult<Option<norad::Plist>> {
+        match identifier.and_then(|i| object_libs.remove(i.as_str())) {
+            Some(::plist::Value::Dictionary(lib)) => Ok(Some(lib)),
+            Some(_) => Err(IondriveError::new_err(format!(
+                "the {} of glyph '{}' has a lib that is not a dictionary",
+                OBJECT_LIBS_KEY, name
+            ))),
+            None => Ok(None),
+        }
+    };
+    for anchor in &mut glyph.anchors {
+        if let Some(lib) = take(anchor.identifier())? {
+            anchor.replace_lib(lib);
+        }
+    }
+    for guideline in &mut glyph.guidelines {
+        if let Some(lib) = take(guideline.identifier())? {
+            guideline.replace_lib(lib);
+        }
+    }
+    for contour in &mut glyph.contours {
+        if let Some(lib) = take(contour.identifier())? {
+            contour.replace_lib(lib);
+        }
+        for point in &mut contour.points {
+            if let Some(lib) = take(point.identifier())? {
+                point.replace_lib(lib);
+            }
+        }
+    }
+    for component in &mut glyph.components {
+        if let Some(lib) = take(component.identifier())? {
+            component.replace_lib(lib);
+        }
+    }
+    Ok(())
+}
+
 impl ToWrappedPyObject for norad::Layer {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
-        let cls = loader.getattr("Layer").unwrap();
-        let kwargs = [
-            ("name", self.name().to_object(py)),
-            (
-                "glyphs",
-                self.iter()
-                    .map(|l| l.to_wrapped_object(loader, py))
-                    .collect::<Vec<PyObject>>()
-                    .to_object(py),
-            ),
-            ("lib", self.lib.to_object(py)),
-            (
-                "color",
-                self.color
-                    .as_ref()
-                    .map(|c| c.to_rgba_string())
-                    .to_object(py),
-            ), // ()
-        ]
-        .into_py_dict(py);
-        cls.call((), Some(kwargs)).unwrap().into()
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
+        wrap_layer(self, &[], Glyphs::List, loader, py)
     }
 }
 
-fn wrap_layerset(layers: &norad::LayerSet, loader: &PyModule, py: Python) -> PyObject {
-    let wrapped_layers: Vec<PyObject> = layers
+/// How wrapped layers hold their glyphs.
+#[derive(Clone, Copy)]
+enum Glyphs<'py> {
+    /// A list of glyphs.
+    List,
+    /// A dictionary of glyphs by name.
+    Dict,
+    /// A dictionary holding the placeholder in place of each glyph, for a lazy
+    /// layer that creates the glyphs from a `lazy::GlyphSet` when accessed.
+    Lazy(&'py PyAny),
+}
+
+impl<'py> Glyphs<'py> {
+    /// How the layers of a font loaded with `options` hold their glyphs.
+    fn for_options(options: &LoadOptions, loader: &'py PyAny) -> PyResult<Self> {
+        match (options.lazy, options.glyphs_as_dict) {
+            (true, _) => Ok(Glyphs::Lazy(lazy::placeholder(loader)?)),
+            (false, true) => Ok(Glyphs::Dict),
+            (false, false) => Ok(Glyphs::List),
+        }
+    }
+}
+
+/// Wraps `layer` with its glyphs in the order of `glyph_order`, followed by any
+/// glyphs it does not list in the layer's own order, held as `glyphs_as` says.
+fn wrap_layer(
+    layer: &norad::Layer,
+    glyph_order: &[&str],
+    glyphs_as: Glyphs,
+    loader: &PyAny,
+    py: Python,
+) -> PyObject {
+    let listed: HashSet<&str> = glyph_order.iter().copied().collect();
+    let mut seen = HashSet::new();
+    let glyphs = glyph_order
         .iter()
-        .map(|l| l.to_wrapped_object(loader, py))
+        .filter(|name| seen.insert(**name))
+        .filter_map(|name| layer.get_glyph(*name))
+        .chain(layer.iter().filter(|g| !listed.contains(&*g.name)));
+
+    let wrapped_glyphs = match glyphs_as {
+        Glyphs::Lazy(placeholder) => {
+            let placeholders = PyDict::new(py);
+            for glyph in glyphs {
+                placeholders.set_item(&*glyph.name, placeholder).unwrap();
+            }
+            placeholders.to_object(py)
+        }
+        Glyphs::Dict => {
+            let by_name = PyDict::new(py);
+            for glyph in glyphs {
+                by_name
+                    .set_item(&*glyph.name, glyph.to_wrapped_object(loader, py))
+                    .unwrap();
+            }
+            by_name.to_object(py)
+        }
+        Glyphs::List => glyphs
+            .map(|g| g.to_wrapped_object(loader, py))
+            .collect::<Vec<PyObject>>()
+            .to_object(py),
+    };
+
+    let cls = loader.getattr("Layer").unwrap();
+    let kwargs = [
+        ("name", layer.name().to_object(py)),
+        ("glyphs", wrapped_glyphs),
+        ("lib", layer.lib.to_object(py)),
+        ("color", layer.color.to_wrapped_object(loader, py)),
+    ]
+    .into_py_dict(py);
+    let wrapped = cls.call((), Some(kwargs)).unwrap();
+    if let Glyphs::Lazy(_) = glyphs_as {
+        let glyph_set = lazy::GlyphSet::new(Arc::new(layer.clone()), loader);
+        wrapped
+            .setattr("_glyphSet", Py::new(py, glyph_set).unwrap())
+            .unwrap();
+    }
+    wrapped.into()
+}
+
+impl FromWrappedPyObject for norad::Layer {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let name: String = convert_attr(obj, "name", PyAny::extract)?;
+        let mut layer = norad::Layer::new(name.into(), None);
+        for glyph in obj.iter()? {
+            layer.insert_glyph(norad::Glyph::from_wrapped_object(glyph?)?);
+        }
+        layer.lib = convert_attr(obj, "lib", norad::Plist::from_object)?;
+        layer.color = extract_color(obj)?;
+        Ok(layer)
+    }
+}
+
+/// Wraps `layers`, in the `order` of their names if given, ordering the glyphs
+/// of each by the font lib's `public.glyphOrder` if it has one, and holding them
+/// as `glyphs_as` says.
+fn wrap_layerset(
+    layers: &norad::LayerSet,
+    order: Option<&[String]>,
+    lib: &norad::Plist,
+    glyphs_as: Glyphs,
+    loader: &PyAny,
+    py: Python,
+) -> PyObject {
+    let glyph_order: Vec<&str> = lib
+        .get("public.glyphOrder")
+        .and_then(|order| order.as_array())
+        .map(|order| order.iter().filter_map(|name| name.as_string()).collect())
+        .unwrap_or_default();
+    let ordered: Vec<&norad::Layer> = match order {
+        Some(order) => order.iter().filter_map(|name| layers.get(name)).collect(),
+        None => layers.iter().collect(),
+    };
+    let wrapped_layers: Vec<PyObject> = ordered
+        .into_iter()
+        .map(|l| wrap_layer(l, &glyph_order, glyphs_as, loader, py))
         .collect();
 
     let cls = loader.getattr("LayerSet").unwrap();
@@ -157,65 +501,2154 @@ fn wrap_layerset(layers: &norad::LayerSet, loader: &PyModule, py: Python) -> PyO
     .into()
 }
 
-fn wrap_kerning(kerning: Option<&norad::Kerning>, py: Python) -> PyObject {
-    match kerning {
-        Some(kerning) => {
-            let d = PyDict::new(py);
-            for (left, v) in kerning.iter() {
-                for (right, kern) in v.iter() {
-                    d.set_item((left, right).to_object(py), kern.to_object(py))
-                        .unwrap();
-                }
+fn unwrap_layerset(layers: &PyAny) -> PyResult<norad::LayerSet> {
+    let default_name: String =
+        convert_attr(layers, "defaultLayer", |l| l.getattr("name")?.extract())?;
+    let mut unwrapped_layers: Vec<norad::Layer> = layers
+        .iter()?
+        .map(|l| l.and_then(norad::Layer::from_wrapped_object))
+        .collect::<PyResult<_>>()?;
+
+    // norad expects the default layer to come first.
+    let default_idx = unwrapped_layers
+        .iter()
+        .position(|l| **l.name() == *default_name)
+        .ok_or_else(|| {
+            IondriveError::new_err(format!("default layer '{}' not found", default_name))
+        })?;
+    unwrapped_layers[..=default_idx].rotate_right(1);
+    Ok(norad::LayerSet::new(unwrapped_layers))
+}
+
+/// The groups as a dictionary of names to lists of glyph names.
+fn wrap_groups(groups: Option<&norad::Groups>, py: Python) -> PyObject {
+    groups.map_or(PyDict::new(py).to_object(py), |v| v.to_object(py))
+}
+
+/// Wraps kerning as the loader's `Kerning` type, built from a mapping of
+/// `(first, second)` pairs to values as ufoLib2's is. Loaders without one get
+/// the nested `{first: {second: value}}` dictionary of the UFO.
+fn wrap_kerning(
+    kerning: Option<&norad::Kerning>,
+    loader: &PyAny,
+    py: Python,
+) -> PyResult<PyObject> {
+    match loader.getattr("Kerning") {
+        Ok(cls) => Ok(cls.call1((kerning_pairs(kerning, py),))?.into()),
+        Err(_) => Ok(raw::kerning_dict(kerning, py)?.into()),
+    }
+}
+
+/// The kerning as a dictionary of `(left, right)` pairs to values.
+fn kerning_pairs<'py>(kerning: Option<&norad::Kerning>, py: Python<'py>) -> &'py PyDict {
+    let d = PyDict::new(py);
+    if let Some(kerning) = kerning {
+        for (left, v) in kerning.iter() {
+            for (right, kern) in v.iter() {
+                d.set_item((left, right).to_object(py), f32_to_int_or_float(*kern, py))
+                    .unwrap();
+            }
+        }
+    }
+    d
+}
+
+/// Unwraps kerning keyed by `(first, second)` pairs, or nested by first and
+/// second as `wrap_kerning` gives it without a `Kerning` type.
+fn unwrap_kerning(kerning: &PyAny) -> PyResult<Option<norad::Kerning>> {
+    let mut unwrapped = norad::Kerning::new();
+    for item in kerning.call_method0("items")?.iter()? {
+        let (key, value): (&PyAny, &PyAny) = item?.extract()?;
+        if let Ok(left) = key.extract::<String>() {
+            let seconds = unwrapped.entry(left).or_default();
+            for item in value.call_method0("items")?.iter()? {
+                let (right, value): (String, f32) = item?.extract()?;
+                seconds.insert(right, value);
             }
-            d.into()
+            continue;
         }
-        None => PyDict::new(py).into(),
+        let (left, right): (String, String) = key.extract()?;
+        unwrapped
+            .entry(left)
+            .or_default()
+            .insert(right, value.extract()?);
+    }
+    Ok(Some(unwrapped).filter(|k| !k.is_empty()))
+}
+
+fn unwrap_groups(groups: &PyAny) -> PyResult<Option<norad::Groups>> {
+    let mut unwrapped = norad::Groups::new();
+    for (name, members) in groups.downcast::<PyDict>()?.iter() {
+        let members: Vec<String> = members.extract()?;
+        unwrapped.insert(
+            name.extract()?,
+            members.into_iter().map(norad::GlyphName::from).collect(),
+        );
+    }
+    Ok(Some(unwrapped).filter(|g| !g.is_empty()))
+}
+
+/// Wraps the feature file text as the loader's `Features`, with empty text if the
+/// UFO has none, or as a plain string if the loader has no such class.
+fn wrap_features(features: Option<&str>, loader: &PyAny, py: Python) -> PyObject {
+    let text = features.unwrap_or("");
+    match loader.getattr("Features") {
+        Ok(cls) => cls.call1((text,)).unwrap().into(),
+        Err(_) => text.to_object(py),
     }
 }
 
+fn unwrap_features(features: &PyAny) -> PyResult<Option<String>> {
+    let text: String = if features.hasattr("text")? {
+        features.getattr("text")?.extract()?
+    } else {
+        features.extract()?
+    };
+    Ok(Some(text).filter(|t| !t.is_empty()))
+}
+
 impl ToWrappedPyObject for norad::Font {
-    fn to_wrapped_object(&self, loader: &PyModule, py: Python) -> PyObject {
+    fn to_wrapped_object(&self, loader: &PyAny, py: Python) -> PyObject {
         let font = loader.getattr("Font").unwrap();
+        let kwargs = font_kwargs(self, None, Glyphs::List, loader, py).unwrap();
+        font.call((), Some(kwargs)).unwrap().into()
+    }
+}
 
-        let kwargs = [
-            ("lib", self.lib.to_object(py)),
-            ("layers", wrap_layerset(&self.layers, loader, py)),
-            ("info", self.font_info.to_wrapped_object(loader, py)),
-            (
-                "features",
-                self.features
-                    .as_ref()
-                    .map_or("", |v| v.as_str())
-                    .to_object(py),
+/// The keyword arguments for the loader's `Font` holding what norad has read,
+/// with the layers in the `layer_order` of their names if given and the glyphs
+/// of each held as `glyphs_as` says.
+fn font_kwargs<'py>(
+    ufo: &norad::Font,
+    layer_order: Option<&[String]>,
+    glyphs_as: Glyphs,
+    loader: &PyAny,
+    py: Python<'py>,
+) -> PyResult<&'py PyDict> {
+    Ok([
+        ("lib", ufo.lib.to_object(py)),
+        (
+            "layers",
+            wrap_layerset(&ufo.layers, layer_order, &ufo.lib, glyphs_as, loader, py),
+        ),
+        ("info", ufo.font_info.to_wrapped_object(loader, py)),
+        (
+            "features",
+            wrap_features(ufo.features.as_deref(), loader, py),
+        ),
+        ("groups", wrap_groups(ufo.groups.as_ref(), py)),
+        ("kerning", wrap_kerning(ufo.kerning.as_ref(), loader, py)?),
+    ]
+    .into_py_dict(py))
+}
+
+impl FromWrappedPyObject for norad::Font {
+    fn from_wrapped_object(obj: &PyAny) -> PyResult<Self> {
+        let mut font = norad::Font::new();
+        font.lib = convert_attr(obj, "lib", norad::Plist::from_object)?;
+        font.layers = convert_attr(obj, "layers", unwrap_layerset)?;
+        font.font_info = convert_attr(obj, "info", norad::FontInfo::from_wrapped_object)
+            .map(|info| Some(info).filter(|i| *i != norad::FontInfo::default()))?;
+        font.features = convert_attr(obj, "features", unwrap_features)?;
+        font.groups = convert_attr(obj, "groups", unwrap_groups)?;
+        font.kerning = convert_attr(obj, "kerning", unwrap_kerning)?;
+        Ok(font)
+    }
+}
+
+create_exception!(readwrite_ufo_glif, IondriveError, PyException);
+create_exception!(readwrite_ufo_glif, IondriveIOError, IondriveError);
+create_exception!(readwrite_ufo_glif, IondriveParseError, IondriveError);
+create_exception!(readwrite_ufo_glif, IondriveStructureError, IondriveError);
+
+/// Options shared by the functions that load a whole font.
+#[derive(Default)]
+struct LoadOptions {
+    /// The number of parsing threads, or one per CPU if not given.
+    jobs: Option<usize>,
+    parse: ParseOptions,
+    /// A callable deciding by name which glyphs to load.
+    glyph_filter: Option<PyObject>,
+    /// The names of the glyphs to load, or all glyphs if not given.
+    glyph_set: Option<HashSet<String>>,
+    /// Whether to also load the base glyphs of the components of the glyphs
+    /// selected.
+    include_components: bool,
+    /// The names of the layers to load besides the default layer, or all layers
+    /// if not given.
+    layers: Option<HashSet<String>>,
+    /// Whether a listed layer that does not exist is an error.
+    require_layers: bool,
+    /// A callable to report the number of glyphs parsed to.
+    progress: Option<PyObject>,
+    /// Whether to reuse the fonts parsed before from unchanged UFOs.
+    use_cache: bool,
+    /// Whether to inline the files included by `features.fea`.
+    resolve_feature_includes: bool,
+    /// Whether a kerning group missing from the groups is an error.
+    check_kerning_groups: bool,
+    /// Whether a component whose base glyph is missing from its layer is an
+    /// error.
+    check_components: bool,
+    /// What to do about data in the UFO that is not loaded.
+    on_unknown: unknown::OnUnknown,
+    /// Whether to create the glyphs only when they are first accessed.
+    lazy: bool,
+    /// Whether layers hold their glyphs in a dictionary by name.
+    glyphs_as_dict: bool,
+    /// Whether to read the `data` and `images` stores, which norad does not
+    /// handle.
+    read_stores: bool,
+    /// The layer to make the default layer in place of the one the UFO marks as
+    /// default.
+    default_layer_name: Option<String>,
+    /// Whether to set the info attributes that are not set and have a fixed
+    /// default.
+    populate_defaults: bool,
+    /// Whether to drop the code points a glyph lists more than once.
+    dedup_unicodes: bool,
+    /// Whether a symbolic link inside the UFO is an error.
+    forbid_symlinks: bool,
+    /// Whether to return the problems skipped while parsing rather than warning
+    /// about them.
+    collect_errors: bool,
+    /// The number of decimal places to round coordinates to, if any.
+    coord_precision: Option<u32>,
+    /// Whether whole point and component coordinates are wrapped as ints.
+    integerize_coords: bool,
+}
+
+/// A font parsed by norad, along with the stores it does not read.
+struct LoadedFont {
+    ufo: norad::Font,
+    /// The path the font was loaded from, if any.
+    path: Option<PathBuf>,
+    /// The names of the layers in the order of `layercontents.plist`, where it
+    /// differs from norad's, which keeps the default layer first.
+    layer_order: Option<Vec<String>>,
+    /// The problems skipped while parsing the font.
+    skipped: Vec<LoadError>,
+    /// The `formatVersion` and `formatVersionMinor` of the UFO, as norad gives
+    /// every font it reads format version 3.
+    format_version: (u64, u64),
+    data: data::Store,
+    images: data::Store,
+}
+
+impl LoadedFont {
+    /// The font of the UFO at `path`, whose stores stay on disk.
+    fn on_disk(ufo: norad::Font, path: &Path) -> Self {
+        LoadedFont {
+            layer_order: reader::layer_order(path, &ufo.layers),
+            format_version: source_format_version(path),
+            ufo,
+            path: Some(path.to_path_buf()),
+            skipped: Vec::new(),
+            data: data::Store::Ufo(path.to_path_buf()),
+            images: data::Store::Ufo(path.to_path_buf()),
+        }
+    }
+
+    /// The font of the UFO directory `dir`, reading its stores now as `dir` is
+    /// about to be removed. `path` is the archive it was unpacked from, if any.
+    fn unpacked(ufo: norad::Font, dir: &Path, path: Option<&Path>) -> PyResult<Self> {
+        Ok(LoadedFont {
+            layer_order: reader::layer_order(dir, &ufo.layers),
+            format_version: source_format_version(dir),
+            ufo,
+            path: path.map(Path::to_path_buf),
+            skipped: Vec::new(),
+            data: data::Store::read(dir, &data::DATA)?,
+            images: data::Store::read(dir, &data::IMAGES)?,
+        })
+    }
+
+    /// The font of the UFO directory `dir` alone, with empty stores.
+    fn without_stores(ufo: norad::Font, dir: &Path) -> Self {
+        LoadedFont {
+            layer_order: reader::layer_order(dir, &ufo.layers),
+            format_version: source_format_version(dir),
+            ufo,
+            path: None,
+            skipped: Vec::new(),
+            data: data::Store::Files(Vec::new()),
+            images: data::Store::Files(Vec::new()),
+        }
+    }
+
+    /// Wraps the font, with its layers lazy or holding their glyphs by name as
+    /// the `options` say.
+    fn to_wrapped_object(
+        &self,
+        loader: &PyAny,
+        py: Python,
+        options: &LoadOptions,
+    ) -> PyResult<PyObject> {
+        let glyphs_as = Glyphs::for_options(options, loader)?;
+        let kwargs = contourpoint::integerizing(options.integerize_coords, || {
+            font_kwargs(
+                &self.ufo,
+                self.layer_order.as_deref(),
+                glyphs_as,
+                loader,
+                py,
+            )
+        })?;
+        for (store, kind) in [(&self.data, &data::DATA), (&self.images, &data::IMAGES)] {
+            kwargs.set_item(kind.attribute(), store.to_wrapped_object(kind, loader, py)?)?;
+        }
+        if let Some(path) = &self.path {
+            let path = py.import("pathlib")?.getattr("Path")?.call1((path,))?;
+            kwargs.set_item("path", path)?;
+        }
+        Ok(loader.getattr("Font")?.call((), Some(kwargs))?.into())
+    }
+
+    /// The font and its stores packed into bytes, with the GIL released.
+    fn to_bytes(&self, py: Python) -> PyResult<PyObject> {
+        let data = self.data.files(&data::DATA)?;
+        let images = self.images.files(&data::IMAGES)?;
+        let path = self.path.as_deref();
+        let order = self.layer_order.as_deref();
+        let version = self.format_version;
+        let packed =
+            py.allow_threads(|| packed::pack(&self.ufo, path, order, version, &data, &images))?;
+        Ok(PyBytes::new(py, &packed).into())
+    }
+
+    /// The font packed into `packed` by `to_bytes`.
+    fn from_bytes(py: Python, packed: &[u8]) -> PyResult<Self> {
+        let unpacked = py.allow_threads(|| packed::unpack(packed))?;
+        Ok(LoadedFont {
+            ufo: unpacked.ufo,
+            path: unpacked.path,
+            layer_order: unpacked.layer_order,
+            format_version: unpacked.format_version,
+            skipped: Vec::new(),
+            data: data::Store::Files(unpacked.data),
+            images: data::Store::Files(unpacked.images),
+        })
+    }
+
+    /// Wraps the font as the given `backend` builds its objects.
+    fn to_backend_object(
+        &self,
+        backend: Backend,
+        loader: &PyAny,
+        py: Python,
+        options: &LoadOptions,
+    ) -> PyResult<PyObject> {
+        match backend {
+            Backend::UfoLib2 => self.to_wrapped_object(loader, py, options),
+            Backend::Defcon => contourpoint::integerizing(options.integerize_coords, || {
+                defcon::wrap_font(
+                    &self.ufo,
+                    self.layer_order.as_deref(),
+                    &[(&self.data, &data::DATA), (&self.images, &data::IMAGES)],
+                    loader,
+                    py,
+                )
+            }),
+        }
+    }
+}
+
+/// The `formatVersion` and `formatVersionMinor` of the UFO directory `dir`, which
+/// norad has loaded, so its `metainfo.plist` is readable.
+fn source_format_version(dir: &Path) -> (u64, u64) {
+    reader::read_metainfo(dir).map_or((3, 0), |m| (m.format_version, m.format_version_minor))
+}
+
+/// How the objects of a loaded font are built.
+#[derive(Clone, Copy)]
+enum Backend {
+    /// Each object is constructed from keyword arguments, as ufoLib2's are.
+    UfoLib2,
+    /// Only the font is constructed, and everything in it is added through its
+    /// methods, as with defcon.
+    Defcon,
+}
+
+impl Backend {
+    /// The backend called `name`, checking that it supports the `options`.
+    fn from_name(name: &str, options: &LoadOptions) -> PyResult<Self> {
+        match name {
+            "ufoLib2" => Ok(Backend::UfoLib2),
+            "defcon" => Backend::Defcon.check(options),
+            _ => Err(IondriveError::new_err(format!(
+                "unknown backend '{}', expected 'ufoLib2' or 'defcon'",
+                name
+            ))),
+        }
+    }
+
+    /// The backend whose object model `loader` provides, checking that it
+    /// supports the `options`.
+    ///
+    /// A `LayerSet` with a `from_iterable` class method is ufoLib2's; one with
+    /// only a `newLayer` method, next to a `Font`, is defcon's.
+    fn detect(loader: &PyAny, options: &LoadOptions) -> PyResult<Self> {
+        let layer_set = loader.getattr("LayerSet").ok();
+        let has = |name: &str| layer_set.is_some_and(|cls| cls.hasattr(name).unwrap_or(false));
+        if has("from_iterable") {
+            Ok(Backend::UfoLib2)
+        } else if has("newLayer") && loader.hasattr("Font")? {
+            Backend::Defcon.check(options)
+        } else {
+            let name = loader
+                .getattr("__name__")
+                .and_then(|name| name.extract::<String>())
+                .or_else(|_| loader.repr().map(|repr| repr.to_string()))?;
+            Err(IondriveError::new_err(format!(
+                "{} does not look like ufoLib2's or defcon's objects: its LayerSet has neither from_iterable nor newLayer",
+                name
+            )))
+        }
+    }
+
+    fn check(self, options: &LoadOptions) -> PyResult<Self> {
+        match self {
+            Backend::Defcon if options.lazy => Err(IondriveError::new_err(
+                "the defcon backend does not support lazily loaded layers",
+            )),
+            _ => Ok(self),
+        }
+    }
+}
+
+/// The load options that apply while parsing, away from the GIL.
+#[derive(Clone, Copy, Default)]
+struct ParseOptions {
+    /// Whether to skip the parts of a font that fail to parse rather than
+    /// raising an error.
+    lenient: bool,
+    /// Whether to replace components with the contours of their base glyphs.
+    decompose: bool,
+    /// Whether to leave out the font, layer and glyph libs.
+    skip_lib: bool,
+}
+
+impl LoadOptions {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        jobs: Option<usize>,
+        validate: bool,
+        glyph_filter: Option<PyObject>,
+        decompose: bool,
+        layers: Option<Vec<String>>,
+        require_layers: bool,
+        lazy: bool,
+    ) -> Self {
+        LoadOptions {
+            jobs,
+            parse: ParseOptions {
+                lenient: !validate,
+                decompose,
+                skip_lib: false,
+            },
+            glyph_filter,
+            glyph_set: None,
+            include_components: false,
+            layers: layers.map(|l| l.into_iter().collect()),
+            require_layers,
+            progress: None,
+            use_cache: false,
+            resolve_feature_includes: false,
+            check_kerning_groups: false,
+            check_components: false,
+            on_unknown: unknown::OnUnknown::Ignore,
+            lazy,
+            glyphs_as_dict: false,
+            read_stores: true,
+            default_layer_name: None,
+            populate_defaults: false,
+            dedup_unicodes: false,
+            forbid_symlinks: false,
+            collect_errors: false,
+            coord_precision: None,
+            integerize_coords: false,
+        }
+    }
+
+    /// Chooses the layers and glyphs to parse from the UFO directory `dir`.
+    ///
+    /// The glyph filter is called with each glyph name in the selected layers,
+    /// and only the names it accepts are selected. Without a filter or a list of
+    /// layers, everything is. Raises `IondriveError` for a listed layer that does
+    /// not exist if the options require all of them to.
+    fn select(&self, py: Python, dir: &Path) -> PyResult<reader::Selection> {
+        let layer_contents = reader::layer_contents(dir)?;
+        let mut selection = reader::Selection {
+            glyphs: None,
+            layers: self.layers.clone().map(|mut layers| {
+                layers.extend(self.default_layer_name.clone());
+                layers
+            }),
+        };
+        if let (Some(layers), true) = (&self.layers, self.require_layers) {
+            for name in layers {
+                if !layer_contents.iter().any(|(n, _)| n == name) {
+                    return Err(IondriveError::new_err(format!(
+                        "{} has no layer named '{}'",
+                        dir.display(),
+                        name
+                    )));
+                }
+            }
+        }
+        if self.glyph_filter.is_none() && self.glyph_set.is_none() {
+            return Ok(selection);
+        }
+        let layer_dirs: Vec<PathBuf> = layer_contents
+            .iter()
+            .filter(|(name, layer_dir)| selection.has_layer(name, layer_dir))
+            .map(|(_, layer_dir)| dir.join(layer_dir))
+            .collect();
+        let mut seen = HashSet::new();
+        let mut glyphs = HashSet::new();
+        for layer_dir in &layer_dirs {
+            for name in reader::glyph_contents(layer_dir)?.into_keys() {
+                if seen.insert(name.clone()) && self.selects(py, &name)? {
+                    glyphs.insert(name);
+                }
+            }
+        }
+        if self.include_components {
+            reader::add_component_bases(&layer_dirs, &mut glyphs)?;
+        }
+        selection.glyphs = Some(glyphs);
+        Ok(selection)
+    }
+
+    /// Whether the glyph `name` is in the glyph set and passes the glyph filter,
+    /// where these are given.
+    fn selects(&self, py: Python, name: &str) -> PyResult<bool> {
+        if let Some(glyph_set) = &self.glyph_set {
+            if !glyph_set.contains(name) {
+                return Ok(false);
+            }
+        }
+        match &self.glyph_filter {
+            Some(glyph_filter) => glyph_filter.as_ref(py).call1((name,))?.is_true(),
+            None => Ok(true),
+        }
+    }
+}
+
+/// Load and return a UFO from `path`, using the objects from `font_objects_module`.
+///
+/// The font objects module is the Python namespace containing the classes as
+/// exported by ufoLib2, typically this will be the module `ufoLib2.objects`. Any
+/// object with the same classes as attributes can be used instead.
+///
+/// `path` may also be a `.ufoz` archive, which is unpacked to a temporary
+/// directory and loaded from there.
+///
+/// UFOs in format version 1 or 2 are converted to format 3 as they are read, as
+/// ufoLib2 does: kerning groups are renamed with the `public.kern1.` and
+/// `public.kern2.` prefixes, the named single-point contours of format 1 GLIF
+/// files become anchors, and the font info is read in its older form.
+///
+/// The layers come in the order `layercontents.plist` lists them, as ufoLib2
+/// reads them, even where it does not list the default layer first.
+///
+/// Glyphs are parsed in parallel before any Python objects are created. `jobs`
+/// sets the number of parsing threads; by default, one per CPU is used.
+///
+/// If `glyph_filter` is given, it is called with each glyph name and only the
+/// glyphs for which it returns true are parsed and included in the layers. The
+/// layers themselves and their libs are kept either way.
+///
+/// If `glyph_set` is given, an iterable of glyph names, only the glyphs it names
+/// are parsed and included, as with `glyph_filter`; given both, a glyph must be
+/// in the set and pass the filter. With `include_components=True`, the base
+/// glyphs of the components of the selected glyphs are loaded too, and theirs in
+/// turn, even where `glyph_set` or `glyph_filter` leave them out, so composites
+/// keep their bases.
+///
+/// With `decompose=True`, the components of each glyph are replaced by the
+/// contours of their base glyphs from the same layer, transformed as the
+/// components are, resolving nested components recursively. A component whose
+/// base glyph is missing, including one left out by `glyph_filter`, raises
+/// `IondriveError`.
+///
+/// If `layers` is given, only the layers it names are parsed and included, along
+/// with the default layer, which a font always has. Names of layers the UFO does
+/// not have are ignored, unless `require_layers=True`, which makes them raise
+/// `IondriveError`.
+///
+/// With `default_layer_name`, the layer of that name is made the default layer
+/// in place of the one the UFO marks as default, and comes first in the layer
+/// set. It is loaded even if `layers` does not name it. A name the UFO has no
+/// layer for raises `IondriveError`.
+///
+/// With `populate_defaults=True`, the info attributes that are not set are given
+/// their default values where these do not depend on the rest of the font:
+///
+/// - `unitsPerEm` 1000, `versionMajor` and `versionMinor` 0 and `italicAngle` 0;
+/// - `openTypeHeadFlags` `[]` and `openTypeHeadLowestRecPPEM` 6;
+/// - `openTypeHheaCaretOffset` and `openTypeHheaLineGap` 0;
+/// - `openTypeOS2WeightClass` 400, `openTypeOS2WidthClass` 5, and
+///   `openTypeOS2Selection` and `openTypeOS2Type` `[]`;
+/// - `postscriptBlueValues`, `postscriptOtherBlues`, `postscriptFamilyBlues`,
+///   `postscriptFamilyOtherBlues`, `postscriptStemSnapH` and
+///   `postscriptStemSnapV` `[]`;
+/// - `postscriptBlueFuzz` 1, `postscriptBlueShift` 7, `postscriptBlueScale`
+///   0.039625, `postscriptForceBold` and `postscriptIsFixedPitch` `False`, and
+///   `postscriptDefaultWidthX` and `postscriptNominalWidthX` 0.
+///
+/// The others, such as the names and the vertical metrics, stay `None`. A UFO
+/// without a `fontinfo.plist` gets an `Info` with these defaults too.
+///
+/// With `dedup_unicodes=True`, a code point listed more than once on a glyph is
+/// kept only where it first appears, as some compilers reject duplicates. By
+/// default the glyphs' `unicodes` are left as the UFO lists them.
+///
+/// With `coord_precision`, a number of decimal places, the `x` and `y` of every
+/// contour point, anchor and guideline, the guidelines of the font info among
+/// them, and the offsets of every component are rounded to that many places,
+/// with halves rounded to the even neighbour, so `0.125` becomes `0.12` and
+/// `0.375` becomes `0.38` with `coord_precision=2`. It applies after
+/// `decompose`, to the contours it adds. Guideline angles, component scales and
+/// advances are left as they are. By default nothing is rounded.
+///
+/// Contour point coordinates and component transformations are Python `float`s
+/// by default. With `integerize_coords=True`, those that are whole are `int`s
+/// instead, so `120.0` comes out as `120`, and fractional ones stay `float`s.
+/// Anchors and guidelines are `float`s either way.
+///
+/// With `lazy=True`, the glyphs are still parsed up front, but their Python
+/// objects are only created when a glyph is first accessed in its layer, which
+/// then keeps it. This needs a font objects module with lazily loaded layers,
+/// as ufoLib2 has. The parsed layers stay in memory for as long as their Python
+/// layers do.
+///
+/// By default, anything norad rejects raises a subclass of `IondriveError`:
+/// `IondriveIOError` for missing or unreadable files, `IondriveParseError` for
+/// malformed XML, plists or GLIF, and `IondriveStructureError` for files that
+/// parse but do not describe a valid font, such as overlapping kerning groups.
+/// Parse errors start with the failing file, relative to the UFO, and the line
+/// where norad reports one, as in `glyphs/A_.glif:12: Unexpected element`.
+/// Contours whose point types break the GLIF rules, such as a `move` point that
+/// does not start the contour or a `line` point after an off-curve point, are
+/// parse errors that also name the glyph and the index of the contour. With
+/// `validate=False`, a font that norad rejects is loaded as far as possible
+/// instead: glyphs whose GLIF files cannot be parsed are left out, layers with a
+/// broken `layerinfo.plist` lose their color and lib, and groups and kerning are
+/// dropped if `groups.plist` or `kerning.plist` is malformed or fails norad's
+/// checks, such as a glyph being in two kerning groups of the same side. A glyph
+/// that a layer's `contents.plist` lists more than once, which raises
+/// `IondriveStructureError` by default, is read from the first file listed. A
+/// `metainfo.plist`, `fontinfo.plist`, `lib.plist`, `groups.plist` or
+/// `kerning.plist` that is not UTF-8, as some legacy tools save them, is read as
+/// latin-1. A `UserWarning` is issued for each thing skipped. Other problems with
+/// `metainfo.plist`, `fontinfo.plist`, `lib.plist`, `features.fea`,
+/// `layercontents.plist` and the layers' `contents.plist` files are still errors.
+/// Plists starting with a UTF-8 byte order mark are read either way. Glyph
+/// guidelines with angles outside 0 to 360 degrees, which norad rejects, have
+/// them brought into `[0, 360)`, so that -45 degrees becomes 315, or passed
+/// through as they are in the GLIF file with `validate=False`. Such angles in
+/// the guidelines of `fontinfo.plist` are still errors.
+///
+/// If `read` is given, an iterable of `"layers"`, `"lib"`, `"groups"`,
+/// `"kerning"`, `"features"`, `"data"` and `"images"`, only those parts of the
+/// UFO are read, and norad is not asked for the others, which are left empty as
+/// if the UFO had none. Without `"layers"` the font has an empty default layer;
+/// without `"lib"` the libs of the layers and glyphs are still read, unlike with
+/// `include_lib=False`. Any other name raises `IondriveError`.
+///
+/// With `collect_errors=True`, the font is loaded as far as possible as with
+/// `validate=False`, whatever `validate` says, but the things skipped are
+/// returned instead of warned about, in a `(font, errors)` tuple. Each error is a
+/// dictionary of its `kind`, `file`, `line` and `message`, as `verify` gives
+/// them, so a glyph whose GLIF file cannot be parsed is reported with the file,
+/// relative to the UFO, and the reason. The list is empty if nothing was skipped.
+/// With `stats=True` too, a `(font, stats, errors)` tuple is returned.
+///
+/// `progress` is optional, and nothing is reported without it. If given, it is
+/// called with `(glyphs_done, glyphs_total)` on the calling thread: once before
+/// parsing starts, after every 100 glyphs parsed, and once at the end. The GIL is
+/// released in between. If it raises, the load is stopped and the exception
+/// propagates. Glyphs are then parsed one at a time rather than a layer at a
+/// time, which is slightly slower.
+///
+/// With `use_cache=True`, the font parsed from a UFO directory is kept, and a
+/// later `load` of the same UFO with `use_cache=True` and the same options reuses
+/// it as long as no file in the UFO has been modified, added or removed since.
+/// The font returned is always made of new objects, so changing it does not
+/// affect the cache or other fonts. `.ufoz` archives are never cached.
+/// `clear_cache` empties the cache.
+///
+/// With `resolve_feature_includes=True`, each `include(...)` statement in
+/// `features.fea` is replaced by the contents of the file it names, recursively,
+/// so that `font.features` does not depend on other files. Relative paths are
+/// looked up in the UFO directory, then in the directory containing it. A
+/// missing include raises `IondriveIOError`, and a file including itself,
+/// directly or not, raises `IondriveStructureError`.
+///
+/// `backend` says how the font objects are built. With `"ufoLib2"`, every
+/// object is constructed from keyword arguments, as ufoLib2's classes take
+/// them. With `"defcon"`, only `font_objects_module.Font` is constructed,
+/// without arguments, and everything else is added through the font's own
+/// methods as defcon's API has them: layers with `newLayer`, glyphs with
+/// `newGlyph`, and outlines drawn into each glyph's point pen. Pass the `defcon`
+/// module itself as the font objects module. Lazy loading is not supported with
+/// it. By default the backend is detected from the font objects module's
+/// `LayerSet`: one with a `from_iterable` class method is ufoLib2's, and one
+/// with only a `newLayer` method, next to a `Font`, is defcon's. A module that
+/// looks like neither raises `IondriveError`.
+///
+/// `on_unknown` says what to do about data in the UFO that is not loaded, such
+/// as `fontinfo.plist` or `layerinfo.plist` keys the font objects have no
+/// attribute for, files the UFO specification does not define, and glyph files
+/// no layer's `contents.plist` lists. With the default, `"ignore"`, it is dropped
+/// silently. `"warn"` issues a `UserWarning` for each item, and `"error"` raises
+/// `IondriveError` listing them all before anything is parsed.
+///
+/// With `include_lib=False`, the font lib and the libs of the layers and glyphs
+/// are parsed but not wrapped, and are empty dictionaries in the font returned.
+/// This saves time and memory on fonts with large libs that are not needed. The
+/// glyph order in the font lib is left out too, so the glyphs of each layer come
+/// in the layer's own order.
+///
+/// With `check_kerning_groups=True`, every kerning group the kerning refers to,
+/// by a name starting with `public.kern1.` on the left side of a pair or
+/// `public.kern2.` on the right, must be in the groups. Otherwise,
+/// `IondriveStructureError` is raised listing the missing left and right groups.
+///
+/// With `check_components=True`, the base glyph of every component must be in
+/// the layer of its composite, once all the layer's glyphs are loaded. Otherwise,
+/// `IondriveStructureError` is raised listing the composites and the base glyphs
+/// they miss. Glyphs left out by `glyph_filter` count as missing.
+///
+/// Symbolic links inside the UFO, such as layer directories shared between UFOs,
+/// are followed. A UFO from an untrusted source can use them to have files from
+/// anywhere on the system read into the font, say as its data files. With
+/// `follow_symlinks=False`, any symbolic link inside the UFO raises
+/// `IondriveIOError` before anything is parsed. The path given may itself be a
+/// link.
+///
+/// With `stats=True`, a `(font, stats)` tuple is returned, where `stats` is a
+/// dictionary of how the load went: `parse_time`, the seconds spent reading and
+/// parsing the UFO, `wrap_time`, the seconds spent creating the Python objects,
+/// and `glyph_count` and `layer_count`, the number of glyphs in all layers and of
+/// layers loaded. With `lazy=True`, `wrap_time` leaves out the glyphs, which are
+/// created later. `format_version` is the `(formatVersion, formatVersionMinor)`
+/// of the UFO's `metainfo.plist`, the minor version 0 if it has none, such as
+/// `(3, 0)`. It is the version of the source, as the font loaded is always in
+/// format 3, and can be passed on when the font is saved.
+///
+/// With `glyphs_as_dict=True`, each `font_objects_module.Layer` is given its
+/// glyphs as a dictionary by glyph name, in the same order as the list it gets
+/// otherwise. The glyphs themselves are the same. Lazy layers and the defcon
+/// backend already key glyphs by name, so it changes nothing for them.
+///
+/// With `as_bytes=True`, no Python objects are created: the font as loaded is
+/// packed, with its data and images, into a `bytes` object that `from_bytes`
+/// turns into a `Font` object. This is meant for sending fonts to worker
+/// processes, as the bytes pickle as a single object however large the font,
+/// and the process loading the fonts skips building their objects. Glyphs are
+/// packed field by field, so unpacking them parses no XML, but `from_bytes`
+/// still builds every object, which takes as long as it does in `load`; the
+/// format is internal and only meant to be read by the same version of
+/// iondrive. `lazy`, `backend` and `glyphs_as_dict` are ignored, and with
+/// `stats=True`, `wrap_time` is the time spent packing.
+///
+/// `load` may be called from several Python threads at once, each loading its
+/// own font. The fonts parsed with `use_cache=True` are shared between threads,
+/// but every call wraps its own copy.
+#[pyfunction(
+    jobs = "None",
+    validate = "true",
+    glyph_filter = "None",
+    decompose = "false",
+    layers = "None",
+    require_layers = "false",
+    lazy = "false",
+    progress = "None",
+    use_cache = "false",
+    resolve_feature_includes = "false",
+    backend = "None",
+    on_unknown = "\"ignore\"",
+    include_lib = "true",
+    check_kerning_groups = "false",
+    stats = "false",
+    glyphs_as_dict = "false",
+    as_bytes = "false",
+    default_layer_name = "None",
+    populate_defaults = "false",
+    dedup_unicodes = "false",
+    check_components = "false",
+    follow_symlinks = "true",
+    glyph_set = "None",
+    include_components = "false",
+    collect_errors = "false",
+    coord_precision = "None",
+    integerize_coords = "false",
+    read = "None"
+)]
+#[pyo3(
+    text_signature = "(font_objects_module, path, /, jobs=None, validate=True, glyph_filter=None, decompose=False, layers=None, require_layers=False, lazy=False, progress=None, use_cache=False, resolve_feature_includes=False, backend=None, on_unknown='ignore', include_lib=True, check_kerning_groups=False, stats=False, glyphs_as_dict=False, as_bytes=False, default_layer_name=None, populate_defaults=False, dedup_unicodes=False, check_components=False, follow_symlinks=True, glyph_set=None, include_components=False, collect_errors=False, coord_precision=None, integerize_coords=False, read=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+fn load(
+    loader: &PyAny,
+    path: PathBuf,
+    jobs: Option<usize>,
+    validate: bool,
+    glyph_filter: Option<PyObject>,
+    decompose: bool,
+    layers: Option<Vec<String>>,
+    require_layers: bool,
+    lazy: bool,
+    progress: Option<PyObject>,
+    use_cache: bool,
+    resolve_feature_includes: bool,
+    backend: Option<String>,
+    on_unknown: &str,
+    include_lib: bool,
+    check_kerning_groups: bool,
+    stats: bool,
+    glyphs_as_dict: bool,
+    as_bytes: bool,
+    default_layer_name: Option<String>,
+    populate_defaults: bool,
+    dedup_unicodes: bool,
+    check_components: bool,
+    follow_symlinks: bool,
+    glyph_set: Option<PyObject>,
+    include_components: bool,
+    collect_errors: bool,
+    coord_precision: Option<u32>,
+    integerize_coords: bool,
+    read: Option<PyObject>,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let mut options = LoadOptions::new(
+            jobs,
+            validate,
+            glyph_filter,
+            decompose,
+            layers,
+            require_layers,
+            lazy,
+        );
+        options.progress = progress;
+        options.use_cache = use_cache;
+        options.resolve_feature_includes = resolve_feature_includes;
+        options.on_unknown = unknown::OnUnknown::from_name(on_unknown)?;
+        options.parse.skip_lib = !include_lib;
+        options.check_kerning_groups = check_kerning_groups;
+        options.glyphs_as_dict = glyphs_as_dict;
+        options.default_layer_name = default_layer_name;
+        options.populate_defaults = populate_defaults;
+        options.dedup_unicodes = dedup_unicodes;
+        options.check_components = check_components;
+        options.forbid_symlinks = !follow_symlinks;
+        options.glyph_set = glyph_set
+            .map(|names| {
+                names
+                    .as_ref(py)
+                    .iter()?
+                    .map(|name| name?.extract())
+                    .collect()
+            })
+            .transpose()?;
+        options.include_components = include_components;
+        options.collect_errors = collect_errors;
+        options.coord_precision = coord_precision;
+        options.integerize_coords = integerize_coords;
+        if collect_errors {
+            options.parse.lenient = true;
+        }
+        let backend = match backend.as_deref() {
+            Some(name) => Backend::from_name(name, &options)?,
+            None => Backend::detect(loader, &options)?,
+        };
+        let read = parts_to_read(py, read)?;
+        let mut request = norad::DataRequest::all();
+        request
+            .layers(read.contains("layers"))
+            .lib(read.contains("lib"))
+            .groups(read.contains("groups"))
+            .kerning(read.contains("kerning"))
+            .features(read.contains("features"));
+        let start = Instant::now();
+        let mut font = load_font(py, &path, request, &options)?;
+        if !read.contains("data") {
+            font.data = data::Store::Files(Vec::new());
+        }
+        if !read.contains("images") {
+            font.images = data::Store::Files(Vec::new());
+        }
+        let parse_time = start.elapsed();
+        let start = Instant::now();
+        let wrapped = match as_bytes {
+            true => font.to_bytes(py)?,
+            false => font.to_backend_object(backend, loader, py, &options)?,
+        };
+        let wrap_time = start.elapsed();
+        let errors = match collect_errors {
+            true => Some(
+                font.skipped
+                    .iter()
+                    .map(|e| e.to_diagnostic(py))
+                    .collect::<PyResult<Vec<_>>>()?,
             ),
+            false => None,
+        };
+        if !stats {
+            return Ok(match errors {
+                Some(errors) => (wrapped, errors).to_object(py),
+                None => wrapped,
+            });
+        }
+        let layers = &font.ufo.layers;
+        let stats = [
+            ("parse_time", parse_time.as_secs_f64().to_object(py)),
+            ("wrap_time", wrap_time.as_secs_f64().to_object(py)),
             (
-                "groups",
-                self.groups
-                    .as_ref()
-                    .map_or(PyDict::new(py).to_object(py), |v| v.to_object(py)),
+                "glyph_count",
+                layers.iter().map(|l| l.len()).sum::<usize>().to_object(py),
             ),
-            ("kerning", wrap_kerning(self.kerning.as_ref(), py)),
+            ("layer_count", layers.len().to_object(py)),
+            ("format_version", font.format_version.to_object(py)),
         ]
         .into_py_dict(py);
-        font.call((), Some(kwargs)).unwrap().into()
+        Ok(match errors {
+            Some(errors) => (wrapped, stats, errors).to_object(py),
+            None => (wrapped, stats).to_object(py),
+        })
+    })
+}
+
+/// The parts of `UFO_PARTS` that the `read` names ask for, all of them if none
+/// are given.
+fn parts_to_read(py: Python, read: Option<PyObject>) -> PyResult<HashSet<String>> {
+    let names = match read {
+        Some(names) => names
+            .as_ref(py)
+            .iter()?
+            .map(|name| name?.extract())
+            .collect::<PyResult<HashSet<String>>>()?,
+        None => return Ok(UFO_PARTS.iter().map(|part| part.to_string()).collect()),
+    };
+    match names
+        .iter()
+        .find(|name| !UFO_PARTS.contains(&name.as_str()))
+    {
+        Some(name) => Err(IondriveError::new_err(format!(
+            "unknown part '{}' to read, expected one of {}",
+            name,
+            UFO_PARTS.join(", ")
+        ))),
+        None => Ok(names),
     }
 }
 
-create_exception!(readwrite_ufo_glif, IondriveError, PyException);
+/// Load a list of UFOs and return them as a list of `Font` objects.
+///
+/// Each path may be a UFO directory or a `.ufoz` archive. The UFOs are parsed in
+/// parallel, without holding the GIL; only the Python objects are built
+/// afterwards. `jobs`, `validate`, `glyph_filter`, `decompose`, `layers`,
+/// `require_layers` and `lazy` are as for `load`, and the backend is chosen from
+/// the objects module as it is there. `load`'s other options, such as `read`,
+/// `use_cache` and `collect_errors`, are not taken, and their defaults apply. If
+/// any UFO fails to load, the error names every failing path as well as those
+/// that loaded successfully.
+#[pyfunction(
+    jobs = "None",
+    validate = "true",
+    glyph_filter = "None",
+    decompose = "false",
+    layers = "None",
+    require_layers = "false",
+    lazy = "false"
+)]
+#[pyo3(
+    text_signature = "(font_objects_module, paths, /, jobs=None, validate=True, glyph_filter=None, decompose=False, layers=None, require_layers=False, lazy=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+fn load_many(
+    loader: &PyAny,
+    paths: Vec<PathBuf>,
+    jobs: Option<usize>,
+    validate: bool,
+    glyph_filter: Option<PyObject>,
+    decompose: bool,
+    layers: Option<Vec<String>>,
+    require_layers: bool,
+    lazy: bool,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let options = LoadOptions::new(
+            jobs,
+            validate,
+            glyph_filter,
+            decompose,
+            layers,
+            require_layers,
+            lazy,
+        );
+        Ok(load_fonts(py, loader, &paths, &options)?.to_object(py))
+    })
+}
 
-/// Load and return a UFO from `path`, using the objects from `font_objects_module`.
+/// Loads and wraps the UFOs at `paths` as `load_font` does, parsing them in
+/// parallel without the GIL and building them for the backend `loader` provides.
+/// Failures are reported together, as for `load_many`.
+fn load_fonts(
+    py: Python,
+    loader: &PyAny,
+    paths: &[PathBuf],
+    options: &LoadOptions,
+) -> PyResult<Vec<PyObject>> {
+    // Archives are unpacked and glyphs selected up front, as both need the GIL.
+    let prepared = paths
+        .iter()
+        .map(|path| {
+            let extracted = match ufoz::is_ufoz(path) {
+                true => Some(ufoz::extract(py, path)?),
+                false => None,
+            };
+            let dir = extracted.as_ref().map_or(path.as_path(), |e| e.path());
+            let selection = options.select(py, dir)?;
+            Ok((extracted, selection))
+        })
+        .map(|prepared: PyResult<_>| {
+            prepared.map_err(|e| LoadError::new(Category::Other, e.pvalue(py).to_string()))
+        })
+        .collect::<Vec<_>>();
+    let backend = Backend::detect(loader, options)?;
+    let pool = thread_pool(options.jobs)?;
+    let request = norad::DataRequest::all();
+    let parse_options = options.parse;
+    let results: Vec<Result<(norad::Font, Vec<LoadError>), LoadError>> = py.allow_threads(|| {
+        pool.install(|| {
+            paths
+                .par_iter()
+                .zip(prepared.par_iter())
+                .map(|(path, prepared)| {
+                    let (extracted, selection) = prepared.as_ref().map_err(LoadError::clone)?;
+                    let dir = extracted.as_ref().map_or(path.as_path(), |e| e.path());
+                    parse_font(dir, request, parse_options, selection, None)
+                })
+                .collect()
+        })
+    });
+
+    let mut fonts = Vec::new();
+    let mut failed = Vec::new();
+    let mut loaded = Vec::new();
+    for ((path, prepared), result) in paths.iter().zip(&prepared).zip(results) {
+        match result {
+            Err(error) => failed.push(error.context(path.display())),
+            Ok((mut ufo, skipped)) => {
+                warn_skipped(py, &path.display().to_string(), &skipped)?;
+                let parent = path.parent().unwrap_or_else(|| Path::new(""));
+                let font = match prepared {
+                    Ok((Some(extracted), _)) => {
+                        finish_font(&mut ufo, &[extracted.path(), parent], options)?;
+                        LoadedFont::unpacked(ufo, extracted.path(), Some(path))?
+                    }
+                    _ => {
+                        finish_font(&mut ufo, &[path, parent], options)?;
+                        LoadedFont::on_disk(ufo, path)
+                    }
+                };
+                loaded.push(path.display().to_string());
+                fonts.push(font.to_backend_object(backend, loader, py, options)?);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        let loaded = match loaded.is_empty() {
+            true => "none".to_string(),
+            false => loaded.join(", "),
+        };
+        // All failures of one kind are raised as that kind.
+        let category = match failed.iter().all(|e| e.category == failed[0].category) {
+            true => failed[0].category,
+            false => Category::Other,
+        };
+        let message = format!(
+            "failed to load {} of {} UFOs: {}; loaded successfully: {}",
+            failed.len(),
+            paths.len(),
+            failed
+                .iter()
+                .map(LoadError::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
+            loaded
+        );
+        let mut error = LoadError::new(category, message);
+        error.kind = failed[0]
+            .kind
+            .filter(|_| failed.iter().all(|e| e.kind == failed[0].kind));
+        return Err(error.into());
+    }
+    Ok(fonts)
+}
+
+/// Load the designspace file at `path` and the UFOs of its sources, and return it
+/// as a fontTools `DesignSpaceDocument`.
 ///
-/// The font objects module is the Python namespace containing the classes as
-/// exported by ufoLib2, typically this will be the module `ufoLib2.objects`.
+/// The document holds the axes, sources and instances as fontTools reads them,
+/// with the `font` of each source set to its loaded `Font`. Source UFOs are found
+/// relative to the designspace file, and each is loaded once, in parallel as for
+/// `load_many`, even if several sources use its layers. The other arguments are
+/// as for `load_many`, which takes only some of `load`'s options.
+///
+/// Raises `IondriveIOError` naming the `<source>` element if a source UFO does
+/// not exist.
+#[pyfunction(
+    jobs = "None",
+    validate = "true",
+    glyph_filter = "None",
+    decompose = "false",
+    layers = "None",
+    require_layers = "false",
+    lazy = "false"
+)]
+#[pyo3(
+    text_signature = "(font_objects_module, path, /, jobs=None, validate=True, glyph_filter=None, decompose=False, layers=None, require_layers=False, lazy=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+fn load_designspace(
+    loader: &PyAny,
+    path: PathBuf,
+    jobs: Option<usize>,
+    validate: bool,
+    glyph_filter: Option<PyObject>,
+    decompose: bool,
+    layers: Option<Vec<String>>,
+    require_layers: bool,
+    lazy: bool,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let options = LoadOptions::new(
+            jobs,
+            validate,
+            glyph_filter,
+            decompose,
+            layers,
+            require_layers,
+            lazy,
+        );
+        let document = designspace::read_document(py, &path)?;
+        let source_paths = designspace::source_paths(document, &path)?;
+        let mut unique = Vec::new();
+        for source_path in &source_paths {
+            if !unique.contains(source_path) {
+                unique.push(source_path.clone());
+            }
+        }
+        let fonts = load_fonts(py, loader, &unique, &options)?;
+        for (source, source_path) in document.getattr("sources")?.iter()?.zip(&source_paths) {
+            let index = unique.iter().position(|p| p == source_path).unwrap();
+            source?.setattr("font", &fonts[index])?;
+        }
+        Ok(document.into())
+    })
+}
+
+/// Load a UFO from `data`, the contents of a `.ufoz` archive, and return it as a
+/// `Font` object.
+///
+/// The result is the same as loading the unpacked UFO with `load`, with the
+/// options `load_many` takes; `load`'s others, such as `read`, are not taken,
+/// and their defaults apply. As norad only reads UFOs from disk, the archive is
+/// unpacked into a temporary directory that is removed again before returning.
+#[pyfunction(
+    jobs = "None",
+    validate = "true",
+    glyph_filter = "None",
+    decompose = "false",
+    layers = "None",
+    require_layers = "false",
+    lazy = "false"
+)]
+#[pyo3(
+    text_signature = "(font_objects_module, data, /, jobs=None, validate=True, glyph_filter=None, decompose=False, layers=None, require_layers=False, lazy=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+fn load_bytes(
+    loader: &PyAny,
+    data: &[u8],
+    jobs: Option<usize>,
+    validate: bool,
+    glyph_filter: Option<PyObject>,
+    decompose: bool,
+    layers: Option<Vec<String>>,
+    require_layers: bool,
+    lazy: bool,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let options = LoadOptions::new(
+            jobs,
+            validate,
+            glyph_filter,
+            decompose,
+            layers,
+            require_layers,
+            lazy,
+        );
+        load_archive(py, loader, data, "<bytes>", &options)
+    })
+}
+
+/// Return the `Font` object of `data`, the bytes that `load` returns with
+/// `as_bytes=True`.
+///
+/// The options given to `load` were applied when the bytes were made, so the
+/// font is built as `load` builds it with its default options. `data` must come
+/// from the same version of iondrive; other bytes raise `IondriveParseError`.
+#[pyfunction]
+#[pyo3(text_signature = "(font_objects_module, data, /)")]
+fn from_bytes(loader: &PyAny, data: &[u8]) -> PyResult<PyObject> {
+    let py = loader.py();
+    LoadedFont::from_bytes(py, data)?.to_wrapped_object(loader, py, &LoadOptions::default())
+}
+
+/// Load a UFO from `fileobj`, an object whose `read()` returns the contents of a
+/// `.ufoz` archive, and return it as a `Font` object.
+///
+/// The whole file is read into memory, after which this is the same as
+/// `load_bytes`. Raises `TypeError` if `fileobj` has no `read` method.
+#[pyfunction(
+    jobs = "None",
+    validate = "true",
+    glyph_filter = "None",
+    decompose = "false",
+    layers = "None",
+    require_layers = "false",
+    lazy = "false"
+)]
+#[pyo3(
+    text_signature = "(font_objects_module, fileobj, /, jobs=None, validate=True, glyph_filter=None, decompose=False, layers=None, require_layers=False, lazy=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+fn load_fileobj(
+    loader: &PyAny,
+    fileobj: &PyAny,
+    jobs: Option<usize>,
+    validate: bool,
+    glyph_filter: Option<PyObject>,
+    decompose: bool,
+    layers: Option<Vec<String>>,
+    require_layers: bool,
+    lazy: bool,
+) -> PyResult<PyObject> {
+    let py = fileobj.py();
+    if !fileobj.hasattr("read")? {
+        return Err(PyTypeError::new_err(format!(
+            "'{}' object has no read method",
+            fileobj.get_type().name()?
+        )));
+    }
+    let data = fileobj.call_method0("read")?;
+    let data = data.downcast::<PyBytes>().map_err(|_| {
+        PyTypeError::new_err(format!(
+            "read() returned '{}', expected bytes",
+            data.get_type().name().unwrap_or("?")
+        ))
+    })?;
+    let options = LoadOptions::new(
+        jobs,
+        validate,
+        glyph_filter,
+        decompose,
+        layers,
+        require_layers,
+        lazy,
+    );
+    // Files opened by name say so; other file objects have no name to give.
+    let description = fileobj
+        .getattr("name")
+        .and_then(|name| name.extract::<String>())
+        .unwrap_or_else(|_| "<file object>".to_string());
+    load_archive(py, loader, data.as_bytes(), &description, &options)
+}
+
+/// Parses `data`, the contents of a `.ufoz` archive, and wraps it. The archive
+/// is called `description` in errors and warnings.
+fn load_archive(
+    py: Python,
+    loader: &PyAny,
+    data: &[u8],
+    description: &str,
+    options: &LoadOptions,
+) -> PyResult<PyObject> {
+    let backend = Backend::detect(loader, options)?;
+    let extracted = ufoz::extract_bytes(py, data, description)?;
+    let (mut ufo, _) = load_dir(
+        py,
+        extracted.path(),
+        description,
+        norad::DataRequest::all(),
+        options,
+        false,
+    )?;
+    finish_font(&mut ufo, &[extracted.path()], options)?;
+    LoadedFont::unpacked(ufo, extracted.path(), None)?
+        .to_backend_object(backend, loader, py, options)
+}
+
+/// Parses the parts of the UFO at `path` selected by `request`. The UFO may be a
+/// directory or a `.ufoz` archive.
+///
+/// The stores norad does not read are only read here if the options ask for
+/// them and the UFO is an archive; otherwise they are left to be read when
+/// wrapped.
+fn load_font(
+    py: Python,
+    path: &Path,
+    request: norad::DataRequest,
+    options: &LoadOptions,
+) -> PyResult<LoadedFont> {
+    let description = path.display().to_string();
+    let parent = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut font = if ufoz::is_ufoz(path) {
+        let extracted = ufoz::extract(py, path)?;
+        let (mut ufo, skipped) =
+            load_dir(py, extracted.path(), &description, request, options, false)?;
+        finish_font(&mut ufo, &[extracted.path(), parent], options)?;
+        let font = match options.read_stores {
+            true => LoadedFont::unpacked(ufo, extracted.path(), Some(path))?,
+            false => LoadedFont::without_stores(ufo, extracted.path()),
+        };
+        LoadedFont { skipped, ..font }
+    } else {
+        let (mut ufo, skipped) =
+            load_dir(py, path, &description, request, options, options.use_cache)?;
+        finish_font(&mut ufo, &[path, parent], options)?;
+        LoadedFont {
+            skipped,
+            ..LoadedFont::on_disk(ufo, path)
+        }
+    };
+    // A default layer chosen in place of the UFO's comes first.
+    if options.default_layer_name.is_some() {
+        font.layer_order = None;
+    }
+    Ok(font)
+}
+
+/// Applies the options that work on the whole parsed font: inlining the files
+/// included by its features, which are looked up in the directories of `search`,
+/// checking its kerning groups and components, choosing its default layer,
+/// filling in the info defaults, removing duplicate code points and rounding
+/// coordinates.
+fn finish_font(ufo: &mut norad::Font, search: &[&Path], options: &LoadOptions) -> PyResult<()> {
+    if options.resolve_feature_includes {
+        if let Some(features) = &ufo.features {
+            ufo.features = Some(features::resolve_includes(features, search)?);
+        }
+    }
+    if options.check_kerning_groups {
+        kerning::check_groups(ufo)?;
+    }
+    if options.check_components {
+        component::check_bases(ufo)?;
+    }
+    if let Some(name) = &options.default_layer_name {
+        set_default_layer(ufo, name)?;
+    }
+    if options.populate_defaults {
+        info::populate_defaults(ufo.font_info.get_or_insert_with(Default::default));
+    }
+    if options.dedup_unicodes {
+        dedup_unicodes(ufo);
+    }
+    if let Some(digits) = options.coord_precision {
+        precision::round_coordinates(ufo, digits);
+    }
+    Ok(())
+}
+
+/// Removes the code points each glyph of `ufo` lists again after their first
+/// occurrence, keeping the order of the others.
+fn dedup_unicodes(ufo: &mut norad::Font) {
+    let layer_names: Vec<_> = ufo.layers.names().cloned().collect();
+    for layer_name in layer_names {
+        let layer = ufo.layers.get_mut(&layer_name).unwrap();
+        let duplicated: Vec<_> = layer
+            .iter()
+            .filter(|g| g.codepoints.iter().collect::<HashSet<_>>().len() < g.codepoints.len())
+            .map(|g| g.name.clone())
+            .collect();
+        for name in duplicated {
+            let glyph = layer.get_glyph_mut(&*name).unwrap();
+            let mut seen = HashSet::new();
+            glyph.codepoints.retain(|c| seen.insert(*c));
+        }
+    }
+}
+
+/// Makes the layer `name` of `ufo` its default layer, moving it first as norad
+/// expects. The layer that was the default keeps its place after it, in the
+/// directory a layer of its name would get.
+fn set_default_layer(ufo: &mut norad::Font, name: &str) -> PyResult<()> {
+    let mut layers: Vec<norad::Layer> = ufo.layers.iter().cloned().collect();
+    let index = layers
+        .iter()
+        .position(|l| **l.name() == *name)
+        .ok_or_else(|| {
+            IondriveError::new_err(format!("cannot make missing layer '{}' the default", name))
+        })?;
+    if index == 0 {
+        return Ok(());
+    }
+    let old = &layers[0];
+    let dir = norad::util::default_file_name_for_layer_name(old.name());
+    let mut moved = norad::Layer::new(old.name().clone(), Some(dir.into()));
+    moved.color = old.color.clone();
+    moved.lib = old.lib.clone();
+    for glyph in old.iter() {
+        moved.insert_glyph(glyph.clone());
+    }
+    layers[0] = moved;
+    layers[..=index].rotate_right(1);
+    ufo.layers = norad::LayerSet::new(layers);
+    Ok(())
+}
+
+/// Parses the UFO directory at `dir`, warning about anything skipped unless the
+/// options collect the problems skipped, which are returned along with the font
+/// either way. The UFO is called `description` in warnings.
+///
+/// If `use_cache`, a font parsed before from the same directory, unchanged and
+/// with the same options, is reused instead of parsing it again.
+fn load_dir(
+    py: Python,
+    dir: &Path,
+    description: &str,
+    request: norad::DataRequest,
+    options: &LoadOptions,
+    use_cache: bool,
+) -> PyResult<(norad::Font, Vec<LoadError>)> {
+    if options.forbid_symlinks {
+        reader::check_no_symlinks(dir)?;
+    }
+    let selection = match request.layers {
+        true => options.select(py, dir)?,
+        false => reader::Selection::default(),
+    };
+    options.on_unknown.check(py, dir, description)?;
+    let parse_options = options.parse;
+    let cache_key = match use_cache {
+        true => Some(cache::Key::new(
+            dir,
+            parse_options.lenient,
+            parse_options.decompose,
+            parse_options.skip_lib,
+            request,
+            &selection,
+        )?),
+        false => None,
+    };
+    if let Some(cached) = cache_key.as_ref().and_then(cache::get) {
+        if !options.collect_errors {
+            warn_skipped(py, description, &cached.skipped)?;
+        }
+        return Ok((cached.ufo, cached.skipped));
+    }
+    let pool = match options.jobs {
+        Some(_) => Some(thread_pool(options.jobs)?),
+        None => None,
+    };
+    let parse = |progress: Option<&progress::Progress>| {
+        let parse = || parse_font(dir, request, parse_options, &selection, progress);
+        match &pool {
+            Some(pool) => pool.install(parse),
+            None => parse(),
+        }
+    };
+    // Other Python threads may run while the UFO is parsed; the GIL is only
+    // taken back to report progress.
+    let (ufo, skipped) = match &options.progress {
+        Some(callback) => {
+            let total = match request.layers {
+                true => py.allow_threads(|| reader::count_glyphs(dir, &selection))?,
+                false => 0,
+            };
+            progress::with_progress(py, callback, total, |progress| parse(Some(progress)))??
+        }
+        None => py.allow_threads(|| parse(None))?,
+    };
+    if !options.collect_errors {
+        warn_skipped(py, description, &skipped)?;
+    }
+    if let Some(key) = cache_key {
+        cache::insert(
+            key,
+            cache::Entry {
+                ufo: ufo.clone(),
+                skipped: skipped.clone(),
+            },
+        );
+    }
+    Ok((ufo, skipped))
+}
+
+/// Parses the UFO directory at `path`, describing any failure as a `LoadError` so
+/// it can be reported from outside the parsing thread.
+///
+/// Only the glyphs and layers in `selection` are parsed. Glyphs are parsed one by
+/// one to count them in `progress`, if given. If the options are lenient, a font
+/// that norad rejects is parsed again piece by piece, and the problems skipped
+/// are returned along with it.
+fn parse_font(
+    path: &Path,
+    request: norad::DataRequest,
+    options: ParseOptions,
+    selection: &reader::Selection,
+    progress: Option<&progress::Progress>,
+) -> Result<(norad::Font, Vec<LoadError>), LoadError> {
+    let lenient = options.lenient;
+    let duplicates = match request.layers {
+        true => reader::duplicate_glyphs(path, selection)?,
+        false => Vec::new(),
+    };
+    if let (Some(duplicate), false) = (duplicates.first(), lenient) {
+        return Err(duplicate.error());
+    }
+    let (mut ufo, mut skipped) = match selection.is_all() && progress.is_none() {
+        false => reader::read_font(path, request, lenient, selection, progress)?,
+        true => match norad::Font::load_requested_data(path, request) {
+            Ok(mut ufo) => {
+                reader::restore_notes(&mut ufo, path);
+                (ufo, Vec::new())
+            }
+            Err(_) if lenient => reader::read_font(path, request, lenient, selection, None)?,
+            Err(error) => {
+                let guideline = reader::is_guideline_error(&error);
+                let error = LoadError::from(error);
+                if !guideline && !error.is_unlocated_parse_error() {
+                    return Err(error.relative_to(path));
+                }
+                // Reading again glyph by glyph gets past the guideline angles
+                // that norad rejects, or else finds the file that failed.
+                match reader::read_font(path, request, false, selection, None) {
+                    Ok(read) if guideline => read,
+                    Err(located) if error.is_unlocated_parse_error() => {
+                        return Err(located.relative_to(path))
+                    }
+                    _ => return Err(error.relative_to(path)),
+                }
+            }
+        },
+    };
+    for duplicate in &duplicates {
+        duplicate.keep_first(&mut ufo, path)?;
+        skipped.push(duplicate.skipped());
+    }
+    if options.decompose {
+        decompose::decompose(&mut ufo).map_err(|e| LoadError::new(Category::Structure, e))?;
+    }
+    if options.skip_lib {
+        clear_libs(&mut ufo);
+    }
+    Ok((ufo, skipped))
+}
+
+/// Empties the font lib and the libs of every layer and glyph of `ufo`, along
+/// with those of the glyphs' anchors, guidelines, contours, points and
+/// components.
+fn clear_libs(ufo: &mut norad::Font) {
+    ufo.lib.clear();
+    let layer_names: Vec<_> = ufo.layers.names().cloned().collect();
+    for layer_name in layer_names {
+        let layer = ufo.layers.get_mut(&layer_name).unwrap();
+        layer.lib.clear();
+        for glyph in layer.iter_mut() {
+            glyph.lib.clear();
+            for anchor in &mut glyph.anchors {
+                anchor.take_lib();
+            }
+            for guideline in &mut glyph.guidelines {
+                guideline.take_lib();
+            }
+            for contour in &mut glyph.contours {
+                contour.take_lib();
+                for point in &mut contour.points {
+                    point.take_lib();
+                }
+            }
+            for component in &mut glyph.components {
+                component.take_lib();
+            }
+        }
+    }
+}
+
+/// Issues a `UserWarning` for each problem skipped while loading `description`.
+fn warn_skipped(py: Python, description: &str, skipped: &[LoadError]) -> PyResult<()> {
+    let category = py.import("builtins")?.getattr("UserWarning")?;
+    for problem in skipped {
+        let message = format!("{}: skipped {}", description, problem);
+        PyErr::warn(py, category, &message, 1)?;
+    }
+    Ok(())
+}
+
+/// Builds a thread pool with `jobs` threads, or one per CPU if not given.
+fn thread_pool(jobs: Option<usize>) -> PyResult<rayon::ThreadPool> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|error| IondriveError::new_err(error.to_string()))
+}
+
+/// Load and return only the font info of the UFO at `path`.
+///
+/// No layers or glyphs are read. A UFO without a `fontinfo.plist` gives an empty
+/// `Info` object.
 #[pyfunction]
 #[pyo3(text_signature = "(font_objects_module, path, /)")]
-fn load(loader: &PyModule, path: PathBuf) -> PyResult<PyObject> {
-    let gil = Python::acquire_gil();
-    let py = gil.python();
-    match norad::Font::load(Path::new(&path)) {
-        Ok(ufo) => Ok(ufo.to_wrapped_object(loader, py)),
-        Err(error) => Err(IondriveError::new_err(error.to_string())),
+fn load_info(loader: &PyAny, path: PathBuf) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let ufo = load_font(
+            py,
+            &path,
+            norad::DataRequest::none(),
+            &LoadOptions::default(),
+        )?;
+        Ok(ufo
+            .ufo
+            .font_info
+            .unwrap_or_default()
+            .to_wrapped_object(loader, py))
+    })
+}
+
+/// Read the `metainfo.plist` of the UFO at `path`, a directory or a `.ufoz`
+/// archive, and return it as a dict with its `formatVersion` and `creator`, which
+/// is `None` if not given.
+///
+/// Nothing else in the UFO is read, not even from an archive, and the format
+/// version is returned as it is, whether or not `load` supports it. Raises
+/// `IondriveIOError` if there is no `metainfo.plist`.
+#[pyfunction]
+#[pyo3(text_signature = "(path, /)")]
+fn load_metainfo(py: Python, path: PathBuf) -> PyResult<PyObject> {
+    let metainfo = match ufoz::is_ufoz(&path) {
+        true => {
+            let file = path.join(reader::METAINFO_FILE);
+            match ufoz::read_file(py, &path, reader::METAINFO_FILE)? {
+                Some(data) => reader::parse_metainfo(&data, &file)?,
+                None => return Err(reader::missing_metainfo(&file).into()),
+            }
+        }
+        false => reader::read_metainfo(&path)?,
+    };
+    let dict = PyDict::new(py);
+    dict.set_item("formatVersion", metainfo.format_version)?;
+    dict.set_item("creator", metainfo.creator)?;
+    Ok(dict.into())
+}
+
+/// Load the UFO at `path`, a directory or a `.ufoz` archive, as plain dicts and
+/// lists, without a font objects module.
+///
+/// No font objects are created, so this is faster than `load` for code that
+/// passes the data on as it is, such as to fontTools. Numbers are ints where
+/// they are whole, as with `load`. The font is a dict with these keys:
+///
+/// - `info`: the `fontinfo.plist` values that are set, by key, with
+///   `guidelines` as a list of guideline dicts if there are any.
+/// - `groups`: lists of glyph names by group name.
+/// - `kerning`: values by second member by first member, as `kerning.plist`
+///   holds them.
+/// - `features`: the text of `features.fea`, empty if there is none.
+/// - `lib`: the font lib.
+/// - `layers`: a list of layer dicts in layer order, the default layer first.
+/// - `data` and `images`: the contents of the stores' files as bytes, by
+///   `/`-separated path relative to the store's directory.
+///
+/// A layer dict has a `name`, a `color` if it has one, its `lib` and its `glyphs`,
+/// a dict of glyph dicts by name, in name order. A glyph dict has:
+///
+/// - `name`, `width`, `height`, and `note` if it has one;
+/// - `unicodes`, a list of ints, and `lib`, holding the libs of the glyph's
+///   objects under `public.objectLibs` as in the GLIF file;
+/// - `anchors` and `guidelines`, lists of dicts of the GLIF attributes that are
+///   set (`x`, `y`, `angle`, `name`, `color`, `identifier`);
+/// - `contours`, a list of dicts with a list of `points` and an `identifier` if
+///   there is one. A point dict has `x`, `y` and `smooth`, and `type`, `name` and
+///   `identifier` if set; off-curve points have no `type`;
+/// - `components`, a list of dicts with `baseGlyph`, `transformation`, a list of
+///   six numbers, and an `identifier` if there is one;
+/// - `image`, if the glyph has one, a dict of the GLIF attributes that are set
+///   (`fileName`, `xScale`, `xyScale`, `yxScale`, `yScale`, `xOffset`,
+///   `yOffset`, `color`).
+///
+/// Colors are strings such as `"1,0,0,1"`.
+#[pyfunction]
+#[pyo3(text_signature = "(path, /)")]
+fn load_raw(py: Python, path: PathBuf) -> PyResult<PyObject> {
+    let options = LoadOptions {
+        read_stores: true,
+        ..LoadOptions::default()
+    };
+    let font = load_font(py, &path, norad::DataRequest::all(), &options)?;
+    raw::font_dict(&font, py)
+}
+
+/// Load the UFO at `path` and return it as a `NoradFont`, which reads the parsed
+/// font as plain Python values, without a font objects module.
+///
+/// `path` may also be a `.ufoz` archive. The `data` and `images` stores are not
+/// read.
+#[pyfunction]
+#[pyo3(text_signature = "(path, /)")]
+fn load_native(py: Python, path: PathBuf) -> PyResult<native::NoradFont> {
+    let font = load_font(
+        py,
+        &path,
+        norad::DataRequest::all(),
+        &LoadOptions::default(),
+    )?;
+    Ok(native::NoradFont::new(font.ufo))
+}
+
+/// Check the structure of the UFO at `path` without loading it, and return the
+/// list of problems found, empty if there are none.
+///
+/// The checks are that:
+///
+/// - `metainfo.plist` is present and has a `formatVersion`;
+/// - `fontinfo.plist`, `groups.plist`, `kerning.plist`, `lib.plist`,
+///   `layercontents.plist` and every `layerinfo.plist` and `contents.plist`
+///   parse, as dictionaries where the UFO specification has them;
+/// - every layer `layercontents.plist` lists has its directory with a
+///   `contents.plist`, one of them is the default layer's `glyphs` directory, and
+///   no layer name is listed twice;
+/// - every GLIF file a `contents.plist` lists exists and parses, and no glyph is
+///   listed twice;
+/// - every image a glyph uses is in the `images` directory.
+///
+/// Glyphs are parsed in parallel, but no font objects are created, so this is
+/// much faster than loading the font. `path` may also be a `.ufoz` archive.
+///
+/// Each problem is a dictionary of its `kind`, as in the `kind` of the
+/// exception loading would raise, its `file`, relative to the UFO, its `line`,
+/// and its `message`. Any but the message is `None` where it is not known.
+#[pyfunction]
+#[pyo3(text_signature = "(path, /)")]
+fn verify(py: Python, path: PathBuf) -> PyResult<Vec<PyObject>> {
+    let extracted = match ufoz::is_ufoz(&path) {
+        true => Some(ufoz::extract(py, &path)?),
+        false => None,
+    };
+    let dir = extracted.as_ref().map_or(path.as_path(), |e| e.path());
+    let problems = py.allow_threads(|| verify::verify(dir));
+    problems.iter().map(|p| p.to_diagnostic(py)).collect()
+}
+
+/// Load and return only the kerning and groups of the UFO at `path`, as a
+/// `(kerning, groups)` tuple.
+///
+/// Only `kerning.plist` and `groups.plist` are read; no layers or glyphs are. A
+/// missing file gives an empty mapping. UFOs in format version 1 or 2 have their
+/// kerning groups renamed as `load` does.
+#[pyfunction]
+#[pyo3(text_signature = "(font_objects_module, path, /)")]
+fn load_kerning(loader: &PyAny, path: PathBuf) -> PyResult<(PyObject, PyObject)> {
+    Python::with_gil(|py| {
+        let mut request = norad::DataRequest::none();
+        request.groups(true).kerning(true);
+        let ufo = load_font(py, &path, request, &LoadOptions::default())?.ufo;
+        Ok((
+            wrap_kerning(ufo.kerning.as_ref(), loader, py)?,
+            wrap_groups(ufo.groups.as_ref(), py),
+        ))
+    })
+}
+
+/// Read and return the text of the `features.fea` of the UFO at `path`, a
+/// directory or a `.ufoz` archive, or an empty string if it has none.
+///
+/// Nothing else in the UFO is read. With `resolve_includes=True`, the files it
+/// includes are inlined as with `load(resolve_feature_includes=True)`, which
+/// unpacks an archive in full to find them.
+#[pyfunction(resolve_includes = "false")]
+#[pyo3(text_signature = "(path, /, resolve_includes=False)")]
+fn load_features(py: Python, path: PathBuf, resolve_includes: bool) -> PyResult<String> {
+    let parent = path.parent().unwrap_or_else(|| Path::new(""));
+    match (ufoz::is_ufoz(&path), resolve_includes) {
+        (true, false) => {
+            let text = ufoz::read_file(py, &path, features::FEATURES_FILE)?.unwrap_or_default();
+            String::from_utf8(text).map_err(|e| {
+                let error = std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+                LoadError::from(error)
+                    .in_file(&path.join(features::FEATURES_FILE))
+                    .into()
+            })
+        }
+        (true, true) => {
+            let extracted = ufoz::extract(py, &path)?;
+            let text = features::read_features(extracted.path())?;
+            Ok(features::resolve_includes(
+                &text,
+                &[extracted.path(), parent],
+            )?)
+        }
+        (false, false) => Ok(features::read_features(&path)?),
+        (false, true) => {
+            let text = features::read_features(&path)?;
+            Ok(features::resolve_includes(&text, &[&path, parent])?)
+        }
+    }
+}
+
+/// Load and return the glyph `glyph_name` from the UFO at `path`.
+///
+/// Only the requested GLIF file is parsed. The glyph is taken from the layer named
+/// `layer_name`, or from the default layer if no name is given. Raises `KeyError`
+/// if the layer or glyph does not exist.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(font_objects_module, path, glyph_name, layer_name=None, /)")]
+fn load_glyph(
+    loader: &PyAny,
+    path: PathBuf,
+    glyph_name: &str,
+    layer_name: Option<String>,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let dir = reader::layer_dir(&path, layer_name.as_deref())?;
+        let glyph = reader::read_glyph(&dir, glyph_name)?;
+        Ok(Arc::new(glyph).to_wrapped_object(loader, py))
+    })
+}
+
+/// Parse the glyph `glyph_name` again from the UFO at `path` and put it in place of
+/// the glyph of that name in `font`, a `Font` loaded from the same UFO. Return the
+/// new glyph, or `None` if it no longer exists.
+///
+/// The glyph is taken from the layer named `layer_name`, or from the default
+/// layer if no name is given, and replaces the glyph in the font's layer of the
+/// same name. A glyph that is new on disk is added to the layer, and one whose
+/// GLIF file or `contents.plist` entry is gone is removed from it. Raises
+/// `KeyError` if either the UFO or the font has no such layer.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(font_objects_module, font, path, glyph_name, layer_name=None, /)")]
+fn reload_glyph(
+    loader: &PyAny,
+    font: &PyAny,
+    path: PathBuf,
+    glyph_name: &str,
+    layer_name: Option<String>,
+) -> PyResult<PyObject> {
+    let py = font.py();
+    let dir = reader::layer_dir(&path, layer_name.as_deref())?;
+    let layers = font.getattr("layers")?;
+    let layer = match &layer_name {
+        Some(name) => layers.get_item(name)?,
+        None => layers.getattr("defaultLayer")?,
+    };
+    let exists = reader::glyph_contents(&dir)?
+        .get(glyph_name)
+        .is_some_and(|file| dir.join(file).is_file());
+    if !exists {
+        if layer
+            .call_method1("__contains__", (glyph_name,))?
+            .is_true()?
+        {
+            layer.del_item(glyph_name)?;
+        }
+        return Ok(py.None());
+    }
+    let glyph = Arc::new(reader::read_glyph(&dir, glyph_name)?).to_wrapped_object(loader, py);
+    let kwargs = [("overwrite", true), ("copy", false)].into_py_dict(py);
+    layer.call_method("insertGlyph", (&glyph,), Some(kwargs))?;
+    Ok(glyph)
+}
+
+/// Write `glyph` into the UFO at `path` as a single GLIF file, without rewriting
+/// anything else of the UFO.
+///
+/// The glyph goes into the layer named `layer_name`, or into the default layer if
+/// no name is given. A layer that does not exist is created, in a new directory
+/// added to `layercontents.plist`. A glyph that the layer already has is written
+/// over its GLIF file; a new one is given a file named after it and added to the
+/// layer's `contents.plist`. Files are written in full under a temporary name and
+/// then renamed into place, and the GIL is released while writing. Saves from
+/// several threads at once update the plists one at a time, so that none is
+/// lost; other processes writing to the same UFO are not guarded against.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(font_objects_module, path, glyph, layer_name=None, /)")]
+fn save_glyph(
+    _loader: &PyAny,
+    path: PathBuf,
+    glyph: &PyAny,
+    layer_name: Option<String>,
+) -> PyResult<()> {
+    let py = glyph.py();
+    let glyph = norad::Glyph::from_wrapped_object(glyph)?;
+    py.allow_threads(|| writer::write_glyph(&path, &glyph, layer_name.as_deref()))?;
+    Ok(())
+}
+
+/// Load a UFO from `path` with only the glyphs whose GLIF file was modified after
+/// `since_mtime`, in seconds since the Unix epoch as `os.stat` gives them.
+///
+/// The font returned is intentionally partial, for incremental builds that only
+/// need to process the glyphs edited since a previous build: each layer holds
+/// just its own glyphs that are newer than the cutoff, and may be empty. Glyphs
+/// it leaves out may still be referenced, by the components of other glyphs, the
+/// glyph order, groups or kerning. The font info, groups, kerning, features, libs
+/// and stores are always loaded in full. Saving the font over the UFO would lose
+/// the glyphs left out. `path` must be a UFO directory, as the files in a
+/// `.ufoz` archive have no modification times of their own once unpacked.
+#[pyfunction]
+#[pyo3(text_signature = "(font_objects_module, path, since_mtime, /)")]
+fn load_changed(loader: &PyAny, path: PathBuf, since_mtime: f64) -> PyResult<PyObject> {
+    let py = loader.py();
+    if ufoz::is_ufoz(&path) {
+        return Err(IondriveError::new_err(format!(
+            "{} is a .ufoz archive: load_changed needs a UFO directory",
+            path.display()
+        )));
+    }
+    let modified = reader::glyphs_modified_since(&path, since_mtime)?;
+    let selection = reader::Selection {
+        glyphs: Some(modified.values().flatten().cloned().collect()),
+        layers: None,
+    };
+    let options = LoadOptions::default();
+    let (mut ufo, _) = parse_font(
+        &path,
+        norad::DataRequest::all(),
+        options.parse,
+        &selection,
+        None,
+    )?;
+    // The selection is by name across all layers, so a glyph modified in one
+    // layer is parsed in the others too.
+    let layer_names: Vec<_> = ufo.layers.names().cloned().collect();
+    for layer_name in layer_names {
+        let layer = ufo.layers.get_mut(&layer_name).unwrap();
+        let keep = modified.get(layer_name.as_ref());
+        let unmodified: Vec<String> = layer
+            .iter()
+            .map(|glyph| glyph.name.to_string())
+            .filter(|name| !keep.is_some_and(|keep| keep.contains(name)))
+            .collect();
+        for name in unmodified {
+            layer.remove_glyph(&name);
+        }
     }
+    LoadedFont::on_disk(ufo, &path).to_wrapped_object(loader, py, &options)
+}
+
+/// Return the names of the glyphs in the layer `layer_name` of the UFO at `path`,
+/// or in the default layer if no name is given, sorted.
+///
+/// Only the layer's `contents.plist` is read; no GLIF file is parsed. Raises
+/// `KeyError` if the layer does not exist, and `IondriveError` if its
+/// `contents.plist` is missing or malformed.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(path, layer_name=None, /)")]
+fn glyph_names(path: PathBuf, layer_name: Option<String>) -> PyResult<Vec<String>> {
+    let dir = reader::layer_dir(&path, layer_name.as_deref())?;
+    Ok(reader::glyph_contents(&dir)?.into_keys().collect())
+}
+
+/// Return the advance width of each glyph in the layer `layer_name` of the UFO at
+/// `path`, or in the default layer if no name is given, as a dict by glyph name,
+/// sorted.
+///
+/// Only the `<advance>` element of each GLIF file is read, without parsing its
+/// outlines or lib, and no font objects are created, so this is much faster than
+/// `load` for metrics reports. Widths are `int`s where they are whole, as with
+/// `load`, and glyphs without an advance width have 0. Raises `KeyError` if the
+/// layer does not exist, and `IondriveParseError` for a width that is not a
+/// number, naming the GLIF file.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(path, layer_name=None, /)")]
+fn advance_widths(py: Python, path: PathBuf, layer_name: Option<String>) -> PyResult<PyObject> {
+    let dir = reader::layer_dir(&path, layer_name.as_deref())?;
+    let widths = py.allow_threads(|| reader::advance_widths(&dir))?;
+    let dict = PyDict::new(py);
+    for (name, width) in widths {
+        dict.set_item(name, f32_to_int_or_float(width, py))?;
+    }
+    Ok(dict.into())
+}
+
+/// Load and return the layer `layer_name` from the UFO at `path`.
+///
+/// Only the directory of the requested layer is parsed. Raises `IondriveError` if
+/// the layer does not exist.
+#[pyfunction]
+#[pyo3(text_signature = "(font_objects_module, path, layer_name, /)")]
+fn load_layer(loader: &PyAny, path: PathBuf, layer_name: &str) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let layer = py.allow_threads(|| reader::read_layer(&path, layer_name))?;
+        Ok(layer.to_wrapped_object(loader, py))
+    })
+}
+
+/// Return an iterator over the glyphs of the layer `layer_name` in the UFO at
+/// `path`, or of the default layer if no name is given, in name order.
+///
+/// The layer is parsed up front, but each `Glyph` is only created when the
+/// iterator gets to it, so processing the glyphs one at a time and dropping each
+/// one keeps memory use far below that of loading the whole layer. Raises
+/// `KeyError` if the layer does not exist.
+#[pyfunction(layer_name = "None")]
+#[pyo3(text_signature = "(font_objects_module, path, layer_name=None, /)")]
+fn iter_glyphs(
+    loader: &PyAny,
+    path: PathBuf,
+    layer_name: Option<String>,
+) -> PyResult<lazy::GlyphIterator> {
+    let dir = reader::layer_dir(&path, layer_name.as_deref())?;
+    let name = layer_name.as_deref().unwrap_or(reader::DEFAULT_LAYER_NAME);
+    let layer = loader
+        .py()
+        .allow_threads(|| reader::load_layer(&dir, name))?;
+    Ok(lazy::GlyphIterator::new(Arc::new(layer), loader))
+}
+
+/// Parse `glif_string`, the XML of a GLIF file, and return it as a `Glyph`.
+///
+/// The glyph is named `glyph_name` if given, regardless of the `name` attribute
+/// of its `<glyph>` element, which may then also be missing. Raises
+/// `IondriveError` with the parse error if the XML is malformed or not valid GLIF.
+#[pyfunction(glyph_name = "None")]
+#[pyo3(text_signature = "(font_objects_module, glif_string, glyph_name=None, /)")]
+fn parse_glif(loader: &PyAny, glif_string: &str, glyph_name: Option<String>) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let glyph = glif::parse_glif(py, glif_string, glyph_name.as_deref())?;
+        Ok(Arc::new(glyph).to_wrapped_object(loader, py))
+    })
+}
+
+/// Serialize `glyph`, a `Glyph` object, and return its GLIF XML as a string.
+///
+/// The XML is formatted as norad writes it, indented by two spaces, so the same
+/// glyph always serializes to the same string. Raises `IondriveError` if the
+/// glyph cannot be converted.
+#[pyfunction]
+#[pyo3(text_signature = "(glyph, /)")]
+fn dump_glif(glyph: &PyAny) -> PyResult<String> {
+    glif::dump_glif(&norad::Glyph::from_wrapped_object(glyph)?)
+}
+
+/// Empty the cache of fonts kept by `load(..., use_cache=True)`.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn clear_cache() {
+    cache::clear();
+}
+
+/// Compare the UFOs at `path_a` and `path_b` and return what changed from the
+/// first to the second, as a dictionary of plain lists, dictionaries, strings and
+/// numbers that `json.dumps` accepts.
+///
+/// Both UFOs are parsed by norad and compared without creating any font objects.
+/// Either may be a `.ufoz` archive. The report has these keys:
+///
+/// - `layers`: the names of the layers `added` and `removed`.
+/// - `glyphs`: for each layer in both UFOs with changed glyphs, the sorted names
+///   of the glyphs `added`, `removed` and `changed`, by layer name. A glyph has
+///   changed if anything in its GLIF file parses differently, including its lib.
+/// - `info`: the `[old, new]` values of the info attributes that changed, by
+///   attribute name, with `None` for one not set. Info guidelines are not
+///   compared.
+/// - `kerning`: the pairs `added` and `removed`, each as `[first, second,
+///   value]`, and those `changed`, as `[first, second, old, new]`, sorted.
+#[pyfunction]
+#[pyo3(text_signature = "(path_a, path_b, /)")]
+fn diff(py: Python, path_a: PathBuf, path_b: PathBuf) -> PyResult<PyObject> {
+    diff::diff(py, &path_a, &path_b)
+}
+
+/// Save `font` as a UFO at `path`, replacing any existing UFO there.
+///
+/// `font` is an object tree such as the one returned by `load`. The font objects
+/// module is accepted for symmetry with `load`; the font is read through its
+/// attributes only.
+///
+/// A `path` ending in `.ufoz` is written as a zip archive instead of a directory,
+/// holding the UFO in a single top-level directory named after the archive.
+/// This is the layout fontTools writes, and the only one its `ufoLib` reads:
+/// an archive with the UFO's files at its root is rejected there.
+///
+/// With `atomic=True`, the default, the UFO is written to a hidden temporary
+/// directory or file next to `path` and renamed to `path` once complete. Any
+/// existing UFO is renamed aside just before and removed just after, so that a
+/// save that fails or is interrupted leaves it as it was, and readers see
+/// either the old UFO or the new one, never a partly written one, though
+/// nothing at all for a moment between the two renames. As the temporary copy
+/// is in the same directory as `path`, the renames never cross filesystems;
+/// the directory must be writable. If `path` is a symbolic link, the link
+/// itself is replaced. A `.ufoz` archive is also first assembled in the
+/// system's temporary directory, which may be on another filesystem, and only
+/// then zipped next to `path`. With `atomic=False`, an existing UFO is removed
+/// first and the new one written in place.
+///
+/// The UFO is written in `format_version` 3 by default. With `format_version=2`
+/// it is written as a UFO 2, with GLIF 1 glyphs whose anchors are contours of a
+/// single named move point, as ufoLib writes them, and without the identifiers
+/// of contours, points and components. A font with anything else UFO 2 cannot
+/// hold, such as layers besides the default layer, guidelines, images, object
+/// libs, data or the fontinfo keys new in UFO 3, raises `IondriveError` saying
+/// what it is, before anything is written.
+#[pyfunction(atomic = "true", format_version = "3")]
+#[pyo3(text_signature = "(font_objects_module, font, path, /, atomic=True, format_version=3)")]
+fn save(
+    _loader: &PyAny,
+    font: &PyAny,
+    path: PathBuf,
+    atomic: bool,
+    format_version: u32,
+) -> PyResult<()> {
+    let mut ufo = norad::Font::from_wrapped_object(font)?;
+    match format_version {
+        3 => {}
+        2 => {
+            downgrade::to_format_2(&mut ufo)
+                .map_err(|e| IondriveError::new_err(format!("cannot save as UFO 2: {}", e)))?;
+            downgrade::check_stores(font)?;
+        }
+        _ => {
+            return Err(IondriveError::new_err(format!(
+                "cannot save as UFO {}, only as UFO 2 or 3",
+                format_version
+            )))
+        }
+    }
+    let write_dir = |dir: &Path| {
+        write_ufo(&ufo, font, dir)?;
+        if format_version == 2 {
+            downgrade::finish_format_2(dir)?;
+        }
+        Ok(())
+    };
+    let write = |target: &Path| match ufoz::has_ufoz_extension(&path) {
+        true => ufoz::write(font.py(), &path, target, write_dir),
+        false => write_dir(target),
+    };
+    match atomic {
+        true => writer::replace_atomically(&path, write),
+        false => write(&path),
+    }
+}
+
+/// Writes `ufo`, converted from `font`, and the stores of `font` as a UFO
+/// directory at `path`.
+fn write_ufo(ufo: &norad::Font, font: &PyAny, path: &Path) -> PyResult<()> {
+    ufo.save(path).map_err(LoadError::from)?;
+    for kind in [&data::DATA, &data::IMAGES] {
+        if let Ok(store) = font.getattr(kind.attribute()) {
+            data::write_store(store, path, kind)?;
+        }
+    }
+    Ok(())
 }
 
 /// Iondrive is a glue library to load [Unified Font Object](ufo) files using norad.
@@ -223,12 +2656,88 @@ fn load(loader: &PyModule, path: PathBuf) -> PyResult<PyObject> {
 /// The goal is to load data faster than can be done by Python and then pass it
 /// over to Python.
 ///
+/// The functions that create font objects take a font objects module first,
+/// such as `ufoLib2.objects`. It need not be a module: any object with the
+/// classes as attributes will do, such as a namespace of defcon-compatible
+/// classes. The classes are called with the keyword arguments ufoLib2's take:
+///
+/// - `Font`, `Info`, `LayerSet` with a `from_iterable(layers, defaultLayerName)`
+///   class method, `Layer` and `Glyph`;
+/// - `Contour`, `Point`, `Component`, `Anchor`, `Guideline` and `Image`;
+/// - `DataSet` and `ImageSet`, called with the stores' files.
+///
+/// `Kerning`, `Features` and `Color` are used if present; otherwise kerning is a
+/// `dict`, and features and colors are strings. Lazy loading also needs a
+/// `layer` attribute whose `_GLYPH_NOT_LOADED` marks glyphs not yet loaded.
+///
+/// `load_raw` and `load_native` need no font objects module: they give the font
+/// as plain Python values.
+///
+/// Errors are raised as `IondriveError` or one of its subclasses
+/// `IondriveIOError`, `IondriveParseError` and `IondriveStructureError`. The
+/// `kind` attribute of the exception names the norad error it comes from, or the
+/// one it is like for the checks iondrive makes itself, and is `None` for other
+/// errors. The kinds are:
+///
+/// - `Io`, `MissingFile` and `MissingUfoDir`, for files and directories that
+///   cannot be read or do not exist;
+/// - `InvalidXml`, `InvalidPlist` and `InvalidGlif`, for files that do not parse;
+/// - `MissingDefaultLayer`, `MissingLayer`, `DuplicateLayer`, `MissingGlyph` and
+///   `DuplicateGlyph`, for layers and glyphs that are missing or listed twice;
+/// - `InvalidColor`, `InvalidFontInfo`, `FontInfoUpconversion`, `InvalidGroups`,
+///   `GroupsUpconversionFailure`, `ExpectedPlistDictionary`,
+///   `ExpectedPlistString` and `ExpectedPositiveValue`, for values norad
+///   rejects;
+/// - `GlifWrite`, `PreexistingPublicObjectLibsKey` and `DowngradeUnsupported`,
+///   for glyphs and fonts that cannot be saved.
+///
+/// When `load_many` fails for several UFOs, the kind is the one they all fail
+/// with, if they do.
+///
 /// [ufo]: https://unifiedfontobject.org/
 #[pymodule]
 fn iondrive(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(clear_cache, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(diff, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(dump_glif, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(from_bytes, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(glyph_names, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(advance_widths, m)?)
+        .unwrap();
+    m.add_function(wrap_pyfunction!(iter_glyphs, m)?).unwrap();
     m.add_function(wrap_pyfunction!(load, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_bytes, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_changed, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_designspace, m)?)
+        .unwrap();
+    m.add_function(wrap_pyfunction!(load_features, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_fileobj, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_glyph, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_info, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_kerning, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_layer, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_many, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_metainfo, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_native, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(load_raw, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(parse_glif, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(reload_glyph, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(save, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(save_glyph, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(verify, m)?).unwrap();
+
+    m.add_class::<native::NoradFont>()?;
+    m.add_class::<ufo_reader::UfoReader>()?;
 
+    // Errors that do not come from norad have no kind.
+    py.get_type::<IondriveError>().setattr("kind", py.None())?;
     m.add("IondriveError", py.get_type::<IondriveError>())?;
+    m.add("IondriveIOError", py.get_type::<IondriveIOError>())?;
+    m.add("IondriveParseError", py.get_type::<IondriveParseError>())?;
+    m.add(
+        "IondriveStructureError",
+        py.get_type::<IondriveStructureError>(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+
+use crate::defcon::guideline_dict;
+use crate::info::info_attributes;
+use crate::raw::kerning_dict;
+
+/// A font parsed by norad, read through plain Python values rather than font
+/// objects.
+///
+/// No font objects module is needed: each method converts only what it returns,
+/// into strings, numbers, lists and dicts. The font is read-only, and holds the
+/// parsed UFO for as long as it is alive. `load_native` creates it.
+#[pyclass(module = "iondrive")]
+pub(crate) struct NoradFont {
+    font: Arc<norad::Font>,
+}
+
+impl NoradFont {
+    pub(crate) fn new(font: norad::Font) -> Self {
+        NoradFont {
+            font: Arc::new(font),
+        }
+    }
+
+    /// The layer `layer_name`, or the default layer if no name is given. Raises
+    /// `KeyError` if there is no such layer.
+    fn layer(&self, layer_name: Option<&str>) -> PyResult<&norad::Layer> {
+        match layer_name {
+            Some(name) => self
+                .font
+                .layers
+                .get(name)
+                .ok_or_else(|| PyKeyError::new_err(name.to_string())),
+            None => Ok(self.font.default_layer()),
+        }
+    }
+}
+
+#[pymethods]
+impl NoradFont {
+    /// The names of the layers, the default layer first.
+    #[pyo3(text_signature = "($self)")]
+    fn layer_names(&self) -> Vec<String> {
+        self.font.layers.names().map(|n| n.to_string()).collect()
+    }
+
+    /// The names of the glyphs in the layer `layer_name`, or in the default layer
+    /// if no name is given, sorted. Raises `KeyError` if the layer does not exist.
+    #[args(layer_name = "None")]
+    #[pyo3(text_signature = "($self, layer_name=None)")]
+    fn glyph_names(&self, layer_name: Option<String>) -> PyResult<Vec<String>> {
+        Ok(self
+            .layer(layer_name.as_deref())?
+            .iter()
+            .map(|g| g.name.to_string())
+            .collect())
+    }
+
+    /// The value of the info attribute `key`, such as `"familyName"`, or `None`
+    /// if the font does not set it. `guidelines` are a list of dicts of the
+    /// attributes they set. Raises `KeyError` if `key` is not an info attribute.
+    #[pyo3(text_signature = "($self, key)")]
+    fn info_value(&self, py: Python, key: &str) -> PyResult<PyObject> {
+        let default = norad::FontInfo::default();
+        let info = self.font.font_info.as_ref().unwrap_or(&default);
+        if key == "guidelines" {
+            return Ok(info
+                .guidelines
+                .as_ref()
+                .map(|guidelines| {
+                    guidelines
+                        .iter()
+                        .map(|g| guideline_dict(g, py))
+                        .collect::<Vec<_>>()
+                })
+                .to_object(py));
+        }
+        info_attributes(info, py)
+            .into_iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+            .ok_or_else(|| PyKeyError::new_err(key.to_string()))
+    }
+
+    /// The kerning values by second member by first member, as `kerning.plist`
+    /// holds them. A UFO without a `kerning.plist` gives an empty dict.
+    #[pyo3(text_signature = "($self)")]
+    fn kerning(&self, py: Python) -> PyResult<PyObject> {
+        Ok(kerning_dict(self.font.kerning.as_ref(), py)?.into())
+    }
+}
@@ -0,0 +1,505 @@
+use std::convert::TryInto;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::error::{Category, LoadError};
+
+/// The start of every packed font, ending in the version of the format, which
+/// changes whenever the layout below does.
+static MAGIC: &[u8] = b"iondrive\x03";
+
+/// A font read back from `unpack`, with the files of its stores.
+pub(crate) struct Unpacked {
+    pub(crate) ufo: norad::Font,
+    pub(crate) path: Option<PathBuf>,
+    /// The order of the layer names, if it is not that of the layer set.
+    pub(crate) layer_order: Option<Vec<String>>,
+    /// The `formatVersion` and `formatVersionMinor` of the UFO it was loaded
+    /// from.
+    pub(crate) format_version: (u64, u64),
+    pub(crate) data: Vec<(String, Vec<u8>)>,
+    pub(crate) images: Vec<(String, Vec<u8>)>,
+}
+
+/// Packs `ufo`, the path it was loaded from, the order of its layer names if it
+/// is not that of its layer set, the format version of the UFO it was loaded
+/// from, and the files of its `data` and `images` stores into bytes that `unpack`
+/// reads back.
+///
+/// Glyphs are written field by field rather than as GLIF, so that unpacking
+/// them needs no XML parsing. Plists, such as the libs and the font info, are
+/// written as binary plists. Each glyph is packed separately, in parallel.
+pub(crate) fn pack(
+    ufo: &norad::Font,
+    path: Option<&Path>,
+    layer_order: Option<&[String]>,
+    format_version: (u64, u64),
+    data: &[(String, Vec<u8>)],
+    images: &[(String, Vec<u8>)],
+) -> Result<Vec<u8>, LoadError> {
+    let mut out = Writer(MAGIC.to_vec());
+    out.opt_str(path.map(|p| p.to_string_lossy()).as_deref());
+    out.str(&ufo.meta.creator);
+    out.u8(ufo.meta.format_version as u8);
+    out.u32(format_version.0 as usize);
+    out.u32(format_version.1 as usize);
+    out.opt_plist(ufo.font_info.as_ref())?;
+    out.opt_plist(ufo.groups.as_ref())?;
+    out.opt_plist(ufo.kerning.as_ref())?;
+    out.opt_str(ufo.features.as_deref());
+    out.lib(&ufo.lib)?;
+    out.u32(ufo.layers.len());
+    for layer in ufo.layers.iter() {
+        out.str(layer.name());
+        out.str(&layer.path().to_string_lossy());
+        out.opt_color(layer.color.as_ref());
+        out.lib(&layer.lib)?;
+        let glyphs = layer
+            .iter()
+            .collect::<Vec<_>>()
+            .par_iter()
+            .map(|glyph| pack_glyph(glyph))
+            .collect::<Result<Vec<_>, _>>()?;
+        out.u32(glyphs.len());
+        for glyph in glyphs {
+            out.bytes(&glyph);
+        }
+    }
+    // An empty order stands for that of the layer set, as a font always has a
+    // layer.
+    let layer_order = layer_order.unwrap_or_default();
+    out.u32(layer_order.len());
+    for name in layer_order {
+        out.str(name);
+    }
+    for files in [data, images] {
+        out.u32(files.len());
+        for (name, contents) in files {
+            out.str(name);
+            out.bytes(contents);
+        }
+    }
+    Ok(out.0)
+}
+
+/// Reads back a font packed by `pack`, unpacking its glyphs in parallel.
+pub(crate) fn unpack(packed: &[u8]) -> Result<Unpacked, LoadError> {
+    let mut input = Reader(packed.strip_prefix(MAGIC).ok_or_else(invalid)?);
+    let path = input.opt_str()?.map(PathBuf::from);
+    let mut ufo = norad::Font::new();
+    ufo.meta.creator = input.str()?.to_string();
+    ufo.meta.format_version = match input.u8()? {
+        1 => norad::FormatVersion::V1,
+        2 => norad::FormatVersion::V2,
+        3 => norad::FormatVersion::V3,
+        _ => return Err(invalid()),
+    };
+    let format_version = (input.u32()? as u64, input.u32()? as u64);
+    ufo.font_info = input.opt_plist()?;
+    ufo.groups = input.opt_plist()?;
+    ufo.kerning = input.opt_plist()?;
+    ufo.features = input.opt_str()?.map(str::to_string);
+    ufo.lib = input.lib()?;
+    let mut layers = Vec::new();
+    for _ in 0..input.u32()? {
+        let name = input.str()?;
+        let mut layer = norad::Layer::new(name.into(), Some(input.str()?.into()));
+        layer.color = input.opt_color()?;
+        layer.lib = input.lib()?;
+        let glyphs = (0..input.u32()?)
+            .map(|_| input.bytes())
+            .collect::<Result<Vec<_>, _>>()?;
+        let glyphs = glyphs
+            .into_par_iter()
+            .map(|glyph| unpack_glyph(Reader(glyph)))
+            .collect::<Result<Vec<_>, _>>()?;
+        for glyph in glyphs {
+            layer.insert_glyph(glyph);
+        }
+        layers.push(layer);
+    }
+    if layers.is_empty() {
+        return Err(invalid());
+    }
+    ufo.layers = norad::LayerSet::new(layers);
+    let layer_order = (0..input.u32()?)
+        .map(|_| input.str().map(str::to_string))
+        .collect::<Result<Vec<_>, _>>()?;
+    let layer_order = Some(layer_order).filter(|order| !order.is_empty());
+    let mut stores = [Vec::new(), Vec::new()];
+    for files in &mut stores {
+        for _ in 0..input.u32()? {
+            files.push((input.str()?.to_string(), input.bytes()?.to_vec()));
+        }
+    }
+    if !input.0.is_empty() {
+        return Err(invalid());
+    }
+    let [data, images] = stores;
+    Ok(Unpacked {
+        ufo,
+        path,
+        layer_order,
+        format_version,
+        data,
+        images,
+    })
+}
+
+fn pack_glyph(glyph: &norad::Glyph) -> Result<Vec<u8>, LoadError> {
+    let mut out = Writer(Vec::new());
+    out.str(&glyph.name);
+    out.u8(match glyph.format {
+        norad::GlifVersion::V1 => 1,
+        norad::GlifVersion::V2 => 2,
+    });
+    out.f32(glyph.width);
+    out.f32(glyph.height);
+    out.u32(glyph.codepoints.len());
+    for codepoint in &glyph.codepoints {
+        out.u32(*codepoint as usize);
+    }
+    out.opt_str(glyph.note.as_deref());
+    out.u32(glyph.guidelines.len());
+    for guideline in &glyph.guidelines {
+        match guideline.line {
+            norad::Line::Vertical(x) => {
+                out.u8(0);
+                out.f32(x);
+            }
+            norad::Line::Horizontal(y) => {
+                out.u8(1);
+                out.f32(y);
+            }
+            norad::Line::Angle { x, y, degrees } => {
+                out.u8(2);
+                out.f32(x);
+                out.f32(y);
+                out.f32(degrees);
+            }
+        }
+        out.opt_str(guideline.name.as_deref());
+        out.opt_color(guideline.color.as_ref());
+        out.object(guideline.identifier(), guideline.lib())?;
+    }
+    out.u32(glyph.anchors.len());
+    for anchor in &glyph.anchors {
+        out.f32(anchor.x);
+        out.f32(anchor.y);
+        out.opt_str(anchor.name.as_deref());
+        out.opt_color(anchor.color.as_ref());
+        out.object(anchor.identifier(), anchor.lib())?;
+    }
+    out.u32(glyph.components.len());
+    for component in &glyph.components {
+        out.str(&component.base);
+        out.transform(&component.transform);
+        out.object(component.identifier(), component.lib())?;
+    }
+    out.u32(glyph.contours.len());
+    for contour in &glyph.contours {
+        out.u32(contour.points.len());
+        for point in &contour.points {
+            out.f32(point.x);
+            out.f32(point.y);
+            out.u8(match point.typ {
+                norad::PointType::Move => 0,
+                norad::PointType::Line => 1,
+                norad::PointType::OffCurve => 2,
+                norad::PointType::Curve => 3,
+                norad::PointType::QCurve => 4,
+            });
+            out.u8(point.smooth as u8);
+            out.opt_str(point.name.as_deref());
+            out.object(point.identifier(), point.lib())?;
+        }
+        out.object(contour.identifier(), contour.lib())?;
+    }
+    match &glyph.image {
+        Some(image) => {
+            out.u8(1);
+            out.str(&image.file_name.to_string_lossy());
+            out.opt_color(image.color.as_ref());
+            out.transform(&image.transform);
+        }
+        None => out.u8(0),
+    }
+    out.lib(&glyph.lib)?;
+    Ok(out.0)
+}
+
+fn unpack_glyph(mut input: Reader) -> Result<norad::Glyph, LoadError> {
+    let mut glyph = norad::Glyph::new_named(input.str()?);
+    glyph.format = match input.u8()? {
+        1 => norad::GlifVersion::V1,
+        2 => norad::GlifVersion::V2,
+        _ => return Err(invalid()),
+    };
+    glyph.width = input.f32()?;
+    glyph.height = input.f32()?;
+    for _ in 0..input.u32()? {
+        let codepoint = char::from_u32(input.u32()? as u32).ok_or_else(invalid)?;
+        glyph.codepoints.push(codepoint);
+    }
+    glyph.note = input.opt_str()?.map(str::to_string);
+    for _ in 0..input.u32()? {
+        let line = match input.u8()? {
+            0 => norad::Line::Vertical(input.f32()?),
+            1 => norad::Line::Horizontal(input.f32()?),
+            2 => norad::Line::Angle {
+                x: input.f32()?,
+                y: input.f32()?,
+                degrees: input.f32()?,
+            },
+            _ => return Err(invalid()),
+        };
+        let name = input.opt_str()?.map(str::to_string);
+        let color = input.opt_color()?;
+        let (identifier, lib) = input.object()?;
+        glyph
+            .guidelines
+            .push(norad::Guideline::new(line, name, color, identifier, lib));
+    }
+    for _ in 0..input.u32()? {
+        let (x, y) = (input.f32()?, input.f32()?);
+        let name = input.opt_str()?.map(str::to_string);
+        let color = input.opt_color()?;
+        let (identifier, lib) = input.object()?;
+        glyph
+            .anchors
+            .push(norad::Anchor::new(x, y, name, color, identifier, lib));
+    }
+    for _ in 0..input.u32()? {
+        let base = input.str()?;
+        let transform = input.transform()?;
+        let (identifier, lib) = input.object()?;
+        glyph.components.push(norad::Component::new(
+            base.into(),
+            transform,
+            identifier,
+            lib,
+        ));
+    }
+    for _ in 0..input.u32()? {
+        let mut points = Vec::new();
+        for _ in 0..input.u32()? {
+            let (x, y) = (input.f32()?, input.f32()?);
+            let typ = match input.u8()? {
+                0 => norad::PointType::Move,
+                1 => norad::PointType::Line,
+                2 => norad::PointType::OffCurve,
+                3 => norad::PointType::Curve,
+                4 => norad::PointType::QCurve,
+                _ => return Err(invalid()),
+            };
+            let smooth = input.u8()? != 0;
+            let name = input.opt_str()?.map(str::to_string);
+            let (identifier, lib) = input.object()?;
+            points.push(norad::ContourPoint::new(
+                x, y, typ, smooth, name, identifier, lib,
+            ));
+        }
+        let (identifier, lib) = input.object()?;
+        glyph
+            .contours
+            .push(norad::Contour::new(points, identifier, lib));
+    }
+    if input.u8()? != 0 {
+        glyph.image = Some(norad::Image {
+            file_name: input.str()?.into(),
+            color: input.opt_color()?,
+            transform: input.transform()?,
+        });
+    }
+    glyph.lib = input.lib()?;
+    if !input.0.is_empty() {
+        return Err(invalid());
+    }
+    Ok(glyph)
+}
+
+fn invalid() -> LoadError {
+    LoadError::new(
+        Category::Parse,
+        "data is not a font packed by load(as_bytes=True) with this version of iondrive",
+    )
+}
+
+/// Appends values to a packed font. Numbers are little-endian, and strings and
+/// nested data are preceded by their length.
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    fn u32(&mut self, value: usize) {
+        self.0.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.u32(value.len());
+        self.0.extend_from_slice(value);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    fn opt_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.u8(1);
+                self.str(value);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn opt_color(&mut self, color: Option<&norad::Color>) {
+        match color {
+            Some(color) => {
+                self.u8(1);
+                for component in [color.red, color.green, color.blue, color.alpha] {
+                    self.f32(component);
+                }
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn transform(&mut self, transform: &norad::AffineTransform) {
+        for value in [
+            transform.x_scale,
+            transform.xy_scale,
+            transform.yx_scale,
+            transform.y_scale,
+            transform.x_offset,
+            transform.y_offset,
+        ] {
+            self.f32(value);
+        }
+    }
+
+    fn opt_plist(&mut self, value: Option<&impl serde::Serialize>) -> Result<(), LoadError> {
+        match value {
+            Some(value) => {
+                self.u8(1);
+                let mut plist = Vec::new();
+                plist::to_writer_binary(&mut plist, value)?;
+                self.bytes(&plist);
+            }
+            None => self.u8(0),
+        }
+        Ok(())
+    }
+
+    /// An empty lib is written as if there were none.
+    fn lib(&mut self, lib: &norad::Plist) -> Result<(), LoadError> {
+        self.opt_plist(Some(lib).filter(|lib| !lib.is_empty()))
+    }
+
+    /// The identifier and lib of a glyph's guideline, anchor, component, contour or
+    /// point.
+    fn object(
+        &mut self,
+        identifier: Option<&norad::Identifier>,
+        lib: Option<&norad::Plist>,
+    ) -> Result<(), LoadError> {
+        self.opt_str(identifier.map(|i| i.as_str()));
+        self.opt_plist(lib)
+    }
+}
+
+/// Reads the values a `Writer` appended from the start of a packed font.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], LoadError> {
+        if self.0.len() < len {
+            return Err(invalid());
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, LoadError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<usize, LoadError> {
+        let bytes = self.take(4)?.try_into().unwrap();
+        Ok(u32::from_le_bytes(bytes) as usize)
+    }
+
+    fn f32(&mut self) -> Result<f32, LoadError> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], LoadError> {
+        let len = self.u32()?;
+        self.take(len)
+    }
+
+    fn str(&mut self) -> Result<&'a str, LoadError> {
+        std::str::from_utf8(self.bytes()?).map_err(|_| invalid())
+    }
+
+    fn opt_str(&mut self) -> Result<Option<&'a str>, LoadError> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => self.str().map(Some),
+        }
+    }
+
+    fn opt_color(&mut self) -> Result<Option<norad::Color>, LoadError> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => Ok(Some(norad::Color {
+                red: self.f32()?,
+                green: self.f32()?,
+                blue: self.f32()?,
+                alpha: self.f32()?,
+            })),
+        }
+    }
+
+    fn transform(&mut self) -> Result<norad::AffineTransform, LoadError> {
+        Ok(norad::AffineTransform {
+            x_scale: self.f32()?,
+            xy_scale: self.f32()?,
+            yx_scale: self.f32()?,
+            y_scale: self.f32()?,
+            x_offset: self.f32()?,
+            y_offset: self.f32()?,
+        })
+    }
+
+    fn opt_plist<T: serde::de::DeserializeOwned>(&mut self) -> Result<Option<T>, LoadError> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => plist::from_bytes(self.bytes()?)
+                .map(Some)
+                .map_err(|_| invalid()),
+        }
+    }
+
+    fn lib(&mut self) -> Result<norad::Plist, LoadError> {
+        Ok(self.opt_plist()?.unwrap_or_default())
+    }
+
+    fn object(&mut self) -> Result<(Option<norad::Identifier>, Option<norad::Plist>), LoadError> {
+        let identifier = match self.opt_str()? {
+            Some(identifier) => Some(norad::Identifier::new(identifier).map_err(|_| invalid())?),
+            None => None,
+        };
+        Ok((identifier, self.opt_plist()?))
+    }
+}
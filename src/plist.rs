@@ -1,15 +1,24 @@
-use crate::MyToPyObject;
+use crate::{IondriveError, MyFromPyObject, MyToPyObject};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{
+    PyBool, PyBytes, PyDateAccess, PyDateTime, PyDict, PyFloat, PyInt, PyList, PyString,
+    PyTimeAccess, PyTuple,
+};
+use pyo3::PyNativeType;
 
 impl MyToPyObject for plist::Value {
     fn to_object(&self, py: Python) -> PyObject {
         match self {
             plist::Value::String(s) => s.to_object(py),
             plist::Value::Boolean(s) => s.to_object(py),
-            plist::Value::Data(s) => s.to_object(py),
+            plist::Value::Data(s) => PyBytes::new(py, s).into(),
+            plist::Value::Date(s) => date_to_object(s, py),
             plist::Value::Real(s) => s.to_object(py),
-            plist::Value::Integer(s) => s.as_signed().to_object(py),
+            // Integers too large for an i64 are kept as unsigned ones.
+            plist::Value::Integer(s) => match s.as_signed() {
+                Some(signed) => signed.to_object(py),
+                None => s.as_unsigned().to_object(py),
+            },
             plist::Value::Uid(s) => s.get().to_object(py),
             plist::Value::Array(s) => s
                 .iter()
@@ -17,12 +26,63 @@ impl MyToPyObject for plist::Value {
                 .collect::<Vec<PyObject>>()
                 .to_object(py),
             plist::Value::Dictionary(s) => s.to_object(py),
-            // XXX Date!
             _ => py.None(),
         }
     }
 }
 
+/// A naive `datetime` in UTC for `date`, as plistlib reads `<date>` elements.
+fn date_to_object(date: &plist::Date, py: Python) -> PyObject {
+    // The XML format is `YYYY-MM-DDTHH:MM:SSZ` for every date a UFO can hold.
+    let text = date.to_xml_format();
+    let field = |start: usize, len: usize| text.get(start..start + len)?.parse::<u8>().ok();
+    let datetime = (|| {
+        let year = text.get(0..4)?.parse().ok()?;
+        PyDateTime::new(
+            py,
+            year,
+            field(5, 2)?,
+            field(8, 2)?,
+            field(11, 2)?,
+            field(14, 2)?,
+            field(17, 2)?,
+            0,
+            None,
+        )
+        .ok()
+    })();
+    datetime.map_or_else(|| py.None(), |d| d.into())
+}
+
+/// The plist date for `datetime`. A naive `datetime` is taken to be in UTC, and an
+/// aware one is converted to UTC. Microseconds are dropped, as plists cannot
+/// store them.
+fn date_from_object(datetime: &PyDateTime) -> PyResult<plist::Date> {
+    let datetime: &PyDateTime = match datetime.getattr("tzinfo")?.is_none() {
+        true => datetime,
+        false => {
+            let utc = datetime
+                .py()
+                .import("datetime")?
+                .getattr("timezone")?
+                .getattr("utc")?;
+            datetime.call_method1("astimezone", (utc,))?.downcast()?
+        }
+    };
+    let text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        datetime.get_year(),
+        datetime.get_month(),
+        datetime.get_day(),
+        datetime.get_hour(),
+        datetime.get_minute(),
+        datetime.get_second()
+    );
+    plist::Date::from_xml_format(&text).map_err(|_| {
+        IondriveError::new_err(format!("cannot store the date {} in a plist", datetime))
+    })
+}
+
 impl MyToPyObject for plist::Dictionary {
     fn to_object(&self, py: Python) -> PyObject {
         let d = PyDict::new(py);
@@ -32,3 +92,53 @@ impl MyToPyObject for plist::Dictionary {
         d.into()
     }
 }
+
+impl MyFromPyObject for plist::Value {
+    fn from_object(obj: &PyAny) -> PyResult<Self> {
+        // bool is a subclass of int, so it must be checked first.
+        if let Ok(b) = obj.downcast::<PyBool>() {
+            Ok(plist::Value::Boolean(b.is_true()))
+        } else if let Ok(s) = obj.downcast::<PyString>() {
+            Ok(plist::Value::String(s.to_str()?.to_string()))
+        } else if obj.is_instance::<PyInt>()? {
+            match obj.extract::<i64>() {
+                Ok(signed) => Ok(plist::Value::Integer(signed.into())),
+                Err(_) => Ok(plist::Value::Integer(obj.extract::<u64>()?.into())),
+            }
+        } else if obj.is_instance::<PyFloat>()? {
+            Ok(plist::Value::Real(obj.extract()?))
+        } else if let Ok(b) = obj.downcast::<PyBytes>() {
+            Ok(plist::Value::Data(b.as_bytes().to_vec()))
+        } else if let Ok(d) = obj.downcast::<PyDateTime>() {
+            Ok(plist::Value::Date(date_from_object(d)?))
+        } else if obj.is_instance::<PyDict>()? {
+            Ok(plist::Value::Dictionary(plist::Dictionary::from_object(
+                obj,
+            )?))
+        } else if obj.is_instance::<PyList>()? || obj.is_instance::<PyTuple>()? {
+            obj.iter()?
+                .map(|v| v.and_then(plist::Value::from_object))
+                .collect::<PyResult<_>>()
+                .map(plist::Value::Array)
+        } else {
+            Err(IondriveError::new_err(format!(
+                "cannot store a {} in a plist",
+                obj.get_type().name()?
+            )))
+        }
+    }
+}
+
+/// The keys are sorted, as plistlib sorts them when writing, so that a lib is
+/// saved the same whatever order its keys were added to it in. norad sorts the
+/// keys of the libs it writes too, but not those of dictionaries in arrays.
+impl MyFromPyObject for plist::Dictionary {
+    fn from_object(obj: &PyAny) -> PyResult<Self> {
+        let mut d = plist::Dictionary::new();
+        for (k, v) in obj.downcast::<PyDict>()?.iter() {
+            d.insert(k.extract()?, plist::Value::from_object(v)?);
+        }
+        d.sort_keys();
+        Ok(d)
+    }
+}
@@ -0,0 +1,71 @@
+use norad::{Glyph, Guideline, Line};
+
+/// Rounds the coordinates of the contour points, anchors and guidelines of every
+/// glyph in `ufo`, the offsets of their components and the coordinates of the
+/// font's guidelines to `digits` decimal places.
+pub(crate) fn round_coordinates(ufo: &mut norad::Font, digits: u32) {
+    let layer_names: Vec<_> = ufo.layers.names().cloned().collect();
+    for layer_name in layer_names {
+        let layer = ufo.layers.get_mut(&layer_name).unwrap();
+        for glyph in layer.iter_mut() {
+            round_glyph(glyph, digits);
+        }
+    }
+    let guidelines = ufo.font_info.as_mut().and_then(|i| i.guidelines.as_mut());
+    for guideline in guidelines.into_iter().flatten() {
+        round_guideline(guideline, digits);
+    }
+}
+
+fn round_glyph(glyph: &mut Glyph, digits: u32) {
+    for point in glyph.contours.iter_mut().flat_map(|c| &mut c.points) {
+        point.x = round(point.x, digits);
+        point.y = round(point.y, digits);
+    }
+    for anchor in &mut glyph.anchors {
+        anchor.x = round(anchor.x, digits);
+        anchor.y = round(anchor.y, digits);
+    }
+    for guideline in &mut glyph.guidelines {
+        round_guideline(guideline, digits);
+    }
+    for component in &mut glyph.components {
+        component.transform.x_offset = round(component.transform.x_offset, digits);
+        component.transform.y_offset = round(component.transform.y_offset, digits);
+    }
+}
+
+/// Rounds the position of `guideline`, leaving its angle as it is.
+fn round_guideline(guideline: &mut Guideline, digits: u32) {
+    guideline.line = match guideline.line {
+        Line::Vertical(x) => Line::Vertical(round(x, digits)),
+        Line::Horizontal(y) => Line::Horizontal(round(y, digits)),
+        Line::Angle { x, y, degrees } => Line::Angle {
+            x: round(x, digits),
+            y: round(y, digits),
+            degrees,
+        },
+    };
+}
+
+/// Rounds `v` to `digits` decimal places, halves to the even neighbour.
+///
+/// The rounding is done on the shortest decimal that reads back as `v`, as it is
+/// given to Python, so that a `2.675` in the source is a tie even though the
+/// nearest `f32` is below it.
+fn round(v: f32, digits: u32) -> f32 {
+    // An f32 has fewer significant digits than this, so nothing would change.
+    if digits > 15 {
+        return v;
+    }
+    let decimal: f64 = v.to_string().parse().unwrap_or(v as f64);
+    let scale = 10f64.powi(digits as i32);
+    let scaled = decimal * scale;
+    // Scaling the decimal can land just off a tie, as with 2.675 * 100.
+    let fraction = (scaled - scaled.trunc()).abs();
+    let rounded = match (fraction - 0.5).abs() < 1e-9 * scaled.abs().max(1.0) {
+        true => (scaled.trunc() + 0.5 * scaled.signum()).round_ties_even(),
+        false => scaled.round(),
+    };
+    (rounded / scale) as f32
+}
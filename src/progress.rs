@@ -0,0 +1,105 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+use pyo3::prelude::*;
+
+use crate::error::{Category, LoadError};
+
+/// How many glyphs are parsed between calls of a progress callback.
+static PROGRESS_STEP: usize = 100;
+
+enum Update {
+    Done(usize),
+    Finished,
+}
+
+/// Counts the glyphs parsed so far, for a progress callback on the thread that
+/// started the load.
+pub(crate) struct Progress {
+    done: AtomicUsize,
+    cancelled: AtomicBool,
+    updates: mpsc::Sender<Update>,
+}
+
+impl Progress {
+    /// Counts one more glyph as parsed, unless the load has been cancelled.
+    // `%` rather than `is_multiple_of`, which only recent toolchains have.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub(crate) fn advance(&self) -> Result<(), LoadError> {
+        self.check()?;
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if done % PROGRESS_STEP == 0 {
+            let _ = self.updates.send(Update::Done(done));
+        }
+        Ok(())
+    }
+
+    /// Fails if the load has been cancelled, as the callback raised.
+    pub(crate) fn check(&self) -> Result<(), LoadError> {
+        match self.cancelled.load(Ordering::Relaxed) {
+            true => Err(LoadError::new(Category::Other, "load cancelled")),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Tells the calling thread that the work is done, even if it panicked.
+struct Finish<'a>(&'a Progress);
+
+impl Drop for Finish<'_> {
+    fn drop(&mut self) {
+        let _ = self.0.updates.send(Update::Finished);
+    }
+}
+
+/// Runs `work` on another thread, calling `callback` with `(glyphs_done,
+/// glyphs_total)` on this one when it starts, every `PROGRESS_STEP` glyphs and
+/// when it finishes.
+///
+/// The GIL is released while waiting for updates. If the callback raises, the
+/// work is cancelled and the exception is returned once it has stopped.
+pub(crate) fn with_progress<T: Send>(
+    py: Python,
+    callback: &PyObject,
+    total: usize,
+    work: impl FnOnce(&Progress) -> T + Send,
+) -> PyResult<T> {
+    let (updates, received) = mpsc::channel();
+    let progress = Progress {
+        done: AtomicUsize::new(0),
+        cancelled: AtomicBool::new(false),
+        updates,
+    };
+    py.allow_threads(move || {
+        std::thread::scope(|scope| {
+            let worker = scope.spawn(|| {
+                let _finish = Finish(&progress);
+                work(&progress)
+            });
+            let mut error = None;
+            let mut reported = None;
+            let mut report = |done: usize| {
+                if error.is_some() || reported == Some(done) {
+                    return;
+                }
+                reported = Some(done);
+                if let Err(e) = Python::with_gil(|py| callback.call1(py, (done, total))) {
+                    progress.cancelled.store(true, Ordering::Relaxed);
+                    error = Some(e);
+                }
+            };
+            report(0);
+            while let Ok(Update::Done(done)) = received.recv() {
+                report(done);
+            }
+            let result = worker
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            report(progress.done.load(Ordering::Relaxed));
+            match error {
+                Some(error) => Err(error),
+                None => Ok(result),
+            }
+        })
+    })
+}
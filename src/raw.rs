@@ -0,0 +1,183 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::contourpoint::{f32_to_int_or_float, transform_to_object};
+use crate::data;
+use crate::defcon::{anchor_dict, guideline_dict, image_dict, without_none};
+use crate::info::info_attributes;
+use crate::{LoadedFont, MyToPyObject};
+
+/// The font as plain dictionaries and lists, in the schema `load_raw` documents.
+pub(crate) fn font_dict(font: &LoadedFont, py: Python) -> PyResult<PyObject> {
+    let ufo = &font.ufo;
+    let dict = PyDict::new(py);
+    dict.set_item("info", info_dict(ufo.font_info.as_ref(), py)?)?;
+    dict.set_item(
+        "groups",
+        ufo.groups
+            .as_ref()
+            .map_or_else(|| PyDict::new(py).into(), |g| g.to_object(py)),
+    )?;
+    dict.set_item("kerning", kerning_dict(ufo.kerning.as_ref(), py)?)?;
+    dict.set_item("features", ufo.features.as_deref().unwrap_or(""))?;
+    dict.set_item("lib", ufo.lib.to_object(py))?;
+    let layers = ufo
+        .layers
+        .iter()
+        .map(|layer| layer_dict(layer, py))
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("layers", layers)?;
+    for (store, kind) in [(&font.data, &data::DATA), (&font.images, &data::IMAGES)] {
+        let files = PyDict::new(py);
+        for (name, contents) in store.files(kind)? {
+            files.set_item(name, PyBytes::new(py, &contents))?;
+        }
+        dict.set_item(kind.attribute(), files)?;
+    }
+    Ok(dict.into())
+}
+
+/// The values of `kerning` by second member by first member, empty if there is
+/// no kerning.
+pub(crate) fn kerning_dict<'py>(
+    kerning: Option<&norad::Kerning>,
+    py: Python<'py>,
+) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    for (first, seconds) in kerning.into_iter().flatten() {
+        let values = PyDict::new(py);
+        for (second, value) in seconds {
+            values.set_item(second, f32_to_int_or_float(*value, py))?;
+        }
+        dict.set_item(first, values)?;
+    }
+    Ok(dict)
+}
+
+fn info_dict<'py>(info: Option<&norad::FontInfo>, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    let info = match info {
+        Some(info) => info,
+        None => return Ok(dict),
+    };
+    for (name, value) in info_attributes(info, py) {
+        if !value.is_none(py) {
+            dict.set_item(name, value)?;
+        }
+    }
+    if let Some(guidelines) = &info.guidelines {
+        let guidelines: Vec<_> = guidelines.iter().map(|g| guideline_dict(g, py)).collect();
+        dict.set_item("guidelines", guidelines)?;
+    }
+    Ok(dict)
+}
+
+fn layer_dict<'py>(layer: &norad::Layer, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let glyphs = PyDict::new(py);
+    for glyph in layer.iter() {
+        glyphs.set_item(&*glyph.name, glyph_dict(glyph, py)?)?;
+    }
+    let dict = without_none(
+        py,
+        [
+            ("name", layer.name().to_object(py)),
+            (
+                "color",
+                layer
+                    .color
+                    .as_ref()
+                    .map(|c| c.to_rgba_string())
+                    .to_object(py),
+            ),
+        ],
+    );
+    dict.set_item("lib", layer.lib.to_object(py))?;
+    dict.set_item("glyphs", glyphs)?;
+    Ok(dict)
+}
+
+fn glyph_dict<'py>(glyph: &norad::Glyph, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let dict = without_none(
+        py,
+        [
+            ("name", glyph.name.to_object(py)),
+            ("width", f32_to_int_or_float(glyph.width, py)),
+            ("height", f32_to_int_or_float(glyph.height, py)),
+            ("note", glyph.note.to_object(py)),
+        ],
+    );
+    let unicodes: Vec<u32> = glyph.codepoints.iter().map(|c| *c as u32).collect();
+    dict.set_item("unicodes", unicodes)?;
+    dict.set_item("lib", crate::glyph_lib(glyph).to_object(py))?;
+    let anchors: Vec<_> = glyph.anchors.iter().map(|a| anchor_dict(a, py)).collect();
+    dict.set_item("anchors", anchors)?;
+    let guidelines: Vec<_> = glyph
+        .guidelines
+        .iter()
+        .map(|g| guideline_dict(g, py))
+        .collect();
+    dict.set_item("guidelines", guidelines)?;
+    let contours = glyph
+        .contours
+        .iter()
+        .map(|contour| contour_dict(contour, py))
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("contours", contours)?;
+    let components: Vec<_> = glyph
+        .components
+        .iter()
+        .map(|component| {
+            without_none(
+                py,
+                [
+                    ("baseGlyph", component.base.to_object(py)),
+                    (
+                        "transformation",
+                        transform_to_object(&component.transform, f32_to_int_or_float, py),
+                    ),
+                    (
+                        "identifier",
+                        component.identifier().map(|i| i.as_str()).to_object(py),
+                    ),
+                ],
+            )
+        })
+        .collect();
+    dict.set_item("components", components)?;
+    if let Some(image) = &glyph.image {
+        dict.set_item("image", image_dict(image, py))?;
+    }
+    Ok(dict)
+}
+
+fn contour_dict<'py>(contour: &norad::Contour, py: Python<'py>) -> PyResult<&'py PyDict> {
+    let points: Vec<_> = contour
+        .points
+        .iter()
+        .map(|point| {
+            without_none(
+                py,
+                [
+                    ("x", f32_to_int_or_float(point.x, py)),
+                    ("y", f32_to_int_or_float(point.y, py)),
+                    ("type", point.typ.to_object(py)),
+                    ("smooth", point.smooth.to_object(py)),
+                    ("name", point.name.to_object(py)),
+                    (
+                        "identifier",
+                        point.identifier().map(|i| i.as_str()).to_object(py),
+                    ),
+                ],
+            )
+        })
+        .collect();
+    let dict = without_none(
+        py,
+        [(
+            "identifier",
+            contour.identifier().map(|i| i.as_str()).to_object(py),
+        )],
+    );
+    dict.set_item("points", points)?;
+    Ok(dict)
+}
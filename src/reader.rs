@@ -0,0 +1,704 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+
+use crate::encoding;
+use crate::error::{Category, LoadError};
+use crate::glif;
+use crate::progress::Progress;
+use crate::IondriveError;
+
+pub(crate) static LAYER_CONTENTS_FILE: &str = "layercontents.plist";
+pub(crate) static CONTENTS_FILE: &str = "contents.plist";
+pub(crate) static DEFAULT_LAYER_NAME: &str = "public.default";
+pub(crate) static DEFAULT_GLYPHS_DIRNAME: &str = "glyphs";
+pub(crate) static METAINFO_FILE: &str = "metainfo.plist";
+static LAYER_INFO_FILE: &str = "layerinfo.plist";
+
+/// Reads the `(layer name, directory)` pairs of the UFO at `ufo`, in file order.
+///
+/// UFOs without a `layercontents.plist` only have the default layer.
+pub(crate) fn layer_contents(ufo: &Path) -> PyResult<Vec<(String, PathBuf)>> {
+    read_layer_contents(ufo).map_err(PyErr::from)
+}
+
+pub(crate) fn read_layer_contents(ufo: &Path) -> Result<Vec<(String, PathBuf)>, LoadError> {
+    if !ufo.is_dir() {
+        return Err(LoadError::new(
+            Category::Io,
+            format!("{} directory was not found", ufo.display()),
+        )
+        .with_kind("MissingUfoDir"));
+    }
+    let path = ufo.join(LAYER_CONTENTS_FILE);
+    if path.exists() {
+        Ok(plist::from_file(&path)?)
+    } else {
+        Ok(vec![(
+            DEFAULT_LAYER_NAME.to_string(),
+            PathBuf::from(DEFAULT_GLYPHS_DIRNAME),
+        )])
+    }
+}
+
+/// The names of the layers in `layers` in the order of the `layercontents.plist`
+/// of the UFO at `ufo`, if it differs from theirs, as norad keeps the default
+/// layer first. Layers that were not read from the file, such as the empty
+/// default layer of a font read without its layers, keep their order.
+pub(crate) fn layer_order(ufo: &Path, layers: &norad::LayerSet) -> Option<Vec<String>> {
+    let order: Vec<String> = read_layer_contents(ufo)
+        .ok()?
+        .into_iter()
+        .map(|(name, _)| name)
+        .filter(|name| layers.get(name).is_some())
+        .collect();
+    let unchanged = order
+        .iter()
+        .map(String::as_str)
+        .eq(layers.names().map(|n| &**n));
+    Some(order).filter(|order| !unchanged && order.len() == layers.len())
+}
+
+/// Resolves the directory of the layer `layer_name`, or of the default layer if
+/// no name is given.
+pub(crate) fn layer_dir(ufo: &Path, layer_name: Option<&str>) -> PyResult<PathBuf> {
+    let layers = layer_contents(ufo)?;
+    let found = match layer_name {
+        Some(name) => layers.into_iter().find(|(n, _)| n == name),
+        None => layers
+            .into_iter()
+            .find(|(_, dir)| dir == Path::new(DEFAULT_GLYPHS_DIRNAME)),
+    };
+    match found {
+        Some((_, dir)) => Ok(ufo.join(dir)),
+        None => Err(PyKeyError::new_err(
+            layer_name.unwrap_or(DEFAULT_LAYER_NAME).to_string(),
+        )),
+    }
+}
+
+/// Parses the layer `layer_name` of the UFO at `ufo`, including its layer info.
+pub(crate) fn read_layer(ufo: &Path, layer_name: &str) -> PyResult<norad::Layer> {
+    let dir = layer_contents(ufo)?
+        .into_iter()
+        .find(|(name, _)| name == layer_name)
+        .map(|(_, dir)| ufo.join(dir))
+        .ok_or_else(|| {
+            IondriveError::new_err(format!(
+                "{} has no layer named '{}'",
+                ufo.display(),
+                layer_name
+            ))
+        })?;
+    Ok(load_layer(&dir, layer_name)?)
+}
+
+/// Parses the layer `name` from the layer directory `dir` with norad, keeping
+/// the glyph notes as written.
+pub(crate) fn load_layer(dir: &Path, name: &str) -> Result<norad::Layer, LoadError> {
+    let mut layer = norad::Layer::load(dir, name.into())?;
+    restore_layer_notes(&mut layer, dir);
+    Ok(layer)
+}
+
+/// Puts back the whitespace around glyph notes that norad trims, in all layers
+/// of `ufo` as norad loaded them from the UFO at `path`.
+pub(crate) fn restore_notes(ufo: &mut norad::Font, path: &Path) {
+    let names: Vec<_> = ufo.layers.names().cloned().collect();
+    for name in names {
+        if let Some(layer) = ufo.layers.get_mut(&name) {
+            let dir = path.join(layer.path());
+            restore_layer_notes(layer, &dir);
+        }
+    }
+}
+
+/// Puts back the note whitespace of the glyphs of `layer`, as norad loaded it
+/// from the layer directory `dir`. Only the files of glyphs with a note are
+/// read again.
+fn restore_layer_notes(layer: &mut norad::Layer, dir: &Path) {
+    let with_notes: Vec<(String, PathBuf)> = layer
+        .iter()
+        .filter(|glyph| glyph.note.is_some())
+        .filter_map(|glyph| Some((glyph.name.to_string(), layer.get_path(&glyph.name)?.into())))
+        .collect();
+    for (name, file_name) in with_notes {
+        if let Some(glyph) = layer.get_glyph_mut(name.as_str()) {
+            restore_note(glyph, &dir.join(file_name));
+        }
+    }
+}
+
+/// Puts back the note whitespace of `glyph`, parsed from the GLIF file `path`.
+fn restore_note(glyph: &mut norad::Glyph, path: &Path) {
+    if glyph.note.is_some() {
+        if let Ok(glif) = std::fs::read_to_string(path) {
+            glif::restore_note(glyph, &glif);
+        }
+    }
+}
+
+/// Checks that nothing in the UFO at `ufo`, the directory itself aside, is a
+/// symbolic link, failing with the first one found.
+pub(crate) fn check_no_symlinks(ufo: &Path) -> Result<(), LoadError> {
+    let mut pending = vec![ufo.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_symlink() {
+                return Err(LoadError::new(
+                    Category::Io,
+                    format!(
+                        "{} is a symbolic link, which follow_symlinks=False forbids",
+                        entry.path().display()
+                    ),
+                ));
+            }
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the glyph name to file name mapping of the layer directory `dir`.
+pub(crate) fn glyph_contents(dir: &Path) -> PyResult<BTreeMap<String, PathBuf>> {
+    read_glyph_contents(dir).map_err(PyErr::from)
+}
+
+pub(crate) fn read_glyph_contents(dir: &Path) -> Result<BTreeMap<String, PathBuf>, LoadError> {
+    let path = dir.join(CONTENTS_FILE);
+    if !path.exists() {
+        return Err(LoadError::new(
+            Category::Io,
+            format!("missing required {} file", path.display()),
+        )
+        .with_kind("MissingFile"));
+    }
+    Ok(plist::from_file(&path)?)
+}
+
+/// The names of the glyphs of each layer of the UFO at `ufo` whose GLIF file was
+/// modified after `since`, in seconds since the Unix epoch, by layer name.
+/// Glyphs whose file is missing are left out.
+pub(crate) fn glyphs_modified_since(
+    ufo: &Path,
+    since: f64,
+) -> Result<HashMap<String, HashSet<String>>, LoadError> {
+    let mut modified = HashMap::new();
+    for (layer, dir) in read_layer_contents(ufo)? {
+        let dir = ufo.join(dir);
+        let mut names = HashSet::new();
+        for (name, file_name) in read_glyph_contents(&dir)? {
+            let mtime = match std::fs::metadata(dir.join(file_name)) {
+                Ok(metadata) => metadata.modified()?,
+                Err(_) => continue,
+            };
+            let mtime = mtime
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64());
+            if mtime > since {
+                names.insert(name);
+            }
+        }
+        modified.insert(layer, names);
+    }
+    Ok(modified)
+}
+
+/// Parses the single glyph `glyph_name` from the layer directory `dir`.
+pub(crate) fn read_glyph(dir: &Path, glyph_name: &str) -> PyResult<norad::Glyph> {
+    let contents = glyph_contents(dir)?;
+    let file_name = contents
+        .get(glyph_name)
+        .ok_or_else(|| PyKeyError::new_err(glyph_name.to_string()))?;
+    Ok(read_glyph_file(dir, file_name, glyph_name)?)
+}
+
+/// Parses the glyph `glyph_name` from the GLIF file `file_name` in the layer
+/// directory `dir`, as listed in its `contents.plist`.
+pub(crate) fn read_glyph_file(
+    dir: &Path,
+    file_name: &Path,
+    glyph_name: &str,
+) -> Result<norad::Glyph, LoadError> {
+    let path = dir.join(file_name);
+    let mut glyph = load_glif(&path, false)?;
+    restore_note(&mut glyph, &path);
+    glyph.name = glyph_name.into();
+    Ok(glyph)
+}
+
+/// Parses the GLIF file at `path` with norad.
+///
+/// A glyph that norad rejects for guideline angles outside 0 to 360 degrees is
+/// parsed again with them brought into `[0, 360)`, so that -45 degrees becomes
+/// 315. If `lenient`, the angles are then put back as they were in the file.
+pub(crate) fn load_glif(path: &Path, lenient: bool) -> Result<norad::Glyph, norad::Error> {
+    let error = match norad::Glyph::load(path) {
+        Ok(glyph) => return Ok(glyph),
+        Err(error) if !is_guideline_error(&error) => return Err(error),
+        Err(error) => error,
+    };
+    let parsed = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|glif| glif::parse_with_normalized_angles(&glif));
+    let (mut glyph, angles) = parsed.ok_or(error)?;
+    if lenient {
+        for (index, degrees) in angles {
+            if let Some(norad::Line::Angle { degrees: angle, .. }) =
+                glyph.guidelines.get_mut(index).map(|g| &mut g.line)
+            {
+                *angle = degrees;
+            }
+        }
+    }
+    Ok(glyph)
+}
+
+/// Whether norad rejected a GLIF file for one of its guidelines, which may be
+/// for an angle outside 0 to 360 degrees.
+pub(crate) fn is_guideline_error(error: &norad::Error) -> bool {
+    matches!(
+        error,
+        norad::Error::Glif(norad::error::GlifError {
+            kind: norad::error::ErrorKind::BadGuideline,
+            ..
+        })
+    )
+}
+
+/// A glyph that the `contents.plist` of a layer lists more than once.
+pub(crate) struct DuplicateGlyph {
+    layer: String,
+    /// The layer directory, relative to the UFO.
+    dir: PathBuf,
+    name: String,
+    /// The file listed first for the glyph.
+    first: PathBuf,
+    /// A file listed for it later, which norad keeps instead.
+    other: PathBuf,
+}
+
+impl DuplicateGlyph {
+    /// The error for the duplicate, in the layer's `contents.plist`, with paths
+    /// relative to the UFO.
+    pub(crate) fn error(&self) -> LoadError {
+        self.located(self.describe())
+    }
+
+    /// The problem skipped for the duplicate when the file listed first is read.
+    pub(crate) fn skipped(&self) -> LoadError {
+        self.located(format!("{}, keeping the first", self.describe()))
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "glyph '{}' is listed more than once, as {} and {}",
+            self.name,
+            self.dir.join(&self.first).display(),
+            self.dir.join(&self.other).display()
+        )
+    }
+
+    fn located(&self, message: String) -> LoadError {
+        LoadError::new(Category::Structure, message)
+            .with_kind("DuplicateGlyph")
+            .in_file(&self.dir.join(CONTENTS_FILE))
+    }
+
+    /// Parses the glyph from the file listed first into `ufo`, which was
+    /// loaded from the UFO at `path`, in place of the one norad kept.
+    pub(crate) fn keep_first(&self, ufo: &mut norad::Font, path: &Path) -> Result<(), LoadError> {
+        if let Some(layer) = ufo.layers.get_mut(&self.layer) {
+            if layer.contains_glyph(&self.name) {
+                let dir = path.join(&self.dir);
+                layer.insert_glyph(read_glyph_file(&dir, &self.first, &self.name)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finds the glyphs listed more than once in the `contents.plist` files of the
+/// layers of the UFO at `ufo` that are in `selection`.
+pub(crate) fn duplicate_glyphs(
+    ufo: &Path,
+    selection: &Selection,
+) -> Result<Vec<DuplicateGlyph>, LoadError> {
+    let mut duplicates = Vec::new();
+    for (layer, dir) in read_layer_contents(ufo)? {
+        if !selection.has_layer(&layer, &dir) {
+            continue;
+        }
+        let path = ufo.join(&dir).join(CONTENTS_FILE);
+        if !path.exists() {
+            continue;
+        }
+        let ContentsEntries(entries) = plist::from_file(&path)?;
+        let mut listed: HashMap<String, PathBuf> = HashMap::new();
+        for (name, file) in entries {
+            let first = match listed.get(&name) {
+                Some(first) => first.clone(),
+                None => {
+                    listed.insert(name, file);
+                    continue;
+                }
+            };
+            if selection.glyphs.as_ref().is_none_or(|g| g.contains(&name)) {
+                duplicates.push(DuplicateGlyph {
+                    layer: layer.clone(),
+                    dir: dir.clone(),
+                    name,
+                    first,
+                    other: file,
+                });
+            }
+        }
+    }
+    Ok(duplicates)
+}
+
+/// The entries of a `contents.plist` in file order, including repeated glyph
+/// names, which reading it into a map would drop.
+struct ContentsEntries(Vec<(String, PathBuf)>);
+
+impl<'de> Deserialize<'de> for ContentsEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = ContentsEntries;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a dictionary of glyph names to file names")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(ContentsEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// The parts of a UFO to parse, where not all of them are wanted.
+#[derive(Default)]
+pub(crate) struct Selection {
+    /// The names of the glyphs to parse, or all glyphs if not given.
+    pub(crate) glyphs: Option<HashSet<String>>,
+    /// The names of the layers to parse, or all layers if not given. The default
+    /// layer is always parsed.
+    pub(crate) layers: Option<HashSet<String>>,
+}
+
+impl Selection {
+    /// Whether everything is selected.
+    pub(crate) fn is_all(&self) -> bool {
+        self.glyphs.is_none() && self.layers.is_none()
+    }
+
+    /// Whether the layer `name`, stored in the directory `dir`, is selected.
+    pub(crate) fn has_layer(&self, name: &str, dir: &Path) -> bool {
+        dir == Path::new(DEFAULT_GLYPHS_DIRNAME)
+            || self.layers.as_ref().is_none_or(|l| l.contains(name))
+    }
+}
+
+/// Parses the UFO at `path` layer by layer, for fonts that norad cannot load in
+/// one go or when only some of it is selected.
+///
+/// Only the glyphs and layers in `selection` are parsed, each glyph counted in
+/// `progress` if given. If `lenient`, glyphs that
+/// fail to parse are left out of their layer, a layer whose `layerinfo.plist` is
+/// broken loses its color and lib, groups and kerning are dropped if either
+/// cannot be read, and font-level plists that are not UTF-8 are read as latin-1.
+/// Each problem skipped is returned in a list. Anything else that norad rejects is still an error.
+pub(crate) fn read_font(
+    path: &Path,
+    mut request: norad::DataRequest,
+    lenient: bool,
+    selection: &Selection,
+    progress: Option<&Progress>,
+) -> Result<(norad::Font, Vec<LoadError>), LoadError> {
+    let mut skipped = Vec::new();
+    let load_layers = request.layers;
+    request.layers(false);
+    let transcoded = match lenient {
+        true => encoding::transcode_font_plists(path)?,
+        false => None,
+    };
+    if let Some(transcoded) = &transcoded {
+        for file in &transcoded.files {
+            let message = format!("UTF-8 decoding of {}, reading it as latin-1", file);
+            skipped.push(LoadError::new(Category::Parse, message).with_kind("InvalidPlist"));
+        }
+    }
+    let font_dir = transcoded.as_ref().map_or(path, |t| t.path());
+    let mut ufo = match norad::Font::load_requested_data(font_dir, request) {
+        Ok(ufo) => ufo,
+        Err(error) if lenient => {
+            request.groups(false).kerning(false);
+            let ufo = norad::Font::load_requested_data(font_dir, request)?;
+            skipped.push(LoadError::from(error).context("groups and kerning"));
+            ufo
+        }
+        Err(error) => return Err(error.into()),
+    };
+    if !load_layers {
+        return Ok((ufo, skipped));
+    }
+
+    let mut layers = Vec::new();
+    for (name, dir) in read_layer_contents(path)? {
+        if !selection.has_layer(&name, &dir) {
+            continue;
+        }
+        let dir = path.join(dir);
+        let glyphs = selection.glyphs.as_ref();
+        let layer = match (glyphs, progress) {
+            (None, None) => load_layer(&dir, &name)
+                .or_else(|_| read_layer_glyphs(&dir, &name, None, lenient, None, &mut skipped))?,
+            _ => read_layer_glyphs(&dir, &name, glyphs, lenient, progress, &mut skipped)?,
+        };
+        layers.push(layer);
+    }
+    // norad expects the default layer to come first, and a layer set to have
+    // one. The default layer is always selected, so a layer set without one
+    // comes from a `layercontents.plist` that does not list it.
+    let default = layers
+        .iter()
+        .position(|l| l.path() == Path::new(DEFAULT_GLYPHS_DIRNAME))
+        .ok_or_else(|| LoadError::from(norad::Error::MissingDefaultLayer))?;
+    layers[..=default].rotate_right(1);
+    ufo.layers = norad::LayerSet::new(layers);
+    Ok((ufo, skipped))
+}
+
+/// Adds to `glyphs` the base glyphs of their components in each of the layer
+/// directories `dirs`, and the base glyphs of those in turn. Glyphs that fail to
+/// parse are passed over, to be reported when they are loaded.
+pub(crate) fn add_component_bases(
+    dirs: &[PathBuf],
+    glyphs: &mut HashSet<String>,
+) -> Result<(), LoadError> {
+    let layers = dirs
+        .iter()
+        .map(|dir| Ok((dir, read_glyph_contents(dir)?)))
+        .collect::<Result<Vec<_>, LoadError>>()?;
+    let mut pending: Vec<String> = glyphs.iter().cloned().collect();
+    while let Some(name) = pending.pop() {
+        for (dir, contents) in &layers {
+            let glyph = match contents
+                .get(&name)
+                .map(|file| load_glif(&dir.join(file), false))
+            {
+                Some(Ok(glyph)) => glyph,
+                _ => continue,
+            };
+            for component in &glyph.components {
+                if glyphs.insert(component.base.to_string()) {
+                    pending.push(component.base.to_string());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the advance width of each glyph of the layer directory `dir` from its
+/// `<advance>` element alone, in parallel, without parsing the rest of the GLIF
+/// files.
+pub(crate) fn advance_widths(dir: &Path) -> Result<BTreeMap<String, f32>, LoadError> {
+    read_glyph_contents(dir)?
+        .into_par_iter()
+        .map(|(name, file_name)| {
+            let path = dir.join(file_name);
+            let locate = |error: LoadError| match dir.parent() {
+                Some(ufo) => error.in_file(&path).relative_to(ufo),
+                None => error.in_file(&path),
+            };
+            let glif = std::fs::read_to_string(&path).map_err(|e| locate(e.into()))?;
+            let width = glif::advance_width(&glif).map_err(|message| {
+                locate(LoadError::new(Category::Parse, message).with_kind("InvalidGlif"))
+            })?;
+            Ok((name, width))
+        })
+        .collect()
+}
+
+/// Counts the glyphs in `selection` of the UFO at `ufo`, over all selected layers.
+pub(crate) fn count_glyphs(ufo: &Path, selection: &Selection) -> Result<usize, LoadError> {
+    let mut count = 0;
+    for (name, dir) in read_layer_contents(ufo)? {
+        if !selection.has_layer(&name, &dir) {
+            continue;
+        }
+        let contents = read_glyph_contents(&ufo.join(dir))?;
+        count += match &selection.glyphs {
+            Some(glyphs) => contents.keys().filter(|n| glyphs.contains(*n)).count(),
+            None => contents.len(),
+        };
+    }
+    Ok(count)
+}
+
+/// Parses the glyphs of the layer directory `dir` one by one, keeping only those
+/// in `selection` if given and counting each in `progress`. Problems are skipped
+/// if `lenient`.
+fn read_layer_glyphs(
+    dir: &Path,
+    name: &str,
+    selection: Option<&HashSet<String>>,
+    lenient: bool,
+    progress: Option<&Progress>,
+    skipped: &mut Vec<LoadError>,
+) -> Result<norad::Layer, LoadError> {
+    let mut layer = norad::Layer::new(name.into(), dir.file_name().map(PathBuf::from));
+    let glyphs: Vec<Result<norad::Glyph, LoadError>> = read_glyph_contents(dir)?
+        .into_par_iter()
+        .filter(|(glyph_name, _)| selection.is_none_or(|s| s.contains(glyph_name)))
+        .map(|(glyph_name, file_name)| {
+            if let Some(progress) = progress {
+                progress.advance()?;
+            }
+            let path = dir.join(file_name);
+            let mut glyph = load_glif(&path, lenient).map_err(|e| {
+                let error = LoadError::from(e).in_file(&path);
+                match dir.parent() {
+                    Some(ufo) => error.relative_to(ufo),
+                    None => error,
+                }
+            })?;
+            restore_note(&mut glyph, &path);
+            glyph.name = glyph_name.as_str().into();
+            Ok(glyph)
+        })
+        .collect();
+    // Being cancelled is not a problem to skip.
+    if let Some(progress) = progress {
+        progress.check()?;
+    }
+    for glyph in glyphs {
+        match glyph {
+            Ok(glyph) => layer.insert_glyph(glyph),
+            Err(error) if lenient => skipped.push(error),
+            Err(error) => return Err(error),
+        }
+    }
+    match read_layer_info(&dir.join(LAYER_INFO_FILE)) {
+        Ok((color, lib)) => {
+            layer.color = color;
+            layer.lib = lib;
+        }
+        Err(error) => {
+            let error = error.context(format!("layer {} info", name));
+            match lenient {
+                true => skipped.push(error),
+                false => return Err(error),
+            }
+        }
+    }
+    Ok(layer)
+}
+
+/// What the `metainfo.plist` of a UFO says.
+pub(crate) struct MetaInfo {
+    pub(crate) format_version: u64,
+    /// The `formatVersionMinor`, 0 if not given.
+    pub(crate) format_version_minor: u64,
+    pub(crate) creator: Option<String>,
+}
+
+/// Reads the `metainfo.plist` of the UFO at `ufo`.
+pub(crate) fn read_metainfo(ufo: &Path) -> Result<MetaInfo, LoadError> {
+    let path = ufo.join(METAINFO_FILE);
+    if !path.exists() {
+        return Err(missing_metainfo(&path));
+    }
+    parse_metainfo(&std::fs::read(&path)?, &path)
+}
+
+/// The error for a UFO without the `metainfo.plist` that would be at `path`.
+pub(crate) fn missing_metainfo(path: &Path) -> LoadError {
+    LoadError::new(
+        Category::Io,
+        format!("missing required {} file", path.display()),
+    )
+    .with_kind("MissingFile")
+}
+
+/// Parses `data`, the contents of the `metainfo.plist` at `path`.
+///
+/// The format version is not checked against the versions norad reads, so that
+/// callers can report unsupported ones themselves. A `formatVersionMinor` that is
+/// not a non-negative integer is taken as 0, as norad ignores it.
+pub(crate) fn parse_metainfo(data: &[u8], path: &Path) -> Result<MetaInfo, LoadError> {
+    let metainfo = plist::from_bytes::<plist::Value>(data)?;
+    let metainfo = metainfo.as_dictionary();
+    let invalid = |message: &str| {
+        LoadError::new(Category::Parse, format!("{} {}", path.display(), message))
+            .with_kind("InvalidPlist")
+    };
+    let format_version = metainfo
+        .and_then(|metainfo| metainfo.get("formatVersion"))
+        .and_then(plist::Value::as_unsigned_integer)
+        .ok_or_else(|| invalid("has no formatVersion"))?;
+    let format_version_minor = metainfo
+        .and_then(|metainfo| metainfo.get("formatVersionMinor"))
+        .and_then(plist::Value::as_unsigned_integer)
+        .unwrap_or(0);
+    let creator = match metainfo.and_then(|metainfo| metainfo.get("creator")) {
+        Some(creator) => Some(
+            creator
+                .as_string()
+                .ok_or_else(|| invalid("has a creator that is not a string"))?
+                .to_string(),
+        ),
+        None => None,
+    };
+    Ok(MetaInfo {
+        format_version,
+        format_version_minor,
+        creator,
+    })
+}
+
+/// Reads the color and lib from a `layerinfo.plist`, if there is one.
+fn read_layer_info(path: &Path) -> Result<(Option<norad::Color>, norad::Plist), LoadError> {
+    if !path.exists() {
+        return Ok((None, norad::Plist::new()));
+    }
+    let invalid = |message: &str| LoadError::new(Category::Structure, message);
+    let mut info = plist::Value::from_file(path)?
+        .into_dictionary()
+        .ok_or_else(|| invalid("expected a dictionary"))?;
+    let color = match info.remove("color") {
+        Some(color) => Some(
+            color
+                .as_string()
+                .ok_or_else(|| invalid("expected the color to be a string"))?
+                .parse::<norad::Color>()
+                .map_err(|e| invalid(&e.to_string()))?,
+        ),
+        None => None,
+    };
+    let lib = match info.remove("lib") {
+        Some(lib) => lib
+            .into_dictionary()
+            .ok_or_else(|| invalid("expected the lib to be a dictionary"))?,
+        None => norad::Plist::new(),
+    };
+    Ok((color, lib))
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+
+use crate::ufoz::{self, ExtractedUfoz};
+use crate::{
+    load_font, reader, wrap_groups, wrap_kerning, IondriveError, LoadOptions, ToWrappedPyObject,
+};
+
+/// A layer of the UFO as indexed when it is opened.
+struct LayerIndex {
+    name: String,
+    dir: PathBuf,
+    /// The glyph name to file name mapping of its `contents.plist`.
+    contents: BTreeMap<String, PathBuf>,
+}
+
+/// A UFO opened for reading parts of it as they are needed, with the objects
+/// from `font_objects_module`.
+///
+/// `metainfo.plist`, `layercontents.plist` and the `contents.plist` of every
+/// layer are read once, when the reader is created. Each method then parses only
+/// the files it needs, so reading many glyphs or layers this way does not scan
+/// the UFO each time as `load_glyph` and `load_layer` do. The UFO is not watched
+/// for changes: create a new reader to see glyphs added or removed since.
+///
+/// `path` may also be a `.ufoz` archive, which is unpacked to a temporary
+/// directory that is kept until the reader is closed. A reader is closed by
+/// `close`, when leaving a `with` block it was the context manager of, or when
+/// it is garbage collected. Once closed, its methods raise `IondriveError`.
+#[pyclass(module = "iondrive")]
+#[pyo3(text_signature = "(font_objects_module, path, /)")]
+pub(crate) struct UfoReader {
+    /// The path the reader was opened with.
+    path: PathBuf,
+    /// The UFO directory read from, which is `path` unless it is an archive.
+    dir: PathBuf,
+    /// The unpacked archive, while the reader is open.
+    extracted: Option<ExtractedUfoz>,
+    closed: bool,
+    loader: PyObject,
+    /// The `formatVersion` of `metainfo.plist`.
+    #[pyo3(get)]
+    format_version: u64,
+    layers: Vec<LayerIndex>,
+}
+
+impl UfoReader {
+    /// The UFO directory to read, unless the reader is closed.
+    fn dir(&self) -> PyResult<&Path> {
+        match self.closed {
+            true => Err(IondriveError::new_err(format!(
+                "the reader of {} is closed",
+                self.path.display()
+            ))),
+            false => Ok(&self.dir),
+        }
+    }
+
+    /// The layer `layer_name`, or the default layer if no name is given. Raises
+    /// `KeyError` if there is no such layer.
+    fn layer_index(&self, layer_name: Option<&str>) -> PyResult<&LayerIndex> {
+        self.dir()?;
+        let found = match layer_name {
+            Some(name) => self.layers.iter().find(|l| l.name == name),
+            None => self
+                .layers
+                .iter()
+                .find(|l| l.dir == Path::new(reader::DEFAULT_GLYPHS_DIRNAME)),
+        };
+        found.ok_or_else(|| PyKeyError::new_err(layer_name.unwrap_or("public.default").to_string()))
+    }
+
+    /// Parses the font-level files in `request`, without any layers.
+    fn read_font(&self, py: Python, request: norad::DataRequest) -> PyResult<norad::Font> {
+        Ok(load_font(py, self.dir()?, request, &LoadOptions::default())?.ufo)
+    }
+}
+
+#[pymethods]
+impl UfoReader {
+    #[new]
+    fn new(py: Python, loader: &PyAny, path: PathBuf) -> PyResult<Self> {
+        let extracted = match ufoz::is_ufoz(&path) {
+            true => Some(ufoz::extract(py, &path)?),
+            false => None,
+        };
+        let ufo = extracted.as_ref().map_or(path.as_path(), |e| e.path());
+        let layer_contents = reader::layer_contents(ufo)?;
+        let format_version = reader::read_metainfo(ufo)?.format_version;
+        let layers = layer_contents
+            .into_iter()
+            .map(|(name, dir)| {
+                let contents = reader::glyph_contents(&ufo.join(&dir))?;
+                Ok(LayerIndex {
+                    name,
+                    dir,
+                    contents,
+                })
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(UfoReader {
+            dir: ufo.to_path_buf(),
+            path,
+            extracted,
+            closed: false,
+            loader: loader.into(),
+            format_version,
+            layers,
+        })
+    }
+
+    /// Close the reader, removing the directory a `.ufoz` archive was unpacked to.
+    /// Closing a closed reader does nothing.
+    #[pyo3(text_signature = "($self)")]
+    fn close(&mut self) {
+        self.closed = true;
+        self.extracted = None;
+        self.layers.clear();
+    }
+
+    fn __enter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        slf.dir()?;
+        Ok(slf)
+    }
+
+    fn __exit__(&mut self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) -> bool {
+        self.close();
+        false
+    }
+
+    /// Whether the reader has been closed.
+    #[getter]
+    fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// The names of the layers, in the order of `layercontents.plist`.
+    #[pyo3(text_signature = "($self)")]
+    fn layer_names(&self) -> PyResult<Vec<String>> {
+        self.dir()?;
+        Ok(self.layers.iter().map(|l| l.name.clone()).collect())
+    }
+
+    /// The names of the glyphs in the layer `layer_name`, or in the default layer
+    /// if no name is given, sorted. Raises `KeyError` if the layer does not exist.
+    #[args(layer_name = "None")]
+    #[pyo3(text_signature = "($self, layer_name=None)")]
+    fn glyph_names(&self, layer_name: Option<String>) -> PyResult<Vec<String>> {
+        Ok(self
+            .layer_index(layer_name.as_deref())?
+            .contents
+            .keys()
+            .cloned()
+            .collect())
+    }
+
+    /// Parse and return the glyph `glyph_name` from the layer `layer_name`, or from
+    /// the default layer if no name is given. Raises `KeyError` if the layer or
+    /// glyph does not exist.
+    #[args(layer_name = "None")]
+    #[pyo3(text_signature = "($self, glyph_name, layer_name=None)")]
+    fn glyph(
+        &self,
+        py: Python,
+        glyph_name: &str,
+        layer_name: Option<String>,
+    ) -> PyResult<PyObject> {
+        let layer = self.layer_index(layer_name.as_deref())?;
+        let file_name = layer
+            .contents
+            .get(glyph_name)
+            .ok_or_else(|| PyKeyError::new_err(glyph_name.to_string()))?;
+        let dir = self.dir()?.join(&layer.dir);
+        let glyph = reader::read_glyph_file(&dir, file_name, glyph_name)?;
+        Ok(Arc::new(glyph).to_wrapped_object(self.loader.as_ref(py), py))
+    }
+
+    /// Parse and return the layer `layer_name`, or the default layer if no name is
+    /// given. Raises `KeyError` if the layer does not exist.
+    #[args(layer_name = "None")]
+    #[pyo3(text_signature = "($self, layer_name=None)")]
+    fn layer(&self, py: Python, layer_name: Option<String>) -> PyResult<PyObject> {
+        let index = self.layer_index(layer_name.as_deref())?;
+        let layer = reader::load_layer(&self.dir()?.join(&index.dir), &index.name)?;
+        Ok(layer.to_wrapped_object(self.loader.as_ref(py), py))
+    }
+
+    /// Parse and return the font info. A UFO without a `fontinfo.plist` gives an
+    /// empty `Info` object.
+    #[pyo3(text_signature = "($self)")]
+    fn info(&self, py: Python) -> PyResult<PyObject> {
+        let ufo = self.read_font(py, norad::DataRequest::none())?;
+        Ok(ufo
+            .font_info
+            .unwrap_or_default()
+            .to_wrapped_object(self.loader.as_ref(py), py))
+    }
+
+    /// Parse and return the kerning. A UFO without a `kerning.plist` gives empty
+    /// kerning.
+    #[pyo3(text_signature = "($self)")]
+    fn kerning(&self, py: Python) -> PyResult<PyObject> {
+        let mut request = norad::DataRequest::none();
+        request.groups(true).kerning(true);
+        let ufo = self.read_font(py, request)?;
+        wrap_kerning(ufo.kerning.as_ref(), self.loader.as_ref(py), py)
+    }
+
+    /// Parse and return the groups. A UFO without a `groups.plist` gives an empty
+    /// dictionary.
+    #[pyo3(text_signature = "($self)")]
+    fn groups(&self, py: Python) -> PyResult<PyObject> {
+        // The kerning is read too, as the groups of UFOs in format version 1 or 2
+        // are renamed along with it.
+        let mut request = norad::DataRequest::none();
+        request.groups(true).kerning(true);
+        let ufo = self.read_font(py, request)?;
+        Ok(wrap_groups(ufo.groups.as_ref(), py))
+    }
+}
@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf};
+
+use pyo3::prelude::*;
+
+use crate::IondriveError;
+
+/// A `.ufoz` archive extracted into a temporary directory.
+///
+/// norad only reads unpacked UFOs, so archives are unpacked with Python's
+/// `zipfile` first. The temporary directory is removed when this is dropped.
+pub(crate) struct ExtractedUfoz {
+    _temp_dir: TempDir,
+    ufo: PathBuf,
+}
+
+impl ExtractedUfoz {
+    /// The path of the unpacked UFO directory.
+    pub(crate) fn path(&self) -> &Path {
+        &self.ufo
+    }
+}
+
+/// A fresh temporary directory, removed again when this is dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(py: Python) -> PyResult<Self> {
+        Ok(TempDir(
+            py.import("tempfile")?.call_method0("mkdtemp")?.extract()?,
+        ))
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Whether `path` should be read as a `.ufoz` archive rather than a directory.
+pub(crate) fn is_ufoz(path: &Path) -> bool {
+    path.is_file() || has_ufoz_extension(path)
+}
+
+/// Whether `path` ends in `.ufoz`, in any case.
+pub(crate) fn has_ufoz_extension(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("ufoz"))
+}
+
+/// Extracts the archive at `path` into a fresh temporary directory.
+pub(crate) fn extract(py: Python, path: &Path) -> PyResult<ExtractedUfoz> {
+    unpack(py, path.to_object(py), &path.display().to_string())
+}
+
+/// Extracts an archive held in memory, called `description` in errors, into a
+/// fresh temporary directory.
+pub(crate) fn extract_bytes(py: Python, data: &[u8], description: &str) -> PyResult<ExtractedUfoz> {
+    let buffer = py
+        .import("io")?
+        .call_method1("BytesIO", (pyo3::types::PyBytes::new(py, data),))?;
+    unpack(py, buffer.into(), description)
+}
+
+/// Unpacks `source`, a path or file object understood by `zipfile.ZipFile`.
+/// `description` names the source in error messages.
+fn unpack(py: Python, source: PyObject, description: &str) -> PyResult<ExtractedUfoz> {
+    // Construct the guard first so the directory is cleaned up on any error below.
+    let temp_dir = TempDir::new(py)?;
+    let mut extracted = ExtractedUfoz {
+        ufo: temp_dir.0.clone(),
+        _temp_dir: temp_dir,
+    };
+    let temp_dir = extracted.ufo.clone();
+
+    let zipfile = py.import("zipfile")?;
+    let archive = zipfile
+        .call_method1("ZipFile", (source,))
+        .map_err(|e| invalid_archive(py, description, e))?;
+    archive
+        .call_method1("extractall", (&temp_dir,))
+        .map_err(|e| invalid_archive(py, description, e))?;
+    archive.call_method0("close")?;
+
+    // The UFO spec asks for a single top-level directory holding the UFO, but
+    // accept archives with the UFO files at the root as well.
+    let entries = std::fs::read_dir(&temp_dir)?.collect::<Result<Vec<_>, _>>()?;
+    if let [entry] = &entries[..] {
+        if entry.path().is_dir() {
+            extracted.ufo = entry.path();
+        }
+    }
+    if !extracted.ufo.join("metainfo.plist").exists() {
+        return Err(IondriveError::new_err(format!(
+            "{} does not contain a UFO: missing metainfo.plist",
+            description
+        )));
+    }
+    Ok(extracted)
+}
+
+/// Reads the file `name` of the UFO in the archive at `path` without extracting
+/// the rest, or `None` if the UFO has no such file.
+///
+/// As when extracting, the UFO is either the archive's single top-level directory
+/// or the archive itself.
+pub(crate) fn read_file(py: Python, path: &Path, name: &str) -> PyResult<Option<Vec<u8>>> {
+    let description = path.display().to_string();
+    let archive = py
+        .import("zipfile")?
+        .call_method1("ZipFile", (path,))
+        .map_err(|e| invalid_archive(py, &description, e))?;
+    let names: Vec<String> = archive.call_method0("namelist")?.extract()?;
+    let mut tops = names
+        .iter()
+        .map(|n| n.split('/').next().unwrap_or_default());
+    let first = tops.next().unwrap_or_default();
+    let in_dir =
+        tops.all(|top| top == first) && names.iter().any(|n| n.starts_with(&format!("{}/", first)));
+    let member = match in_dir {
+        true => format!("{}/{}", first, name),
+        false => name.to_string(),
+    };
+    let contents = match names.contains(&member) {
+        true => Some(archive.call_method1("read", (member,))?.extract()?),
+        false => None,
+    };
+    archive.call_method0("close")?;
+    Ok(contents)
+}
+
+/// Writes the `.ufoz` archive for `path` to the file `to`, holding the UFO that
+/// `save` writes into the directory it is given, which is temporary.
+///
+/// As with the archives fontTools writes, the UFO is in a single top-level
+/// directory named after `path`, with a `.ufo` extension; fontTools' `ufoLib`
+/// refuses archives without exactly one such directory. Files are added in
+/// sorted order, so the same UFO always gives the same entries.
+pub(crate) fn write(
+    py: Python,
+    path: &Path,
+    to: &Path,
+    save: impl FnOnce(&Path) -> PyResult<()>,
+) -> PyResult<()> {
+    let temp_dir = TempDir::new(py)?;
+    let root = Path::new(path.file_stem().unwrap_or_default()).with_extension("ufo");
+    let ufo = temp_dir.0.join(&root);
+    save(&ufo)?;
+
+    let zipfile = py.import("zipfile")?;
+    let archive = zipfile.call_method1("ZipFile", (to, "w", zipfile.getattr("ZIP_DEFLATED")?))?;
+    for file in files(&ufo)? {
+        // Zip entry names separate directories with slashes on every platform.
+        let name: Vec<String> = root
+            .join(&file)
+            .iter()
+            .map(|c| c.to_string_lossy().into_owned())
+            .collect();
+        archive.call_method1("write", (ufo.join(&file), name.join("/")))?;
+    }
+    archive.call_method0("close")?;
+    Ok(())
+}
+
+/// The paths of the files in `dir` and its subdirectories, relative to it and
+/// sorted.
+fn files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        for entry in std::fs::read_dir(dir.join(&relative))? {
+            let entry = entry?;
+            let path = relative.join(entry.file_name());
+            match entry.file_type()?.is_dir() {
+                true => pending.push(path),
+                false => files.push(path),
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn invalid_archive(py: Python, description: &str, error: PyErr) -> PyErr {
+    IondriveError::new_err(format!(
+        "{} is not a valid .ufoz archive: {}",
+        description,
+        error.pvalue(py)
+    ))
+}
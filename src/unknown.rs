@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use pyo3::prelude::*;
+
+use crate::info::INFO_ATTRIBUTES;
+use crate::reader;
+use crate::IondriveError;
+
+/// The files and directories at the top of a UFO that are loaded, besides the
+/// layer directories.
+static UFO_ENTRIES: &[&str] = &[
+    "metainfo.plist",
+    "fontinfo.plist",
+    "groups.plist",
+    "kerning.plist",
+    "features.fea",
+    "lib.plist",
+    "layercontents.plist",
+    "data",
+    "images",
+];
+
+static METAINFO_KEYS: &[&str] = &["creator", "formatVersion", "formatVersionMinor"];
+
+static LAYER_INFO_KEYS: &[&str] = &["color", "lib"];
+
+/// What to do about data in a UFO that is not loaded.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub(crate) enum OnUnknown {
+    #[default]
+    Ignore,
+    Warn,
+    Error,
+}
+
+impl OnUnknown {
+    pub(crate) fn from_name(name: &str) -> PyResult<Self> {
+        match name {
+            "ignore" => Ok(OnUnknown::Ignore),
+            "warn" => Ok(OnUnknown::Warn),
+            "error" => Ok(OnUnknown::Error),
+            _ => Err(IondriveError::new_err(format!(
+                "unknown on_unknown '{}', expected 'ignore', 'warn' or 'error'",
+                name
+            ))),
+        }
+    }
+
+    /// Looks for data in the UFO directory `ufo` that loading it leaves out, and
+    /// warns about or raises for it as asked. The UFO is called `description`.
+    pub(crate) fn check(self, py: Python, ufo: &Path, description: &str) -> PyResult<()> {
+        if self == OnUnknown::Ignore {
+            return Ok(());
+        }
+        let unknown = find_unknown(ufo)?;
+        match self {
+            OnUnknown::Error if !unknown.is_empty() => Err(IondriveError::new_err(format!(
+                "{}: not loaded: {}",
+                description,
+                unknown.join("; ")
+            ))),
+            OnUnknown::Warn => {
+                let category = py.import("builtins")?.getattr("UserWarning")?;
+                for item in unknown {
+                    let message = format!("{}: not loaded: {}", description, item);
+                    PyErr::warn(py, category, &message, 1)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Describes the files, plist keys and glyph files of the UFO directory `ufo`
+/// that are not loaded, in the order they are found. Hidden files, such as
+/// `.DS_Store`, are not counted.
+pub(crate) fn find_unknown(ufo: &Path) -> PyResult<Vec<String>> {
+    let mut unknown = Vec::new();
+    let layers = reader::layer_contents(ufo)?;
+    let layer_dirs: HashSet<&Path> = layers.iter().map(|(_, dir)| dir.as_path()).collect();
+    for name in visible_entries(ufo)? {
+        if !UFO_ENTRIES.contains(&name.as_str()) && !layer_dirs.contains(Path::new(&name)) {
+            unknown.push(format!("file '{}'", name));
+        }
+    }
+    unknown_keys(ufo, "metainfo.plist", METAINFO_KEYS, &mut unknown);
+    let mut info_keys = INFO_ATTRIBUTES.to_vec();
+    info_keys.push("guidelines");
+    unknown_keys(ufo, "fontinfo.plist", &info_keys, &mut unknown);
+    for (_, dir) in &layers {
+        let listed: HashSet<String> = reader::glyph_contents(&ufo.join(dir))?
+            .into_values()
+            .map(|file| file.to_string_lossy().into_owned())
+            .collect();
+        for name in visible_entries(&ufo.join(dir))? {
+            if name != "contents.plist" && name != "layerinfo.plist" && !listed.contains(&name) {
+                unknown.push(format!("file '{}/{}'", dir.display(), name));
+            }
+        }
+        let layer_info = format!("{}/layerinfo.plist", dir.display());
+        unknown_keys(ufo, &layer_info, LAYER_INFO_KEYS, &mut unknown);
+    }
+    Ok(unknown)
+}
+
+/// The names of the entries of `dir` that do not start with a dot, sorted.
+fn visible_entries(dir: &Path) -> PyResult<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if !name.starts_with('.') {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Adds the keys of the plist dictionary at `file` in the UFO directory `ufo`
+/// that are not in `known`, if the file exists. A malformed file is left for
+/// loading to report.
+fn unknown_keys(ufo: &Path, file: &str, known: &[&str], unknown: &mut Vec<String>) {
+    if let Ok(plist::Value::Dictionary(dict)) = plist::Value::from_file(ufo.join(file)) {
+        for key in dict.keys() {
+            if !known.contains(&key.as_str()) {
+                unknown.push(format!("{} key '{}'", file, key));
+            }
+        }
+    }
+}
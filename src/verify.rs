@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::error::{Category, LoadError};
+use crate::reader::{self, Selection, CONTENTS_FILE, DEFAULT_GLYPHS_DIRNAME, LAYER_CONTENTS_FILE};
+
+/// The optional font-level plists, each holding a dictionary.
+static FONT_PLISTS: &[&str] = &[
+    "fontinfo.plist",
+    "groups.plist",
+    "kerning.plist",
+    "lib.plist",
+];
+static LAYER_INFO_FILE: &str = "layerinfo.plist";
+static IMAGES_DIRNAME: &str = "images";
+
+/// Checks the structure of the UFO at `ufo` without loading it, collecting every
+/// problem found, with the files they are in relative to the UFO.
+///
+/// The required files must be present and every plist must parse, the layers of
+/// `layercontents.plist` must have their directories, the default layer among
+/// them, and the GLIF files each `contents.plist` lists must exist and parse,
+/// and have their images in the `images` directory.
+pub(crate) fn verify(ufo: &Path) -> Vec<LoadError> {
+    let mut problems = Vec::new();
+    let layers = match reader::read_layer_contents(ufo) {
+        Ok(layers) => Some(layers),
+        Err(error) if error.kind == Some("MissingUfoDir") => return vec![error],
+        Err(error) => {
+            problems.push(error.in_file(Path::new(LAYER_CONTENTS_FILE)));
+            None
+        }
+    };
+    if let Err(error) = reader::read_metainfo(ufo) {
+        problems.push(error.in_file(Path::new(reader::METAINFO_FILE)));
+    }
+    for file in FONT_PLISTS {
+        problems.extend(check_dictionary(ufo, Path::new(file)));
+    }
+    let layers = match layers {
+        Some(layers) => layers,
+        None => return problems,
+    };
+    if !layers
+        .iter()
+        .any(|(_, dir)| dir == Path::new(DEFAULT_GLYPHS_DIRNAME))
+    {
+        problems.push(
+            LoadError::from(norad::Error::MissingDefaultLayer)
+                .in_file(Path::new(LAYER_CONTENTS_FILE)),
+        );
+    }
+    let mut names = HashSet::new();
+    let mut images = Vec::new();
+    for (name, dir) in &layers {
+        if !names.insert(name) {
+            problems.push(
+                LoadError::from(norad::Error::DuplicateLayer(name.clone()))
+                    .in_file(Path::new(LAYER_CONTENTS_FILE)),
+            );
+            continue;
+        }
+        if !ufo.join(dir).is_dir() {
+            problems.push(
+                LoadError::new(
+                    Category::Io,
+                    format!("layer '{}' has no directory {}", name, dir.display()),
+                )
+                .with_kind("MissingFile")
+                .in_file(Path::new(LAYER_CONTENTS_FILE)),
+            );
+            continue;
+        }
+        problems.extend(check_dictionary(ufo, &dir.join(LAYER_INFO_FILE)));
+        problems.extend(check_glyphs(ufo, dir, &mut images));
+    }
+    match reader::duplicate_glyphs(ufo, &Selection::default()) {
+        Ok(duplicates) => problems.extend(duplicates.iter().map(|d| d.error())),
+        Err(error) => problems.push(error),
+    }
+    let images_dir = ufo.join(IMAGES_DIRNAME);
+    for (glif, file_name) in images {
+        if !images_dir.join(&file_name).is_file() {
+            problems.push(
+                LoadError::new(
+                    Category::Io,
+                    format!("image {} is not in the images directory", file_name),
+                )
+                .with_kind("MissingFile")
+                .in_file(&glif),
+            );
+        }
+    }
+    problems
+}
+
+/// Checks that the plist `file` of the UFO at `ufo`, if it exists, holds a
+/// dictionary.
+fn check_dictionary(ufo: &Path, file: &Path) -> Option<LoadError> {
+    let path = ufo.join(file);
+    if !path.exists() {
+        return None;
+    }
+    match plist::Value::from_file(&path) {
+        Ok(plist::Value::Dictionary(_)) => None,
+        Ok(_) => Some(
+            LoadError::from(norad::Error::ExpectedPlistDictionary(
+                file.display().to_string(),
+            ))
+            .in_file(file),
+        ),
+        Err(error) => Some(LoadError::from(error).in_file(file)),
+    }
+}
+
+/// Checks that the GLIF files the `contents.plist` of the layer directory `dir`
+/// of the UFO at `ufo` lists exist and parse, parsing them in parallel. The
+/// images the glyphs use are added to `images`, after the GLIF files using them.
+fn check_glyphs(ufo: &Path, dir: &Path, images: &mut Vec<(PathBuf, String)>) -> Vec<LoadError> {
+    let contents = match reader::read_glyph_contents(&ufo.join(dir)) {
+        Ok(contents) => contents,
+        Err(error) => return vec![error.in_file(&dir.join(CONTENTS_FILE))],
+    };
+    let checked: Vec<Result<Option<(PathBuf, String)>, LoadError>> = contents
+        .into_par_iter()
+        .map(|(name, file_name)| {
+            let glif = dir.join(&file_name);
+            if !ufo.join(&glif).is_file() {
+                return Err(LoadError::new(
+                    Category::Io,
+                    format!("glyph '{}' has no GLIF file {}", name, glif.display()),
+                )
+                .with_kind("MissingFile")
+                .in_file(&dir.join(CONTENTS_FILE)));
+            }
+            let glyph = norad::Glyph::load(ufo.join(&glif))
+                .map_err(|e| LoadError::from(e).in_file(&glif).relative_to(ufo))?;
+            Ok(glyph
+                .image
+                .map(|image| (glif, image.file_name.display().to_string())))
+        })
+        .collect();
+    let mut problems = Vec::new();
+    for result in checked {
+        match result {
+            Ok(image) => images.extend(image),
+            Err(error) => problems.push(error),
+        }
+    }
+    problems
+}
@@ -0,0 +1,175 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::error::{Category, LoadError};
+use crate::reader::{self, CONTENTS_FILE, DEFAULT_GLYPHS_DIRNAME, LAYER_CONTENTS_FILE};
+
+/// Held while a `contents.plist` or `layercontents.plist` is read and written
+/// back, so that updates made at the same time do not undo each other.
+static PLIST_UPDATE: Mutex<()> = Mutex::new(());
+
+/// Numbers the temporary paths made by this process, so that threads writing
+/// the same path at once do not share one.
+static NEXT_SIBLING: AtomicUsize = AtomicUsize::new(0);
+
+/// Writes `glyph` to the layer `layer_name` of the UFO directory `ufo`, or to
+/// the default layer if no name is given, creating the layer if needed.
+///
+/// A glyph already listed in the layer's `contents.plist` is written over its
+/// file. A new glyph gets a file name made from its name, and is added to the
+/// `contents.plist`. Each file is written to a temporary file first and then
+/// renamed into place, so readers never see a partly written one.
+pub(crate) fn write_glyph(
+    ufo: &Path,
+    glyph: &norad::Glyph,
+    layer_name: Option<&str>,
+) -> Result<(), LoadError> {
+    let xml = glyph
+        .encode_xml()
+        .map_err(|e| LoadError::new(Category::Structure, e.to_string()).with_kind("GlifWrite"))?;
+    let _update = PLIST_UPDATE.lock().unwrap_or_else(|e| e.into_inner());
+    let dir = ufo.join(find_or_add_layer(ufo, layer_name)?);
+    let mut contents = reader::read_glyph_contents(&dir)?;
+    let file_name = match contents.get(&*glyph.name) {
+        Some(file_name) => file_name.clone(),
+        None => new_file_name(&contents, &glyph.name),
+    };
+    write_atomically(&dir.join(&file_name), &xml)?;
+    if !contents.contains_key(&*glyph.name) {
+        contents.insert(glyph.name.to_string(), file_name);
+        write_plist(&dir.join(CONTENTS_FILE), &contents)?;
+    }
+    Ok(())
+}
+
+/// The directory of the layer `layer_name` of the UFO directory `ufo`, or of
+/// the default layer if no name is given, relative to the UFO.
+///
+/// A layer that does not exist is created, with an empty `contents.plist` in a
+/// new directory named after it, and added to the end of `layercontents.plist`.
+fn find_or_add_layer(ufo: &Path, layer_name: Option<&str>) -> Result<PathBuf, LoadError> {
+    let mut layers = reader::read_layer_contents(ufo)?;
+    let found = match layer_name {
+        Some(name) => layers.iter().find(|(n, _)| n == name),
+        None => layers
+            .iter()
+            .find(|(_, dir)| dir == Path::new(DEFAULT_GLYPHS_DIRNAME)),
+    };
+    if let Some((_, dir)) = found {
+        return Ok(dir.clone());
+    }
+    let (name, dir) = match layer_name {
+        Some(name) => (
+            name.to_string(),
+            new_layer_dir(ufo, &norad::util::default_file_name_for_layer_name(name)),
+        ),
+        None => (
+            reader::DEFAULT_LAYER_NAME.to_string(),
+            PathBuf::from(DEFAULT_GLYPHS_DIRNAME),
+        ),
+    };
+    std::fs::create_dir_all(ufo.join(&dir))?;
+    write_plist(
+        &ufo.join(&dir).join(CONTENTS_FILE),
+        &BTreeMap::<String, PathBuf>::new(),
+    )?;
+    layers.push((name, dir.clone()));
+    write_plist(&ufo.join(LAYER_CONTENTS_FILE), &layers)?;
+    Ok(dir)
+}
+
+/// `dir_name`, or `dir_name` followed by a number if the UFO directory `ufo`
+/// already has an entry of that name.
+fn new_layer_dir(ufo: &Path, dir_name: &str) -> PathBuf {
+    std::iter::once(dir_name.to_string())
+        .chain((1..).map(|n| format!("{}{}", dir_name, n)))
+        .map(PathBuf::from)
+        .find(|dir| !ufo.join(dir).exists())
+        .unwrap()
+}
+
+/// A file name for the new glyph `name` that no glyph in `contents` has, even
+/// ignoring case, as file systems may.
+fn new_file_name(contents: &BTreeMap<String, PathBuf>, name: &str) -> PathBuf {
+    let taken: HashSet<String> = contents
+        .values()
+        .map(|file| file.to_string_lossy().to_lowercase())
+        .collect();
+    let file_name = norad::util::default_file_name_for_glyph_name(name);
+    let stem = file_name.trim_end_matches(".glif");
+    std::iter::once(file_name.clone())
+        .chain((1..).map(|n| format!("{}{}.glif", stem, n)))
+        .find(|candidate| !taken.contains(&candidate.to_lowercase()))
+        .unwrap()
+        .into()
+}
+
+fn write_plist(path: &Path, value: &impl serde::Serialize) -> Result<(), LoadError> {
+    let mut xml = Vec::new();
+    plist::to_writer_xml(&mut xml, value)?;
+    xml.push(b'\n');
+    write_atomically(path, &xml)
+}
+
+/// Writes `contents` to a hidden temporary file next to `path`, then renames it
+/// to `path`.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), LoadError> {
+    let temp = sibling(path, "tmp");
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path).map_err(|e| {
+        let _ = std::fs::remove_file(&temp);
+        e.into()
+    })
+}
+
+/// Has `write` write a UFO directory or archive to a hidden temporary path next
+/// to `path`, then renames it to `path`.
+///
+/// An existing UFO at `path` is renamed out of the way first, and removed only
+/// once the new one is in place. If anything fails, the temporary copy is
+/// removed and the existing UFO is put back.
+pub(crate) fn replace_atomically<E: From<std::io::Error>>(
+    path: &Path,
+    write: impl FnOnce(&Path) -> Result<(), E>,
+) -> Result<(), E> {
+    let temp = sibling(path, "tmp");
+    let old = sibling(path, "old");
+    let result = write(&temp).and_then(|_| {
+        let existed = path.symlink_metadata().is_ok();
+        if existed {
+            std::fs::rename(path, &old)?;
+        }
+        std::fs::rename(&temp, path).map_err(|e| {
+            if existed {
+                let _ = std::fs::rename(&old, path);
+            }
+            E::from(e)
+        })
+    });
+    remove(&temp);
+    remove(&old);
+    result
+}
+
+/// A new hidden path next to `path` for a temporary copy of it, told apart by
+/// `suffix`.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(
+        ".{}.{}-{}.{}",
+        file_name,
+        std::process::id(),
+        NEXT_SIBLING.fetch_add(1, Ordering::Relaxed),
+        suffix
+    ))
+}
+
+/// Removes the file or directory at `path`, if there is one.
+fn remove(path: &Path) {
+    let _ = match path.is_dir() {
+        true => std::fs::remove_dir_all(path),
+        false => std::fs::remove_file(path),
+    };
+}
@@ -0,0 +1,32 @@
+import shutil
+from pathlib import Path
+
+import pytest
+
+from paths import MUTATOR
+
+
+@pytest.fixture
+def ufo(tmp_path: Path) -> Path:
+    """A copy of MutatorSans that the test may change."""
+    ufo = tmp_path / MUTATOR.name
+    shutil.copytree(MUTATOR, ufo)
+    return ufo
+
+
+@pytest.fixture
+def ufo_with_data(ufo: Path) -> Path:
+    """The `ufo` with two files in its data directory."""
+    (ufo / "data" / "com.example").mkdir(parents=True)
+    (ufo / "data" / "com.example" / "foo.bin").write_bytes(b"\x00\x01\x02binary")
+    (ufo / "data" / "manifest.txt").write_bytes(b"build 42\n")
+    return ufo
+
+
+@pytest.fixture
+def ufo_with_stores(ufo_with_data: Path) -> Path:
+    """The `ufo_with_data` with two images too."""
+    (ufo_with_data / "images").mkdir()
+    (ufo_with_data / "images" / "sketch.png").write_bytes(b"\x89PNG\r\n\x1a\nsketch")
+    (ufo_with_data / "images" / "scan.png").write_bytes(b"\x89PNG\r\n\x1a\nscan")
+    return ufo_with_data
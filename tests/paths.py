@@ -0,0 +1,3 @@
+from pathlib import Path
+
+MUTATOR = Path("tests/data/MutatorSansBoldCondensed.ufo")
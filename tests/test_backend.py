@@ -0,0 +1,110 @@
+import types
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_ufolib2_backend() -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR, backend="ufoLib2")
+
+    assert font.layers == iondrive.load(ufoLib2.objects, MUTATOR).layers
+
+
+def test_unknown_backend() -> None:
+    with pytest.raises(iondrive.IondriveError, match="unknown backend 'fontParts'"):
+        iondrive.load(ufoLib2.objects, MUTATOR, backend="fontParts")
+
+
+def test_detects_ufolib2() -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+
+    assert isinstance(font, ufoLib2.objects.Font)
+
+
+def test_detects_defcon() -> None:
+    defcon = pytest.importorskip("defcon")
+
+    font = iondrive.load(defcon, MUTATOR)
+
+    assert isinstance(font, defcon.Font)
+    assert set(font.keys()) == set(defcon.Font(MUTATOR).keys())
+
+
+def test_load_many_detects_defcon() -> None:
+    defcon = pytest.importorskip("defcon")
+
+    (font,) = iondrive.load_many(defcon, [MUTATOR])
+
+    assert isinstance(font, defcon.Font)
+    assert set(font.keys()) == set(defcon.Font(MUTATOR).keys())
+
+
+def test_detects_defcon_laziness() -> None:
+    defcon = pytest.importorskip("defcon")
+
+    with pytest.raises(iondrive.IondriveError, match="lazily"):
+        iondrive.load(defcon, MUTATOR, lazy=True)
+
+
+def test_unrecognized_objects() -> None:
+    objects = types.SimpleNamespace(Font=ufoLib2.objects.Font)
+
+    with pytest.raises(iondrive.IondriveError, match="does not look like"):
+        iondrive.load(objects, MUTATOR)
+
+
+def outline(glyph) -> list:
+    contours = [
+        [(p.x, p.y, p.segmentType, p.smooth, p.name) for p in contour]
+        for contour in glyph
+    ]
+    components = [(c.baseGlyph, tuple(c.transformation)) for c in glyph.components]
+    return [contours, components]
+
+
+@pytest.mark.parametrize(
+    "path",
+    [MUTATOR, Path("tests/data/UbuTestData.ufo")],
+    ids=lambda p: p.name,
+)
+def test_defcon_backend(path: Path) -> None:
+    defcon = pytest.importorskip("defcon")
+    expected = defcon.Font(path)
+
+    font = iondrive.load(defcon, path, backend="defcon")
+
+    assert isinstance(font, defcon.Font)
+    assert font.layers.layerOrder == expected.layers.layerOrder
+    assert font.layers.defaultLayer.name == expected.layers.defaultLayer.name
+    assert dict(font.lib) == dict(expected.lib)
+    assert dict(font.groups) == dict(expected.groups)
+    assert dict(font.kerning) == dict(expected.kerning)
+    assert font.features.text == expected.features.text
+    assert font.info.familyName == expected.info.familyName
+    assert font.info.openTypeOS2WinAscent == expected.info.openTypeOS2WinAscent
+    for layer in expected.layers:
+        loaded = font.layers[layer.name]
+        assert loaded.color == layer.color
+        assert dict(loaded.lib) == dict(layer.lib)
+        assert set(loaded.keys()) == set(layer.keys())
+        for glyph in layer:
+            loaded_glyph = loaded[glyph.name]
+            assert loaded_glyph.width == glyph.width
+            assert loaded_glyph.unicodes == glyph.unicodes
+            assert dict(loaded_glyph.lib) == dict(glyph.lib)
+            assert [dict(a) for a in loaded_glyph.anchors] == [
+                dict(a) for a in glyph.anchors
+            ]
+            assert outline(loaded_glyph) == outline(glyph)
+
+
+def test_defcon_backend_is_not_lazy() -> None:
+    defcon = pytest.importorskip("defcon")
+
+    with pytest.raises(iondrive.IondriveError, match="lazily"):
+        iondrive.load(defcon, MUTATOR, backend="defcon", lazy=True)
@@ -0,0 +1,102 @@
+"""Wall-clock benchmarks, skipped unless IONDRIVE_BENCHMARK is set in the environment."""
+import os
+import pickle
+import time
+from concurrent.futures import ThreadPoolExecutor
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+pytestmark = pytest.mark.skipif(
+    not os.environ.get("IONDRIVE_BENCHMARK"), reason="IONDRIVE_BENCHMARK not set"
+)
+
+NOTO = Path("tests/data/NotoSans-Regular.ufo")
+
+
+def timed(func, *args, **kwargs) -> float:
+    start = time.perf_counter()
+    func(*args, **kwargs)
+    return time.perf_counter() - start
+
+
+def test_parallel_parsing() -> None:
+    serial = min(timed(iondrive.load, ufoLib2.objects, NOTO, jobs=1) for _ in range(3))
+    parallel = min(timed(iondrive.load, ufoLib2.objects, NOTO) for _ in range(3))
+    print(f"jobs=1: {serial:.3f}s, jobs=None: {parallel:.3f}s")
+    assert parallel < serial
+
+
+@pytest.fixture
+def large_lib_ufo(tmp_path: Path) -> Path:
+    """A copy of Noto Sans whose glyphs each have a lib of a few kilobytes, for
+    several megabytes in all."""
+    font = ufoLib2.Font.open(NOTO)
+    for glyph in font:
+        glyph.lib.update(
+            {
+                f"com.example.roundtrip.{i}": {"values": list(range(20)), "name": f"item{i}"}
+                for i in range(20)
+            }
+        )
+    ufo = tmp_path / "LargeLib.ufo"
+    font.save(ufo)
+    return ufo
+
+
+def test_skip_lib(large_lib_ufo: Path) -> None:
+    with_lib = min(timed(iondrive.load, ufoLib2.objects, large_lib_ufo) for _ in range(3))
+    without_lib = min(
+        timed(iondrive.load, ufoLib2.objects, large_lib_ufo, include_lib=False)
+        for _ in range(3)
+    )
+    print(f"include_lib=True: {with_lib:.3f}s, include_lib=False: {without_lib:.3f}s")
+    assert without_lib < with_lib
+
+
+
+
+def test_as_bytes_against_pickle() -> None:
+    """Sending a font with as_bytes=True spares the loading process building its
+    objects; the worker then builds them in from_bytes rather than unpickling."""
+    font_pickle = pickle.dumps(iondrive.load(ufoLib2.objects, NOTO))
+    bytes_pickle = pickle.dumps(iondrive.load(ufoLib2.objects, NOTO, as_bytes=True))
+
+    send_font = min(
+        timed(lambda: pickle.dumps(iondrive.load(ufoLib2.objects, NOTO))) for _ in range(3)
+    )
+    send_bytes = min(
+        timed(lambda: pickle.dumps(iondrive.load(ufoLib2.objects, NOTO, as_bytes=True)))
+        for _ in range(3)
+    )
+    receive_font = min(timed(pickle.loads, font_pickle) for _ in range(3))
+    receive_bytes = min(
+        timed(lambda: iondrive.from_bytes(ufoLib2.objects, pickle.loads(bytes_pickle)))
+        for _ in range(3)
+    )
+    print(
+        f"pickling the Font: {send_font:.3f}s to send, {receive_font:.3f}s to receive, "
+        f"{len(font_pickle)} bytes; as_bytes=True: {send_bytes:.3f}s to send, "
+        f"{receive_bytes:.3f}s to receive, {len(bytes_pickle)} bytes"
+    )
+    assert send_bytes < send_font
+
+
+def test_concurrent_loads() -> None:
+    # Without building objects, a load is almost all parsing, which runs without
+    # the GIL, so loads in threads overlap.
+    def load() -> None:
+        iondrive.load(ufoLib2.objects, NOTO, jobs=1, as_bytes=True)
+
+    def load_in_threads() -> None:
+        with ThreadPoolExecutor(max_workers=4) as pool:
+            for future in [pool.submit(load) for _ in range(4)]:
+                future.result()
+
+    serial = min(timed(lambda: [load() for _ in range(4)]) for _ in range(3))
+    threaded = min(timed(load_in_threads) for _ in range(3))
+    print(f"4 loads in series: {serial:.3f}s, in 4 threads: {threaded:.3f}s")
+    assert threaded < serial
@@ -0,0 +1,78 @@
+import os
+from pathlib import Path
+from typing import Iterator
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+@pytest.fixture
+def ufo(ufo: Path) -> Iterator[Path]:
+    yield ufo
+    iondrive.clear_cache()
+
+
+def change_width(glif: Path, keep_mtime: bool = False) -> None:
+    stat = glif.stat()
+    glif.write_text(glif.read_text().replace('<advance width="', '<advance width="1'))
+    if keep_mtime:
+        os.utime(glif, ns=(stat.st_atime_ns, stat.st_mtime_ns))
+    else:
+        os.utime(glif, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+
+
+def test_cache_hit(ufo: Path) -> None:
+    first = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    # Unchanged modification times make the change invisible to the cache.
+    change_width(ufo / "glyphs" / "A_.glif", keep_mtime=True)
+
+    cached = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    fresh = iondrive.load(ufoLib2.objects, ufo)
+
+    assert cached["A"].width == first["A"].width
+    assert fresh["A"].width != first["A"].width
+
+
+def test_cache_returns_fresh_objects(ufo: Path) -> None:
+    first = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    first["A"].width = 1
+    first["A"].contours.clear()
+    first.lib["changed"] = True
+
+    second = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+
+    assert second["A"] is not first["A"]
+    assert second["A"] == ufoLib2.Font.open(MUTATOR)["A"]
+    assert "changed" not in second.lib
+
+
+def test_cache_sees_changes(ufo: Path) -> None:
+    first = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    change_width(ufo / "glyphs" / "A_.glif")
+
+    second = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+
+    assert second["A"].width != first["A"].width
+
+
+def test_cache_keyed_by_options(ufo: Path) -> None:
+    iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    change_width(ufo / "glyphs" / "A_.glif", keep_mtime=True)
+
+    decomposed = iondrive.load(ufoLib2.objects, ufo, use_cache=True, decompose=True)
+
+    assert decomposed["A"].width == ufoLib2.Font.open(ufo)["A"].width
+
+
+def test_clear_cache(ufo: Path) -> None:
+    first = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+    change_width(ufo / "glyphs" / "A_.glif", keep_mtime=True)
+    iondrive.clear_cache()
+
+    second = iondrive.load(ufoLib2.objects, ufo, use_cache=True)
+
+    assert second["A"].width != first["A"].width
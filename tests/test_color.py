@@ -0,0 +1,56 @@
+import types
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+
+class Color(str):
+    pass
+
+
+def loader_with_color() -> types.ModuleType:
+    loader = types.ModuleType("objects_with_color")
+    loader.__dict__.update(vars(ufoLib2.objects))
+    loader.Color = Color
+    return loader
+
+
+def colorful_font(tmp_path: Path) -> Path:
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.color = "1,0,0,1"
+    glyph = font.newGlyph("a")
+    glyph.appendAnchor({"x": 10, "y": 20, "name": "top", "color": "0,1,0,1"})
+    glyph.appendGuideline({"x": 100, "color": "0,0,1,1"})
+    font.info.guidelines = [ufoLib2.objects.Guideline(y=500, color="0,0,0,0.5")]
+    font.save(tmp_path / "Colors.ufo")
+    return tmp_path / "Colors.ufo"
+
+
+def colors(font: ufoLib2.Font) -> list:
+    glyph = font["a"]
+    return [
+        font.layers.defaultLayer.color,
+        glyph.anchors[0].color,
+        glyph.guidelines[0].color,
+        font.info.guidelines[0].color,
+    ]
+
+
+def test_colors_without_color_class(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, colorful_font(tmp_path))
+
+    expected = getattr(ufoLib2.objects, "Color", str)
+    assert all(isinstance(color, expected) for color in colors(font))
+    assert colors(font) == ["1,0,0,1", "0,1,0,1", "0,0,1,1", "0,0,0,0.5"]
+
+
+def test_colors_with_color_class(tmp_path: Path) -> None:
+    font = iondrive.load(loader_with_color(), colorful_font(tmp_path))
+
+    assert all(type(color) is Color for color in colors(font))
+    assert colors(font) == ["1,0,0,1", "0,1,0,1", "0,0,1,1", "0,0,0,0.5"]
+
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo")
+    assert colors(ufoLib2.Font.open(tmp_path / "Saved.ufo")) == colors(font)
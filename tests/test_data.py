@@ -0,0 +1,123 @@
+import types
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_load_data(ufo_with_data: Path) -> None:
+    font = ufoLib2.Font.open(ufo_with_data)
+    loaded = iondrive.load(ufoLib2.objects, ufo_with_data)
+
+    assert isinstance(loaded.data, ufoLib2.objects.DataSet)
+    assert sorted(loaded.data.keys()) == sorted(font.data.keys())
+    assert loaded.data["com.example/foo.bin"] == b"\x00\x01\x02binary"
+    assert loaded.data["manifest.txt"] == b"build 42\n"
+
+
+def test_save_data(ufo_with_data: Path, tmp_path: Path) -> None:
+    loaded = iondrive.load(ufoLib2.objects, ufo_with_data)
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+
+    saved = tmp_path / "Saved.ufo" / "data"
+    assert (saved / "com.example" / "foo.bin").read_bytes() == b"\x00\x01\x02binary"
+    assert (saved / "manifest.txt").read_bytes() == b"build 42\n"
+
+
+def test_load_data_with_symlink_cycle(ufo_with_data: Path) -> None:
+    (ufo_with_data / "data" / "com.example" / "loop").symlink_to("..")
+
+    loaded = iondrive.load(ufoLib2.objects, ufo_with_data)
+
+    assert sorted(loaded.data.keys()) == ["com.example/foo.bin", "manifest.txt"]
+
+
+def test_unreadable_data_file(ufo_with_data: Path) -> None:
+    (ufo_with_data / "data" / "missing.bin").symlink_to("nowhere.bin")
+
+    with pytest.raises(iondrive.IondriveIOError, match="missing.bin") as excinfo:
+        iondrive.load(ufoLib2.objects, ufo_with_data, as_bytes=True)
+
+    assert excinfo.value.kind == "Io"
+
+
+def test_failing_lazy_read(ufo_with_data: Path) -> None:
+    class DataSet(ufoLib2.objects.DataSet):
+        @classmethod
+        def read(cls, reader, lazy=True):
+            raise ValueError("cannot read the data")
+
+    objects = types.SimpleNamespace(**vars(ufoLib2.objects))
+    objects.DataSet = DataSet
+
+    with pytest.raises(ValueError, match="cannot read the data"):
+        iondrive.load(objects, ufo_with_data)
+
+
+def test_load_without_data() -> None:
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR)
+
+    assert not loaded.data
+
+
+def test_load_images(ufo: Path, tmp_path: Path) -> None:
+    (ufo / "images").mkdir()
+    (ufo / "images" / "sketch.png").write_bytes(b"\x89PNG\r\n\x1a\nsketch")
+
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+    assert isinstance(loaded.images, ufoLib2.objects.ImageSet)
+    assert list(loaded.images.keys()) == ["sketch.png"]
+    assert loaded.images["sketch.png"] == b"\x89PNG\r\n\x1a\nsketch"
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    saved = tmp_path / "Saved.ufo" / "images" / "sketch.png"
+    assert saved.read_bytes() == b"\x89PNG\r\n\x1a\nsketch"
+
+
+def test_load_images_in_subdirectory(ufo: Path) -> None:
+    (ufo / "images" / "nested").mkdir(parents=True)
+    (ufo / "images" / "nested" / "sketch.png").write_bytes(b"\x89PNG\r\n\x1a\n")
+
+    with pytest.raises(iondrive.IondriveError, match="directly in the images"):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+STORES = {
+    "data": {
+        "com.example/foo.bin": b"\x00\x01\x02binary",
+        "manifest.txt": b"build 42\n",
+    },
+    "images": {
+        "scan.png": b"\x89PNG\r\n\x1a\nscan",
+        "sketch.png": b"\x89PNG\r\n\x1a\nsketch",
+    },
+}
+
+
+@pytest.mark.parametrize("how", ["load", "as_bytes", "ufoz"])
+def test_store_access(ufo_with_stores: Path, tmp_path: Path, how: str) -> None:
+    if how == "load":
+        font = iondrive.load(ufoLib2.objects, ufo_with_stores)
+    elif how == "as_bytes":
+        packed = iondrive.load(ufoLib2.objects, ufo_with_stores, as_bytes=True)
+        font = iondrive.from_bytes(ufoLib2.objects, packed)
+    else:
+        loaded = iondrive.load(ufoLib2.objects, ufo_with_stores)
+        iondrive.save(ufoLib2.objects, loaded, tmp_path / "Stores.ufoz")
+        font = iondrive.load(ufoLib2.objects, tmp_path / "Stores.ufoz")
+
+    assert isinstance(font.data, ufoLib2.objects.DataSet)
+    assert isinstance(font.images, ufoLib2.objects.ImageSet)
+    for store, files in [(font.data, STORES["data"]), (font.images, STORES["images"])]:
+        assert len(store) == len(files)
+        assert sorted(store) == sorted(files)
+        for name, contents in files.items():
+            assert name in store
+            assert store[name] == contents
+        assert "missing.bin" not in store
+        with pytest.raises(KeyError):
+            store["missing.bin"]
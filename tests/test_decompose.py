@@ -0,0 +1,105 @@
+from pathlib import Path
+
+import pytest
+import ufoLib2
+from ufoLib2.objects import Component, Contour, Glyph, Point
+
+import iondrive
+
+
+def square() -> Contour:
+    return Contour(
+        points=[
+            Point(0, 0, "line"),
+            Point(0, 10, "line"),
+            Point(10, 10, "line"),
+            Point(10, 0, "line"),
+        ]
+    )
+
+
+def save_composites(tmp_path: Path) -> Path:
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.insertGlyph(Glyph("base", contours=[square()]))
+    font.layers.defaultLayer.insertGlyph(
+        Glyph("mid", components=[Component("base", (1, 0, 0, 1, 10, 0))])
+    )
+    font.layers.defaultLayer.insertGlyph(
+        Glyph(
+            "top",
+            contours=[square()],
+            components=[Component("mid", (2, 0, 0, 2, 0, 5))],
+        )
+    )
+    font.save(tmp_path / "Composites.ufo")
+    return tmp_path / "Composites.ufo"
+
+
+def test_decompose_nested(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, save_composites(tmp_path), decompose=True)
+    top = font["top"]
+
+    assert not top.components
+    assert len(top.contours) == 2
+    assert [(p.x, p.y) for p in top.contours[1]] == [
+        (20, 5),
+        (20, 25),
+        (40, 25),
+        (40, 5),
+    ]
+    assert not font["mid"].components
+    assert [(p.x, p.y) for p in font["mid"].contours[0]] == [
+        (10, 0),
+        (10, 10),
+        (20, 10),
+        (20, 0),
+    ]
+
+
+def test_no_decompose_by_default(tmp_path: Path) -> None:
+    path = save_composites(tmp_path)
+    font = iondrive.load(ufoLib2.objects, path)
+
+    assert font["top"] == ufoLib2.Font.open(path)["top"]
+    assert len(font["top"].components) == 1
+
+
+def test_decompose_missing_base(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.insertGlyph(
+        Glyph("broken", components=[Component("nonexistent")])
+    )
+    font.save(tmp_path / "Broken.ufo")
+
+    with pytest.raises(iondrive.IondriveError, match="'broken'.*'nonexistent'"):
+        iondrive.load(ufoLib2.objects, tmp_path / "Broken.ufo", decompose=True)
+
+
+def test_check_components(tmp_path: Path) -> None:
+    path = save_composites(tmp_path)
+
+    font = iondrive.load(ufoLib2.objects, path, check_components=True)
+
+    assert font["top"] == ufoLib2.Font.open(path)["top"]
+
+
+def test_check_components_missing_base(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.insertGlyph(Glyph("base", contours=[square()]))
+    font.layers.defaultLayer.insertGlyph(
+        Glyph("broken", components=[Component("base"), Component("nonexistent")])
+    )
+    font.newLayer("sketches").insertGlyph(
+        Glyph("sketch", components=[Component("base")])
+    )
+    font.save(tmp_path / "Broken.ufo")
+
+    iondrive.load(ufoLib2.objects, tmp_path / "Broken.ufo")
+    with pytest.raises(iondrive.IondriveStructureError) as error:
+        iondrive.load(ufoLib2.objects, tmp_path / "Broken.ufo", check_components=True)
+
+    assert str(error.value) == (
+        "components with missing base glyphs: "
+        "'broken' uses 'nonexistent' in layer 'public.default', "
+        "'sketch' uses 'base' in layer 'sketches'"
+    )
@@ -0,0 +1,76 @@
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from test_equivalence import UFOS
+
+DESIGNSPACE = """<?xml version="1.0" encoding="UTF-8"?>
+<designspace format="4.1">
+  <axes>
+    <axis tag="wght" name="Weight" minimum="300" maximum="700" default="300"/>
+  </axes>
+  <sources>
+    <source filename="{light}" name="Light">
+      <location><dimension name="Weight" xvalue="300"/></location>
+    </source>
+    <source filename="{bold}" name="Bold">
+      <location><dimension name="Weight" xvalue="700"/></location>
+    </source>
+  </sources>
+  <instances>
+    <instance name="Regular" familyname="Test" stylename="Regular">
+      <location><dimension name="Weight" xvalue="400"/></location>
+    </instance>
+  </instances>
+</designspace>
+"""
+
+
+@pytest.fixture
+def designspace(tmp_path: Path) -> Path:
+    for ufo in UFOS[:2]:
+        shutil.copytree(ufo, tmp_path / "sources" / ufo.name)
+    path = tmp_path / "Test.designspace"
+    path.write_text(
+        DESIGNSPACE.format(
+            light=f"sources/{UFOS[0].name}", bold=f"sources/{UFOS[1].name}"
+        )
+    )
+    return path
+
+
+def test_load_designspace(designspace: Path) -> None:
+    document = iondrive.load_designspace(ufoLib2.objects, designspace)
+
+    assert [axis.name for axis in document.axes] == ["Weight"]
+    assert [instance.name for instance in document.instances] == ["Regular"]
+    assert [source.name for source in document.sources] == ["Light", "Bold"]
+    for source, ufo in zip(document.sources, UFOS):
+        assert source.font.keys() == ufoLib2.Font.open(ufo).keys()
+
+
+def test_shared_source_ufo(designspace: Path) -> None:
+    path = designspace.with_name("Shared.designspace")
+    path.write_text(
+        DESIGNSPACE.format(
+            light=f"sources/{UFOS[0].name}", bold=f"sources/{UFOS[0].name}"
+        )
+    )
+
+    document = iondrive.load_designspace(ufoLib2.objects, path)
+
+    assert document.sources[0].font is document.sources[1].font
+
+
+def test_missing_source(designspace: Path) -> None:
+    path = designspace.with_name("Missing.designspace")
+    path.write_text(
+        DESIGNSPACE.format(light=f"sources/{UFOS[0].name}", bold="Missing.ufo")
+    )
+
+    with pytest.raises(iondrive.IondriveError, match='<source name="Bold">.*Missing.ufo'):
+        iondrive.load_designspace(ufoLib2.objects, path)
@@ -0,0 +1,51 @@
+import json
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_diff_same_ufo() -> None:
+    assert iondrive.diff(MUTATOR, MUTATOR) == {
+        "layers": {"added": [], "removed": []},
+        "glyphs": {},
+        "info": {},
+        "kerning": {"added": [], "removed": [], "changed": []},
+    }
+
+
+def test_diff(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    font["A"].width += 1
+    del font["B"]
+    font.newGlyph("new")
+    font.layers.newLayer("sketches")
+    del font.layers["background"]
+    font.info.familyName = "Changed"
+    font.info.copyright = None
+    old_pair, old_value = next(iter(font.kerning.items()))
+    font.kerning[old_pair] = old_value + 10
+    font.kerning[("B", "new")] = -5
+    removed_pair = next(pair for pair in font.kerning if "B" in pair and pair[1] != "new")
+    removed_value = font.kerning.pop(removed_pair)
+    font.save(tmp_path / "Changed.ufo")
+
+    report = iondrive.diff(MUTATOR, tmp_path / "Changed.ufo")
+
+    assert report["layers"] == {"added": ["sketches"], "removed": ["background"]}
+    assert report["glyphs"] == {
+        "foreground": {"added": ["new"], "removed": ["B"], "changed": ["A"]}
+    }
+    assert report["info"] == {
+        "familyName": ["MutatorMathTest", "Changed"],
+        "copyright": [ufoLib2.Font.open(MUTATOR).info.copyright, None],
+    }
+    assert report["kerning"] == {
+        "added": [["B", "new", -5]],
+        "removed": [[*removed_pair, removed_value]],
+        "changed": [[*old_pair, old_value, old_value + 10]],
+    }
+    assert json.loads(json.dumps(report)) == report
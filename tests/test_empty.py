@@ -0,0 +1,60 @@
+import plistlib
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+EMPTY = Path("tests/data/Empty.ufo")
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {},
+        {"validate": False},
+        {"layers": []},
+        {"glyphs_as_dict": True},
+        {"as_bytes": True},
+    ],
+)
+def test_load_empty(options: dict) -> None:
+    font = ufoLib2.Font.open(EMPTY)
+    loaded = iondrive.load(ufoLib2.objects, EMPTY, **options)
+    if options.get("as_bytes"):
+        loaded = iondrive.from_bytes(ufoLib2.objects, loaded)
+
+    assert list(loaded.layers.keys()) == list(font.layers.keys()) == ["public.default"]
+    assert loaded.layers.defaultLayer.name == "public.default"
+    assert len(loaded) == len(font) == 0
+
+
+def test_empty_layer(tmp_path: Path) -> None:
+    ufo = tmp_path / "Empty.ufo"
+    shutil.copytree(EMPTY, ufo)
+    (ufo / "glyphs.background").mkdir()
+    shutil.copy(ufo / "glyphs" / "contents.plist", ufo / "glyphs.background")
+    with open(ufo / "layercontents.plist", "wb") as f:
+        plistlib.dump(
+            [["public.default", "glyphs"], ["public.background", "glyphs.background"]],
+            f,
+        )
+
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+
+    assert list(loaded.layers.keys()) == ["public.default", "public.background"]
+    assert len(loaded.layers["public.background"]) == 0
+
+
+@pytest.mark.parametrize("validate", [True, False])
+def test_no_layers(tmp_path: Path, validate: bool) -> None:
+    ufo = tmp_path / "Empty.ufo"
+    shutil.copytree(EMPTY, ufo)
+    with open(ufo / "layercontents.plist", "wb") as f:
+        plistlib.dump([], f)
+
+    with pytest.raises(iondrive.IondriveStructureError) as info:
+        iondrive.load(ufoLib2.objects, ufo, validate=validate)
+    assert info.value.kind == "MissingDefaultLayer"
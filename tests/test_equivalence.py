@@ -1,4 +1,5 @@
 import math
+import types
 from pathlib import Path
 
 import pytest
@@ -82,3 +83,102 @@ def test_equivalence(path: Path) -> None:
     # Not supported by norad yet:
     # assert font.data == id_font.data
     # assert font.images == id_font.images
+
+
+def test_objects_namespace(tmp_path: Path) -> None:
+    path = UFOS[0]
+    objects = types.SimpleNamespace(
+        **{
+            name: getattr(ufoLib2.objects, name)
+            for name in dir(ufoLib2.objects)
+            if not name.startswith("_")
+        }
+    )
+
+    font = iondrive.load(objects, path)
+    reference = iondrive.load(ufoLib2.objects, path)
+
+    assert font.info == reference.info
+    assert font.kerning == reference.kerning
+    for layer in reference.layers:
+        assert font.layers[layer.name] == layer
+    assert iondrive.load_glyph(objects, path, "A") == reference["A"]
+
+    iondrive.save(objects, font, tmp_path / "Saved.ufo")
+    assert iondrive.load(objects, tmp_path / "Saved.ufo").layers == font.layers
+
+
+# SourceSans has component scales such as 0.8000000119, more digits than the
+# 32-bit floats norad stores coordinates in can hold, so it only compares equal
+# approximately, as in test_equivalence.
+@pytest.mark.parametrize(
+    "path", [p for p in UFOS if "SourceSans" not in p.name], ids=lambda p: p.name
+)
+def test_font_equality(path: Path) -> None:
+    font = ufoLib2.Font.open(path, lazy=False)
+    id_font = iondrive.load(ufoLib2.objects, path)
+
+    assert id_font == font
+
+
+def test_empty_font_equality(tmp_path: Path) -> None:
+    ufoLib2.Font().save(tmp_path / "Empty.ufo")
+    font = ufoLib2.Font.open(tmp_path / "Empty.ufo", lazy=False)
+    id_font = iondrive.load(ufoLib2.objects, tmp_path / "Empty.ufo")
+
+    assert id_font.groups == font.groups == {}
+    assert id_font.kerning == font.kerning == {}
+    assert id_font.features == font.features
+    assert id_font.info == font.info
+    assert id_font == font
+
+
+def test_fractional_coordinates() -> None:
+    # Coordinates come out as Python reads them from the GLIF files, not as the
+    # nearest 32-bit float widened to 64 bits.
+    path = Path("tests/data/NotoSans-Regular.ufo")
+    glyphs = [glyph for glyph in ufoLib2.Font.open(path, lazy=False)]
+    id_font = iondrive.load(ufoLib2.objects, path)
+    for glyph in glyphs:
+        assert id_font[glyph.name].anchors == glyph.anchors
+        assert id_font[glyph.name].components == glyph.components
+        assert id_font[glyph.name].contours == glyph.contours
+
+
+# The ways of holding glyphs that the font's default layer can be built with.
+LOAD_MODES = [{}, {"lazy": True}, {"glyphs_as_dict": True}]
+
+
+@pytest.mark.parametrize("kwargs", LOAD_MODES, ids=lambda k: ",".join(k) or "default")
+def test_font_len(kwargs: dict) -> None:
+    font = ufoLib2.Font.open(UFOS[0])
+    id_font = iondrive.load(ufoLib2.objects, UFOS[0], **kwargs)
+
+    assert len(id_font) == len(font) == len(font.layers.defaultLayer)
+
+
+@pytest.mark.parametrize("kwargs", LOAD_MODES, ids=lambda k: ",".join(k) or "default")
+def test_font_iter(kwargs: dict) -> None:
+    font = ufoLib2.Font.open(UFOS[0], lazy=False)
+    id_font = iondrive.load(ufoLib2.objects, UFOS[0], **kwargs)
+
+    glyphs = list(id_font)
+    # ufoLib2 iterates in the order of contents.plist; iondrive follows the
+    # font's public.glyphOrder, so only the order differs.
+    assert [glyph.name for glyph in glyphs] == id_font.lib["public.glyphOrder"]
+    assert sorted(glyph.name for glyph in glyphs) == sorted(glyph.name for glyph in font)
+    for glyph in glyphs:
+        assert isinstance(glyph, ufoLib2.objects.Glyph)
+        assert glyph is id_font[glyph.name]
+        assert glyph == font[glyph.name]
+
+
+@pytest.mark.parametrize("kwargs", LOAD_MODES, ids=lambda k: ",".join(k) or "default")
+def test_font_contains(kwargs: dict) -> None:
+    font = ufoLib2.Font.open(UFOS[0])
+    id_font = iondrive.load(ufoLib2.objects, UFOS[0], **kwargs)
+
+    for name in font.keys():
+        assert name in id_font
+    assert "not.a.glyph" not in id_font
+    assert "not.a.glyph" not in font
@@ -0,0 +1,136 @@
+import plistlib
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_subclasses() -> None:
+    for error in (
+        iondrive.IondriveIOError,
+        iondrive.IondriveParseError,
+        iondrive.IondriveStructureError,
+    ):
+        assert issubclass(error, iondrive.IondriveError)
+
+
+def test_missing_ufo(tmp_path: Path) -> None:
+    with pytest.raises(iondrive.IondriveIOError):
+        iondrive.load(ufoLib2.objects, tmp_path / "Missing.ufo")
+
+
+def test_missing_contents(ufo: Path) -> None:
+    (ufo / "glyphs" / "contents.plist").unlink()
+
+    with pytest.raises(iondrive.IondriveIOError):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+def test_malformed_glif(ufo: Path) -> None:
+    (ufo / "glyphs" / "A_.glif").write_text("<glyph")
+
+    with pytest.raises(iondrive.IondriveParseError, match="A_.glif"):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+def test_malformed_plist(ufo: Path) -> None:
+    (ufo / "lib.plist").write_text("<plist")
+
+    with pytest.raises(iondrive.IondriveParseError):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+def test_overlapping_kerning_groups(ufo: Path) -> None:
+    with open(ufo / "groups.plist", "wb") as f:
+        plistlib.dump({"public.kern1.a": ["A"], "public.kern1.b": ["A"]}, f)
+
+    with pytest.raises(iondrive.IondriveStructureError, match="kerning group"):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+def test_load_many_mixed_errors(ufo: Path, tmp_path: Path) -> None:
+    (ufo / "glyphs" / "A_.glif").write_text("<glyph")
+
+    with pytest.raises(iondrive.IondriveParseError):
+        iondrive.load_many(ufoLib2.objects, [ufo, MUTATOR])
+    with pytest.raises(iondrive.IondriveError) as info:
+        iondrive.load_many(ufoLib2.objects, [ufo, tmp_path / "Missing.ufo"])
+    assert type(info.value) is iondrive.IondriveError
+
+
+def test_parse_glif_error() -> None:
+    with pytest.raises(iondrive.IondriveParseError):
+        iondrive.parse_glif(ufoLib2.objects, "<glyph")
+
+
+def test_glif_error_location(ufo: Path) -> None:
+    glif = ufo / "glyphs" / "A_.glif"
+    lines = glif.read_text().splitlines()
+    line = next(i for i, text in enumerate(lines) if "<contour>" in text) + 2
+    lines.insert(line - 1, "<foo/>")
+    glif.write_text("\n".join(lines))
+
+    with pytest.raises(iondrive.IondriveParseError) as info:
+        iondrive.load(ufoLib2.objects, ufo)
+    assert str(info.value) == f"glyphs/A_.glif:{line}: Unexpected element"
+
+
+def test_xml_error_names_file(ufo: Path) -> None:
+    glif = ufo / "glyphs" / "A_.glif"
+    glif.write_text(glif.read_text().replace("</outline>", "</outlin>"))
+
+    with pytest.raises(iondrive.IondriveParseError, match="^glyphs/A_.glif: "):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+def test_error_kinds(ufo: Path, tmp_path: Path) -> None:
+    with pytest.raises(iondrive.IondriveIOError) as info:
+        iondrive.load(ufoLib2.objects, tmp_path / "Missing.ufo")
+    assert info.value.kind == "MissingUfoDir"
+
+    (ufo / "lib.plist").write_text("<plist")
+    with pytest.raises(iondrive.IondriveParseError) as info:
+        iondrive.load(ufoLib2.objects, ufo)
+    assert info.value.kind == "InvalidPlist"
+
+    with open(ufo / "groups.plist", "wb") as f:
+        plistlib.dump({"public.kern1.a": ["A"], "public.kern1.b": ["A"]}, f)
+    (ufo / "lib.plist").unlink()
+    with pytest.raises(iondrive.IondriveStructureError) as info:
+        iondrive.load(ufoLib2.objects, ufo)
+    assert info.value.kind == "InvalidGroups"
+
+    shutil.copy(MUTATOR / "groups.plist", ufo / "groups.plist")
+    (ufo / "glyphs" / "contents.plist").unlink()
+    with pytest.raises(iondrive.IondriveIOError) as info:
+        iondrive.load(ufoLib2.objects, ufo)
+    assert info.value.kind == "MissingFile"
+
+
+def test_glif_error_kind(ufo: Path, tmp_path: Path) -> None:
+    (ufo / "glyphs" / "A_.glif").write_text("<glyph")
+    other = tmp_path / "Other.ufo"
+    shutil.copytree(ufo, other)
+
+    with pytest.raises(iondrive.IondriveParseError) as info:
+        iondrive.parse_glif(ufoLib2.objects, "<glyph")
+    assert info.value.kind == "InvalidGlif"
+    with pytest.raises(iondrive.IondriveParseError) as info:
+        iondrive.load_many(ufoLib2.objects, [ufo, other])
+    assert info.value.kind == "InvalidGlif"
+    # UFOs failing in different ways have no kind in common.
+    with pytest.raises(iondrive.IondriveError) as info:
+        iondrive.load_many(ufoLib2.objects, [ufo, tmp_path / "Missing.ufo"])
+    assert info.value.kind is None
+
+
+def test_no_kind() -> None:
+    with pytest.raises(iondrive.IondriveError) as info:
+        iondrive.from_bytes(ufoLib2.objects, b"not a packed font")
+    assert info.value.kind is None
+    assert iondrive.IondriveError("message").kind is None
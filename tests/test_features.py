@@ -0,0 +1,94 @@
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+@pytest.fixture
+def ufo(ufo: Path) -> Path:
+    (ufo / "features.fea").write_text(
+        "languagesystem DFLT dflt;\n"
+        "include(features/kern.fea);\n"
+        "# include(commented-out.fea)\n"
+    )
+    (ufo / "features").mkdir()
+    (ufo / "features" / "kern.fea").write_text(
+        "include ( classes.fea ) ;\nfeature kern { pos @caps V -50; } kern;\n"
+    )
+    (ufo.parent / "classes.fea").write_text("@caps = [A B];\n")
+    return ufo
+
+
+def test_features_unresolved_by_default(ufo: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, ufo)
+
+    assert font.features.text == (ufo / "features.fea").read_text()
+
+
+def test_resolve_feature_includes(ufo: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, ufo, resolve_feature_includes=True)
+
+    assert font.features.text == (
+        "languagesystem DFLT dflt;\n"
+        "@caps = [A B];\n"
+        "\nfeature kern { pos @caps V -50; } kern;\n"
+        "\n# include(commented-out.fea)\n"
+    )
+
+
+def test_resolve_missing_feature_include(ufo: Path) -> None:
+    (ufo.parent / "classes.fea").unlink()
+
+    with pytest.raises(iondrive.IondriveIOError, match="classes.fea not found"):
+        iondrive.load(ufoLib2.objects, ufo, resolve_feature_includes=True)
+
+
+def test_resolve_cyclic_feature_includes(ufo: Path) -> None:
+    (ufo.parent / "classes.fea").write_text(f"include({ufo.name}/features/kern.fea);")
+
+    with pytest.raises(iondrive.IondriveError, match="cyclic include"):
+        iondrive.load(ufoLib2.objects, ufo, resolve_feature_includes=True)
+
+
+def test_features_object() -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+
+    assert isinstance(font.features, ufoLib2.objects.Features)
+    assert font.features.text == (MUTATOR / "features.fea").read_text()
+
+
+def test_empty_features(tmp_path: Path) -> None:
+    ufoLib2.Font().save(tmp_path / "Empty.ufo")
+
+    font = iondrive.load(ufoLib2.objects, tmp_path / "Empty.ufo")
+
+    assert isinstance(font.features, ufoLib2.objects.Features)
+    assert font.features.text == ""
+
+
+def test_load_features(ufo: Path) -> None:
+    for resolve in [False, True]:
+        font = iondrive.load(ufoLib2.objects, ufo, resolve_feature_includes=resolve)
+        text = iondrive.load_features(ufo, resolve_includes=resolve)
+        assert text == font.features.text
+
+
+def test_load_features_ufoz(ufo: Path) -> None:
+    # Zipped by hand, as saving would leave out the included files.
+    archive = shutil.make_archive(str(ufo.with_suffix("")), "zip", ufo.parent, ufo.name)
+    ufoz = Path(archive).rename(ufo.with_suffix(".ufoz"))
+
+    assert iondrive.load_features(ufoz) == iondrive.load_features(ufo)
+    resolved = iondrive.load_features(ufo, resolve_includes=True)
+    assert iondrive.load_features(ufoz, resolve_includes=True) == resolved
+
+
+def test_load_features_missing(tmp_path: Path) -> None:
+    assert iondrive.load_features(Path("tests/data/Empty.ufo")) == ""
+    with pytest.raises(iondrive.IondriveIOError):
+        iondrive.load_features(tmp_path / "Missing.ufo")
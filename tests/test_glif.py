@@ -0,0 +1,79 @@
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+GLIF = """<?xml version="1.0" encoding="UTF-8"?>
+<glyph name="A" format="2">
+  <advance width="500"/>
+  <unicode hex="0041"/>
+  <outline>
+    <contour>
+      <point x="0" y="0" type="line"/>
+      <point x="250" y="700" type="line"/>
+      <point x="500" y="0" type="line"/>
+    </contour>
+  </outline>
+</glyph>
+"""
+
+
+def test_parse_glif() -> None:
+    glyph = iondrive.parse_glif(ufoLib2.objects, GLIF)
+
+    assert glyph.name == "A"
+    assert glyph.width == 500
+    assert glyph.unicodes == [0x41]
+    assert [(p.x, p.y) for p in glyph.contours[0]] == [(0, 0), (250, 700), (500, 0)]
+
+
+def test_parse_glif_matches_load_glyph() -> None:
+    glif = (MUTATOR / "glyphs" / "A_.glif").read_text()
+
+    parsed = iondrive.parse_glif(ufoLib2.objects, glif)
+
+    assert parsed == iondrive.load_glyph(ufoLib2.objects, MUTATOR, "A")
+
+
+def test_parse_glif_glyph_name() -> None:
+    assert iondrive.parse_glif(ufoLib2.objects, GLIF, "B").name == "B"
+
+    unnamed = GLIF.replace(' name="A"', "")
+    assert iondrive.parse_glif(ufoLib2.objects, unnamed, "B").name == "B"
+
+
+def test_parse_glif_malformed() -> None:
+    with pytest.raises(iondrive.IondriveError, match="outline"):
+        iondrive.parse_glif(ufoLib2.objects, GLIF.replace("</outline>", ""))
+
+
+def test_dump_glif() -> None:
+    glif = iondrive.dump_glif(iondrive.parse_glif(ufoLib2.objects, GLIF))
+
+    assert glif.startswith('<?xml version="1.0" encoding="UTF-8"?>\n<glyph name="A"')
+    assert '\n  <advance width="500"/>\n' in glif
+
+
+@pytest.mark.parametrize("name", ["A", "Aacute", "S"])
+def test_glif_roundtrip(name: str) -> None:
+    glyph = iondrive.load_glyph(ufoLib2.objects, MUTATOR, name)
+
+    glif = iondrive.dump_glif(glyph)
+    parsed = iondrive.parse_glif(ufoLib2.objects, glif)
+
+    assert parsed == glyph
+    assert iondrive.dump_glif(parsed) == glif
+
+
+def test_parse_glif_duplicate_unicodes() -> None:
+    glif = GLIF.replace(
+        '<unicode hex="0041"/>',
+        '<unicode hex="1F600"/><unicode hex="0041"/><unicode hex="1F600"/>',
+    )
+
+    glyph = iondrive.parse_glif(ufoLib2.objects, glif)
+
+    assert glyph.unicodes == [0x1F600, 0x41, 0x1F600]
+    assert iondrive.dump_glif(glyph).count('hex="1F600"') == 2
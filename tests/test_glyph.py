@@ -0,0 +1,314 @@
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def roundtrip_glyph(glyph: ufoLib2.objects.Glyph, tmp_path: Path) -> ufoLib2.objects.Glyph:
+    """Save `glyph` in a fresh UFO with ufoLib2 and load it back with iondrive."""
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.insertGlyph(glyph)
+    font.save(tmp_path / "Test.ufo")
+    return iondrive.load(ufoLib2.objects, tmp_path / "Test.ufo")[glyph.name]
+
+
+def test_height(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("uni4E00", width=1000, height=880)
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.height == 880
+
+
+def test_image(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    glyph.image = ufoLib2.objects.Image(
+        fileName="scan.png", transformation=(0.5, 0, 0, 0.5, 10, 20), color="1,0,0,1"
+    )
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.image.fileName == "scan.png"
+    assert tuple(loaded.image.transformation) == (0.5, 0, 0, 0.5, 10, 20)
+    assert loaded.image.color == "1,0,0,1"
+
+
+def test_no_image(tmp_path: Path) -> None:
+    loaded = roundtrip_glyph(ufoLib2.objects.Glyph("A"), tmp_path)
+
+    assert loaded.image == ufoLib2.objects.Image()
+
+
+def test_anchor_color_and_identifier(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    glyph.appendAnchor(
+        ufoLib2.objects.Anchor(
+            x=100, y=200, name="top", color="0,0.5,1,1", identifier="anchor-top"
+        )
+    )
+    glyph.appendAnchor(ufoLib2.objects.Anchor(x=100, y=0, name="bottom"))
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.anchors[0].color == "0,0.5,1,1"
+    assert loaded.anchors[0].identifier == "anchor-top"
+    assert loaded.anchors[1].color is None
+    assert loaded.anchors[1].identifier is None
+
+
+def test_anchor_order_and_duplicates(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    anchors = [("top", 300, 700), ("_top", 300, 0), ("top", 310, 720), ("bottom", 0, 0)]
+    for name, x, y in anchors:
+        glyph.appendAnchor(ufoLib2.objects.Anchor(x=x, y=y, name=name))
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+    assert [(a.name, a.x, a.y) for a in loaded.anchors] == anchors
+
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.insertGlyph(loaded)
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo")
+    for loaded in (
+        iondrive.load(ufoLib2.objects, tmp_path / "Saved.ufo"),
+        iondrive.load(ufoLib2.objects, tmp_path / "Saved.ufo", lazy=True),
+        iondrive.from_bytes(
+            ufoLib2.objects,
+            iondrive.load(ufoLib2.objects, tmp_path / "Saved.ufo", as_bytes=True),
+        ),
+    ):
+        assert [(a.name, a.x, a.y) for a in loaded["A"].anchors] == anchors
+
+
+def test_component_transformation_and_identifier(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("Aitalic")
+    glyph.components.append(
+        ufoLib2.objects.Component(
+            "A", (0.75, 0.125, 0.25, 1.5, -30.5, 12), identifier="comp1"
+        )
+    )
+
+    component = roundtrip_glyph(glyph, tmp_path).components[0]
+    assert component.baseGlyph == "A"
+    assert tuple(component.transformation) == (0.75, 0.125, 0.25, 1.5, -30.5, 12)
+    assert component.identifier == "comp1"
+
+
+def test_guideline_variants(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    glyph.appendGuideline(ufoLib2.objects.Guideline(y=500))
+    glyph.appendGuideline(ufoLib2.objects.Guideline(x=250))
+    glyph.appendGuideline(ufoLib2.objects.Guideline(x=10, y=20, angle=45))
+    glyph.appendGuideline(
+        ufoLib2.objects.Guideline(
+            y=700, name="overshoot", color="1,0,0,0.5", identifier="guide-1"
+        )
+    )
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.guidelines == glyph.guidelines
+
+
+def test_contour_and_point_identifiers(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    glyph.contours.append(
+        ufoLib2.objects.Contour(
+            points=[
+                ufoLib2.objects.Point(0, 0, "line", identifier="point-1"),
+                ufoLib2.objects.Point(100, 0, "line"),
+                ufoLib2.objects.Point(100, 100, "line", identifier="point-3"),
+            ],
+            identifier="contour-1",
+        )
+    )
+    glyph.contours.append(
+        ufoLib2.objects.Contour(points=[ufoLib2.objects.Point(0, 0, "move")])
+    )
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.contours[0].identifier == "contour-1"
+    assert [p.identifier for p in loaded.contours[0].points] == [
+        "point-1",
+        None,
+        "point-3",
+    ]
+    assert loaded.contours[1].identifier is None
+
+
+def test_integerize_coords(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A")
+    glyph.contours.append(
+        ufoLib2.objects.Contour(
+            points=[
+                ufoLib2.objects.Point(120.0, 0.0, "line"),
+                ufoLib2.objects.Point(120.5, -30.0, "line"),
+            ]
+        )
+    )
+    glyph.components.append(
+        ufoLib2.objects.Component("B", (1.0, 0, 0, 1.0, 40.0, 0.25))
+    )
+    glyph.appendAnchor(ufoLib2.objects.Anchor(x=10.0, y=20.5, name="top"))
+    roundtrip_glyph(glyph, tmp_path)
+
+    default = iondrive.load(ufoLib2.objects, tmp_path / "Test.ufo")["A"]
+    integerized = iondrive.load(
+        ufoLib2.objects, tmp_path / "Test.ufo", integerize_coords=True
+    )["A"]
+
+    assert default == integerized
+    points = default.contours[0].points
+    assert [(type(p.x), type(p.y)) for p in points] == [(float, float)] * 2
+    assert {type(v) for v in default.components[0].transformation} == {float}
+    points = integerized.contours[0].points
+    assert [(type(p.x), type(p.y)) for p in points] == [(int, int), (float, int)]
+    assert [type(v) for v in integerized.components[0].transformation] == [
+        int, int, int, int, int, float
+    ]
+    for loaded in (default, integerized):
+        assert (type(loaded.anchors[0].x), type(loaded.anchors[0].y)) == (float, float)
+
+
+def test_point_smooth_and_name(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("O")
+    glyph.contours.append(
+        ufoLib2.objects.Contour(
+            points=[
+                ufoLib2.objects.Point(0, 100, "curve", smooth=True, name="left"),
+                ufoLib2.objects.Point(0, 155),
+                ufoLib2.objects.Point(45, 200),
+                ufoLib2.objects.Point(100, 200, "curve"),
+                ufoLib2.objects.Point(155, 200),
+                ufoLib2.objects.Point(200, 155),
+            ]
+        )
+    )
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    points = loaded.contours[0].points
+    assert points[0].smooth is True
+    assert points[0].name == "left"
+    assert points[3].smooth is False
+    assert points[3].name is None
+    assert not any(p.smooth for p in points if p.type is None)
+
+
+def test_glyph_order(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    names = sorted(font.keys())
+    shuffled = names[1::2] + names[::2]
+    font.lib["public.glyphOrder"] = shuffled[: len(shuffled) // 2]
+    font.save(tmp_path / "Shuffled.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Shuffled.ufo")
+    listed = shuffled[: len(shuffled) // 2]
+    unlisted = [name for name in names if name not in listed]
+
+    assert list(loaded.layers.defaultLayer.keys()) == listed + unlisted
+
+
+NOTE = "  first line\nsecond 🎉 line\n\tשלום עולם & <done>\n\n"
+
+
+def test_note_round_trip(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.newGlyph("A").note = NOTE
+    font.info.note = NOTE
+    font.save(tmp_path / "Notes.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Notes.ufo")
+
+    assert loaded["A"].note.encode() == NOTE.encode()
+    assert loaded.info.note.encode() == NOTE.encode()
+    assert iondrive.load_glyph(ufoLib2.objects, tmp_path / "Notes.ufo", "A").note == NOTE
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    saved = iondrive.load(ufoLib2.objects, tmp_path / "Saved.ufo")
+
+    assert saved["A"].note.encode() == NOTE.encode()
+    assert saved.info.note.encode() == NOTE.encode()
+
+
+def test_unicodes_order(tmp_path: Path) -> None:
+    glyph = ufoLib2.objects.Glyph("A", unicodes=[0x1D400, 0x41, 0x391])
+
+    loaded = roundtrip_glyph(glyph, tmp_path)
+
+    assert loaded.unicodes == [0x1D400, 0x41, 0x391]
+    assert all(type(u) is int for u in loaded.unicodes)
+
+
+DUPLICATE_UNICODES_GLIF = Path("tests/data/duplicate_unicodes.glif")
+
+
+def test_dedup_unicodes(tmp_path: Path) -> None:
+    ufo = tmp_path / "Duplicates.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    (ufo / "glyphs" / "A_.glif").write_bytes(DUPLICATE_UNICODES_GLIF.read_bytes())
+
+    raw = iondrive.load(ufoLib2.objects, ufo)
+    deduped = iondrive.load(ufoLib2.objects, ufo, dedup_unicodes=True)
+
+    assert raw["A"].unicodes == [0x41, 0x391, 0x41, 0x61, 0x391]
+    assert deduped["A"].unicodes == [0x41, 0x391, 0x61]
+    assert deduped["B"].unicodes == raw["B"].unicodes
+
+
+FRACTIONAL_GLIF = Path("tests/data/fractional.glif")
+
+
+def test_coord_precision(tmp_path: Path) -> None:
+    ufo = tmp_path / "Fractional.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    (ufo / "glyphs" / "A_.glif").write_bytes(FRACTIONAL_GLIF.read_bytes())
+
+    raw = iondrive.load(ufoLib2.objects, ufo)["A"]
+    rounded = iondrive.load(ufoLib2.objects, ufo, coord_precision=2)["A"]
+
+    assert [(p.x, p.y) for p in raw.contours[0]] == [
+        (0.125, 0.375),
+        (100.5555, 2.675),
+        (-3.125, 50.0049),
+    ]
+    # Halves go to the even neighbour.
+    assert [(p.x, p.y) for p in rounded.contours[0]] == [
+        (0.12, 0.38),
+        (100.56, 2.68),
+        (-3.12, 50),
+    ]
+    assert (rounded.anchors[0].x, rounded.anchors[0].y) == (370.12, 700.38)
+    guideline = rounded.guidelines[0]
+    assert (guideline.x, guideline.y, guideline.angle) == (10, 20.02, 45.125)
+    assert tuple(rounded.components[0].transformation) == (
+        0.5,
+        0,
+        0,
+        1,
+        10.12,
+        -5.38,
+    )
+    assert rounded.width == raw.width == 740.5
+
+
+NEGATIVE_ANGLE_GLIF = Path("tests/data/negative_angle.glif")
+
+
+def test_guideline_angle_normalized(tmp_path: Path) -> None:
+    ufo = tmp_path / "NegativeAngle.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    (ufo / "glyphs" / "A_.glif").write_bytes(NEGATIVE_ANGLE_GLIF.read_bytes())
+
+    normalized = iondrive.load(ufoLib2.objects, ufo)["A"]
+    lenient = iondrive.load(ufoLib2.objects, ufo, validate=False)["A"]
+    single = iondrive.load_glyph(ufoLib2.objects, ufo, "A")
+
+    assert [g.angle for g in normalized.guidelines] == [315, 90, None]
+    assert [(g.x, g.y) for g in normalized.guidelines] == [(10, 20), (30, 40), (50, None)]
+    assert [g.angle for g in single.guidelines] == [315, 90, None]
+    # Lenient loading passes the angles through as they are in the file.
+    assert [g.angle for g in lenient.guidelines] == [-45, 90, None]
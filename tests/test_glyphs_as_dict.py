@@ -0,0 +1,48 @@
+import types
+from typing import Any, List
+
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def recording_objects(received: List[Any]) -> types.SimpleNamespace:
+    """ufoLib2's objects, with a `Layer` that records the glyphs it is given."""
+
+    class Layer(ufoLib2.objects.Layer):
+        def __init__(self, *args: Any, **kwargs: Any) -> None:
+            received.append(kwargs.get("glyphs"))
+            super().__init__(*args, **kwargs)
+
+    objects = types.SimpleNamespace(**vars(ufoLib2.objects))
+    objects.Layer = Layer
+    return objects
+
+
+def test_glyphs_as_dict() -> None:
+    received: List[Any] = []
+    font = iondrive.load(recording_objects(received), MUTATOR, glyphs_as_dict=True)
+
+    assert received and all(isinstance(glyphs, dict) for glyphs in received)
+    default = received[0]
+    assert list(default) == [glyph.name for glyph in default.values()]
+    assert font == iondrive.load(ufoLib2.objects, MUTATOR)
+
+
+def test_glyphs_as_list_by_default() -> None:
+    received: List[Any] = []
+    iondrive.load(recording_objects(received), MUTATOR)
+
+    assert received and all(isinstance(glyphs, list) for glyphs in received)
+
+
+def test_glyphs_as_dict_keeps_order() -> None:
+    listed: List[Any] = []
+    keyed: List[Any] = []
+    iondrive.load(recording_objects(listed), MUTATOR)
+    iondrive.load(recording_objects(keyed), MUTATOR, glyphs_as_dict=True)
+
+    for glyphs, by_name in zip(listed, keyed):
+        assert [glyph.name for glyph in glyphs] == list(by_name)
@@ -0,0 +1,189 @@
+import plistlib
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+
+def test_info_guidelines(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.guidelines = [
+        ufoLib2.objects.Guideline(y=500, name="x-height", identifier="guide-x"),
+        ufoLib2.objects.Guideline(x=10, y=20, angle=45, color="1,0,0,1"),
+    ]
+    font.save(tmp_path / "Guidelines.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Guidelines.ufo")
+
+    assert loaded.info.guidelines == font.info.guidelines
+    assert loaded.info.guidelines[0].name == "x-height"
+    assert loaded.info.guidelines[1].angle == 45
+
+
+FULL_INFO = Path("tests/data/FullInfo.ufo")
+
+
+def test_load_full_info() -> None:
+    with open(FULL_INFO / "fontinfo.plist", "rb") as f:
+        expected = plistlib.load(f)
+
+    info = iondrive.load_info(ufoLib2.objects, FULL_INFO)
+
+    assert info == ufoLib2.Font.open(FULL_INFO).info
+    for key in expected:
+        assert getattr(info, key) is not None, key
+    assert info.openTypeOS2WinAscent == 932
+    assert info.openTypeHheaLineGap == 28
+    assert info.openTypeOS2Panose == [2, 11, 5, 3, 3, 4, 3, 9, 2, 4]
+    assert info.openTypeNameRecords[1].string == "Alternate a"
+    assert info.openTypeGaspRangeRecords[0].rangeGaspBehavior == [0, 2]
+    assert info.postscriptBlueValues == [-12, 0, 500, 512, 700, 712]
+    assert info.postscriptBlueScale == 0.039625
+    assert info.styleMapStyleName == "italic"
+
+
+def test_load_info_absent_fields(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.familyName = "Sparse"
+    font.save(tmp_path / "Sparse.ufo")
+
+    info = iondrive.load_info(ufoLib2.objects, tmp_path / "Sparse.ufo")
+
+    assert info == font.info
+    assert info.familyName == "Sparse"
+    assert info.openTypeOS2WinAscent is None
+    assert info.openTypeOS2WidthClass is None
+    assert info.postscriptBlueValues is None
+
+
+def test_save_full_info(tmp_path: Path) -> None:
+    with open(FULL_INFO / "fontinfo.plist", "rb") as f:
+        expected = plistlib.load(f)
+    font = iondrive.load(ufoLib2.objects, FULL_INFO)
+
+    iondrive.save(ufoLib2.objects, font, tmp_path / "FullInfo.ufo")
+
+    with open(tmp_path / "FullInfo.ufo" / "fontinfo.plist", "rb") as f:
+        assert plistlib.load(f) == expected
+
+
+def test_info_name_records(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.openTypeNameRecords = [
+        {
+            "nameID": 1,
+            "platformID": 3,
+            "encodingID": 1,
+            "languageID": 1031,
+            "string": "Testschrift",
+        },
+        {
+            "nameID": 256,
+            "platformID": 1,
+            "encodingID": 0,
+            "languageID": 0,
+            "string": "Alternate g",
+        },
+    ]
+    font.save(tmp_path / "NameRecords.ufo")
+
+    loaded = iondrive.load_info(ufoLib2.objects, tmp_path / "NameRecords.ufo")
+
+    assert loaded.openTypeNameRecords == font.info.openTypeNameRecords
+    assert [r.nameID for r in loaded.openTypeNameRecords] == [1, 256]
+    assert loaded.openTypeNameRecords[0].languageID == 1031
+    assert loaded.openTypeNameRecords[0].string == "Testschrift"
+
+
+def test_info_panose_and_family_class(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.openTypeOS2Panose = [2, 0, 5, 3, 0, 0, 0, 2, 0, 4]
+    font.info.openTypeOS2FamilyClass = [8, 1]
+    font.save(tmp_path / "Panose.ufo")
+
+    loaded = iondrive.load_info(ufoLib2.objects, tmp_path / "Panose.ufo")
+
+    assert loaded.openTypeOS2Panose == [2, 0, 5, 3, 0, 0, 0, 2, 0, 4]
+    assert loaded.openTypeOS2FamilyClass == [8, 1]
+
+
+def test_info_gasp_range_records(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.openTypeGaspRangeRecords = [
+        {"rangeMaxPPEM": 8, "rangeGaspBehavior": [1]},
+        {"rangeMaxPPEM": 65535, "rangeGaspBehavior": [0, 1, 2, 3]},
+    ]
+    font.save(tmp_path / "Gasp.ufo")
+
+    loaded = iondrive.load_info(ufoLib2.objects, tmp_path / "Gasp.ufo")
+
+    assert loaded.openTypeGaspRangeRecords == font.info.openTypeGaspRangeRecords
+    assert loaded.openTypeGaspRangeRecords[1].rangeGaspBehavior == [0, 1, 2, 3]
+
+
+INFO_DEFAULTS = {
+    "unitsPerEm": 1000,
+    "versionMajor": 0,
+    "versionMinor": 0,
+    "italicAngle": 0,
+    "openTypeHeadFlags": [],
+    "openTypeHeadLowestRecPPEM": 6,
+    "openTypeHheaCaretOffset": 0,
+    "openTypeHheaLineGap": 0,
+    "openTypeOS2WeightClass": 400,
+    "openTypeOS2WidthClass": 5,
+    "openTypeOS2Selection": [],
+    "openTypeOS2Type": [],
+    "postscriptBlueValues": [],
+    "postscriptOtherBlues": [],
+    "postscriptFamilyBlues": [],
+    "postscriptFamilyOtherBlues": [],
+    "postscriptStemSnapH": [],
+    "postscriptStemSnapV": [],
+    "postscriptBlueFuzz": 1,
+    "postscriptBlueShift": 7,
+    "postscriptBlueScale": 0.039625,
+    "postscriptForceBold": False,
+    "postscriptIsFixedPitch": False,
+    "postscriptDefaultWidthX": 0,
+    "postscriptNominalWidthX": 0,
+}
+
+
+def test_populate_defaults(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.info.familyName = "Sparse"
+    font.info.unitsPerEm = 2048
+    font.save(tmp_path / "Sparse.ufo")
+
+    info = iondrive.load(
+        ufoLib2.objects, tmp_path / "Sparse.ufo", populate_defaults=True
+    ).info
+
+    assert info.familyName == "Sparse"
+    assert info.unitsPerEm == 2048
+    for key, value in INFO_DEFAULTS.items():
+        if key != "unitsPerEm":
+            assert getattr(info, key) == value, key
+    assert info.ascender is None
+    assert info.openTypeOS2WinAscent is None
+    assert iondrive.load(ufoLib2.objects, tmp_path / "Sparse.ufo").info == font.info
+
+
+def test_populate_defaults_without_fontinfo(tmp_path: Path) -> None:
+    ufoLib2.Font().save(tmp_path / "Empty.ufo")
+    (tmp_path / "Empty.ufo" / "fontinfo.plist").unlink(missing_ok=True)
+
+    info = iondrive.load(
+        ufoLib2.objects, tmp_path / "Empty.ufo", populate_defaults=True
+    ).info
+
+    for key, value in INFO_DEFAULTS.items():
+        assert getattr(info, key) == value, key
+
+
+def test_populate_defaults_keeps_full_info() -> None:
+    loaded = iondrive.load(ufoLib2.objects, FULL_INFO, populate_defaults=True)
+
+    assert loaded.info == ufoLib2.Font.open(FULL_INFO).info
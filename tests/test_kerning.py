@@ -0,0 +1,103 @@
+import types
+from pathlib import Path
+
+import pytest
+import ufoLib2
+import ufoLib2.objects
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_kerning_matches_ufolib2_type() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    id_font = iondrive.load(ufoLib2.objects, MUTATOR)
+
+    assert type(id_font.kerning) is type(font.kerning)
+    assert id_font.kerning[("A", "J")] == font.kerning[("A", "J")]
+    assert id_font.kerning.get(("A", "nonexistent")) is None
+
+
+def test_kerning_without_kerning_type(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    objects = types.SimpleNamespace(**vars(ufoLib2.objects))
+    del objects.Kerning
+
+    id_font = iondrive.load(objects, MUTATOR)
+
+    assert type(id_font.kerning) is dict
+    assert id_font.kerning["A"]["J"] == font.kerning[("A", "J")]
+    assert {
+        (first, second): value
+        for first, seconds in id_font.kerning.items()
+        for second, value in seconds.items()
+    } == font.kerning
+    iondrive.save(objects, id_font, tmp_path / "Nested.ufo")
+    assert ufoLib2.Font.open(tmp_path / "Nested.ufo").kerning == font.kerning
+
+
+def test_failing_kerning_type() -> None:
+    def kerning(pairs: dict) -> None:
+        raise ValueError("no kerning here")
+
+    objects = types.SimpleNamespace(**vars(ufoLib2.objects))
+    objects.Kerning = kerning
+
+    with pytest.raises(ValueError, match="no kerning here"):
+        iondrive.load(objects, MUTATOR)
+
+
+def test_load_kerning() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    kerning, groups = iondrive.load_kerning(ufoLib2.objects, MUTATOR)
+
+    assert type(kerning) is type(font.kerning)
+    assert kerning == font.kerning
+    assert groups == font.groups
+
+
+def test_load_kerning_missing_files(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    font.kerning.clear()
+    font.groups.clear()
+    font.save(tmp_path / "NoKerning.ufo")
+    assert not (tmp_path / "NoKerning.ufo" / "kerning.plist").exists()
+    assert not (tmp_path / "NoKerning.ufo" / "groups.plist").exists()
+
+    kerning, groups = iondrive.load_kerning(ufoLib2.objects, tmp_path / "NoKerning.ufo")
+    assert kerning == {}
+    assert groups == {}
+
+
+def test_load_kerning_ufo2() -> None:
+    kerning, groups = iondrive.load_kerning(ufoLib2.objects, Path("tests/data/UFO2.ufo"))
+
+    assert kerning[("public.kern1.round", "public.kern2.round")] == -10
+    assert groups["public.kern1.round"] == ["O", "D"]
+
+
+def test_check_kerning_groups(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    font.kerning[("public.kern1.gone", "A")] = -10
+    font.kerning[("A", "public.kern2.missing")] = -20
+    font.kerning[("public.kern1.gone", "public.kern2.missing")] = -30
+    font.save(tmp_path / "Dangling.ufo")
+
+    # The check is off by default.
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Dangling.ufo")
+    assert loaded.kerning[("public.kern1.gone", "A")] == -10
+
+    with pytest.raises(iondrive.IondriveStructureError) as excinfo:
+        iondrive.load(ufoLib2.objects, tmp_path / "Dangling.ufo", check_kerning_groups=True)
+    message = str(excinfo.value)
+    assert "left group 'public.kern1.gone'" in message
+    assert "right group 'public.kern2.missing'" in message
+    assert message.count("public.kern1.gone") == 1
+    assert isinstance(excinfo.value, iondrive.IondriveError)
+
+
+def test_check_kerning_groups_valid() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, check_kerning_groups=True)
+    assert loaded.kerning == font.kerning
@@ -0,0 +1,54 @@
+from pathlib import Path
+from typing import Any, List
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+LAYER_ORDER = Path("tests/data/LayerOrder.ufo")
+# The order of layercontents.plist, which neither starts with the default layer
+# nor follows the names of the layer directories.
+PLIST_ORDER = ["sketches", "public.default", "alternates"]
+
+
+def layer_names(font: Any) -> List[str]:
+    return [layer.name for layer in font.layers]
+
+
+@pytest.mark.parametrize(
+    "load",
+    [
+        lambda path: iondrive.load(ufoLib2.objects, path),
+        lambda path: iondrive.load(ufoLib2.objects, path, lazy=True),
+        lambda path: iondrive.load(ufoLib2.objects, path, use_cache=True),
+        lambda path: iondrive.from_bytes(
+            ufoLib2.objects, iondrive.load(ufoLib2.objects, path, as_bytes=True)
+        ),
+        lambda path: iondrive.load_many(ufoLib2.objects, [path])[0],
+    ],
+    ids=["load", "lazy", "cached", "as_bytes", "load_many"],
+)
+def test_layer_order(load: Any) -> None:
+    font = load(LAYER_ORDER)
+
+    assert layer_names(font) == PLIST_ORDER
+    assert layer_names(font) == layer_names(ufoLib2.Font.open(LAYER_ORDER))
+    assert font.layers.defaultLayer.name == "public.default"
+
+
+def test_layer_order_of_selected_layers() -> None:
+    font = iondrive.load(ufoLib2.objects, LAYER_ORDER, layers=["alternates"])
+
+    assert layer_names(font) == ["public.default", "alternates"]
+    font = iondrive.load(ufoLib2.objects, LAYER_ORDER, layers=["sketches"])
+
+    assert layer_names(font) == ["sketches", "public.default"]
+
+
+def test_defcon_layer_order() -> None:
+    defcon = pytest.importorskip("defcon")
+
+    font = iondrive.load(defcon, LAYER_ORDER)
+
+    assert font.layers.layerOrder == PLIST_ORDER
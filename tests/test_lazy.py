@@ -0,0 +1,41 @@
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_lazy_glyphs_match_eager() -> None:
+    lazy = iondrive.load(ufoLib2.objects, MUTATOR, lazy=True)
+    eager = iondrive.load(ufoLib2.objects, MUTATOR)
+
+    assert list(lazy.keys()) == list(eager.keys())
+    for name in eager.keys():
+        assert lazy[name] == eager[name]
+
+
+def test_lazy_glyph_is_cached() -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR, lazy=True)
+
+    assert font["A"] is font["A"]
+
+
+def test_lazy_roundtrip(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR, lazy=True)
+    font["A"].width = 1234
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Lazy.ufo")
+
+    reloaded = ufoLib2.Font.open(tmp_path / "Lazy.ufo")
+    assert reloaded["A"].width == 1234
+    assert reloaded.keys() == ufoLib2.Font.open(MUTATOR).keys()
+
+
+def test_glyph_set_takes_point_pen() -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR, lazy=True)
+    glyph = ufoLib2.objects.Glyph("A")
+
+    font.layers.defaultLayer._glyphSet.readGlyph("A", glyph, point_pen=None)
+
+    assert glyph == iondrive.load(ufoLib2.objects, MUTATOR)["A"]
@@ -0,0 +1,47 @@
+from concurrent.futures import ThreadPoolExecutor
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from test_equivalence import UFOS
+
+
+def test_load_many() -> None:
+    fonts = iondrive.load_many(ufoLib2.objects, UFOS)
+
+    assert len(fonts) == len(UFOS)
+    for path, font in zip(UFOS, fonts):
+        expected = iondrive.load(ufoLib2.objects, path)
+        assert font.info == expected.info
+        assert font.kerning == expected.kerning
+        assert font.layers == expected.layers
+
+
+def test_load_many_reports_failures(tmp_path: Path) -> None:
+    missing = tmp_path / "Missing.ufo"
+
+    with pytest.raises(iondrive.IondriveError) as excinfo:
+        iondrive.load_many(ufoLib2.objects, [UFOS[0], missing])
+
+    message = str(excinfo.value)
+    assert str(missing) in message
+    assert "loaded successfully: " + str(UFOS[0]) in message
+
+
+def test_load_from_threads() -> None:
+    expected = [iondrive.load(ufoLib2.objects, path) for path in UFOS]
+
+    with ThreadPoolExecutor(max_workers=len(UFOS)) as pool:
+        fonts = list(
+            pool.map(lambda path: iondrive.load(ufoLib2.objects, path), UFOS * 2)
+        )
+
+    assert fonts == expected * 2
+
+
+def test_load_many_takes_fewer_options() -> None:
+    with pytest.raises(TypeError, match="read"):
+        iondrive.load_many(ufoLib2.objects, UFOS, read=["layers"])
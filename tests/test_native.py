@@ -0,0 +1,45 @@
+from pathlib import Path
+
+import pytest
+
+import iondrive
+
+from paths import MUTATOR
+
+FULL_INFO = Path("tests/data/FullInfo.ufo")
+
+
+def test_load_native() -> None:
+    font = iondrive.load_native(MUTATOR)
+    raw = iondrive.load_raw(MUTATOR)
+
+    assert isinstance(font, iondrive.NoradFont)
+    assert font.layer_names() == [layer["name"] for layer in raw["layers"]]
+    for layer in raw["layers"]:
+        assert font.glyph_names(layer["name"]) == list(layer["glyphs"])
+    assert font.glyph_names() == list(raw["layers"][0]["glyphs"])
+    assert font.kerning() == raw["kerning"]
+
+
+def test_info_value() -> None:
+    font = iondrive.load_native(FULL_INFO)
+    info = iondrive.load_raw(FULL_INFO)["info"]
+
+    for key, value in info.items():
+        assert font.info_value(key) == value
+
+
+def test_info_value_unset() -> None:
+    font = iondrive.load_native(Path("tests/data/Empty.ufo"))
+
+    assert font.info_value("familyName") is None
+    assert font.info_value("guidelines") is None
+
+
+def test_missing_keys() -> None:
+    font = iondrive.load_native(MUTATOR)
+
+    with pytest.raises(KeyError):
+        font.info_value("fontName")
+    with pytest.raises(KeyError):
+        font.glyph_names("missing")
@@ -0,0 +1,484 @@
+import os
+import plistlib
+import time
+from concurrent.futures import ThreadPoolExecutor
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_load_glyph() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    glyph = iondrive.load_glyph(ufoLib2.objects, MUTATOR, "A")
+
+    assert glyph.name == "A"
+    assert glyph.width == font["A"].width
+    assert glyph.unicodes == font["A"].unicodes
+    assert len(glyph.contours) == len(font["A"].contours)
+
+
+def test_load_glyph_from_layer() -> None:
+    glyph = iondrive.load_glyph(ufoLib2.objects, MUTATOR, "S.closed", "background")
+
+    assert glyph.name == "S.closed"
+
+
+def test_load_glyph_missing() -> None:
+    with pytest.raises(KeyError):
+        iondrive.load_glyph(ufoLib2.objects, MUTATOR, "does-not-exist")
+    with pytest.raises(KeyError):
+        iondrive.load_glyph(ufoLib2.objects, MUTATOR, "A", "does-not-exist")
+
+
+@pytest.mark.parametrize(
+    "path",
+    [MUTATOR, Path("tests/data/NotoSans-Regular.ufo")],
+    ids=lambda p: p.name,
+)
+def test_load_info(path: Path) -> None:
+    assert iondrive.load_info(ufoLib2.objects, path) == ufoLib2.Font.open(path).info
+
+
+def test_load_info_missing_fontinfo(tmp_path: Path) -> None:
+    ufo = tmp_path / "NoInfo.ufo"
+    ufoLib2.Font().save(ufo)
+    if (ufo / "fontinfo.plist").exists():
+        (ufo / "fontinfo.plist").unlink()
+
+    assert iondrive.load_info(ufoLib2.objects, ufo) == ufoLib2.objects.Info()
+
+
+def test_load_layer() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    layer = iondrive.load_layer(ufoLib2.objects, MUTATOR, "background")
+
+    assert layer == font.layers["background"]
+
+
+def test_load_missing_layer() -> None:
+    with pytest.raises(iondrive.IondriveError, match="no layer named"):
+        iondrive.load_layer(ufoLib2.objects, MUTATOR, "nonexistent")
+
+
+def test_load_with_glyph_filter() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    wanted = {"A", "B", "S.closed"}
+    loaded = iondrive.load(
+        ufoLib2.objects, MUTATOR, glyph_filter=lambda name: name in wanted
+    )
+
+    assert loaded.layers.keys() == font.layers.keys()
+    for layer in font.layers:
+        filtered = loaded.layers[layer.name]
+        assert set(filtered.keys()) == wanted & set(layer.keys())
+        assert filtered.lib == layer.lib
+        for name in filtered.keys():
+            assert filtered[name] == layer[name]
+    assert loaded.kerning == font.kerning
+
+
+def test_load_glyph_set() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, glyph_set={"A", "Aacute"})
+
+    assert set(loaded.keys()) == {"A", "Aacute"}
+    assert loaded["Aacute"] == font["Aacute"]
+    assert not iondrive.load(
+        ufoLib2.objects, MUTATOR, glyph_set={"A", "Aacute"}, glyph_filter=str.islower
+    ).keys()
+
+
+def test_load_glyph_set_with_components() -> None:
+    loaded = iondrive.load(
+        ufoLib2.objects, MUTATOR, glyph_set={"A", "Aacute"}, include_components=True
+    )
+
+    assert set(loaded.keys()) == {"A", "Aacute", "acute"}
+
+
+def test_load_glyph_set_nested_components(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.newGlyph("base")
+    font.newGlyph("other")
+    for name, base in [("mid", "base"), ("top", "mid"), ("user", "top")]:
+        font.newGlyph(name).components.append(ufoLib2.objects.Component(base))
+    font["base"].components.append(ufoLib2.objects.Component("missing"))
+    font.save(tmp_path / "Nested.ufo")
+
+    loaded = iondrive.load(
+        ufoLib2.objects,
+        tmp_path / "Nested.ufo",
+        glyph_set=["top"],
+        include_components=True,
+    )
+
+    assert set(loaded.keys()) == {"top", "mid", "base"}
+
+
+def test_load_selected_layers() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, layers=["background"])
+
+    assert loaded.layers.keys() == font.layers.keys()
+    assert loaded.layers["background"] == font.layers["background"]
+
+
+def test_load_default_layer_only() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, layers=[])
+
+    assert list(loaded.layers.keys()) == [font.layers.defaultLayer.name]
+    assert loaded.layers.defaultLayer == font.layers.defaultLayer
+
+
+def test_load_unknown_layers() -> None:
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, layers=["nonexistent"])
+    assert list(loaded.layers.keys()) == [loaded.layers.defaultLayer.name]
+
+    with pytest.raises(iondrive.IondriveError, match="no layer named 'nonexistent'"):
+        iondrive.load(
+            ufoLib2.objects, MUTATOR, layers=["nonexistent"], require_layers=True
+        )
+
+
+def test_default_layer_name(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, default_layer_name="background")
+
+    assert loaded.layers.defaultLayer.name == "background"
+    assert list(loaded.layers.keys()) == ["background", "foreground"]
+    assert loaded.layers["background"] == font.layers["background"]
+    assert loaded.layers["foreground"] == font.layers["foreground"]
+    assert set(loaded.keys()) == set(font.layers["background"].keys())
+
+    # The default layer is loaded even when the layers listed leave it out.
+    only = iondrive.load(
+        ufoLib2.objects, MUTATOR, layers=[], default_layer_name="background"
+    )
+    assert list(only.layers.keys()) == ["background", "foreground"]
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    saved = ufoLib2.Font.open(tmp_path / "Saved.ufo")
+    assert saved.layers.defaultLayer.name == "background"
+    assert saved.layers["foreground"] == font.layers["foreground"]
+
+
+def test_default_layer_name_missing() -> None:
+    with pytest.raises(iondrive.IondriveError, match="missing layer 'nonexistent'"):
+        iondrive.load(ufoLib2.objects, MUTATOR, default_layer_name="nonexistent")
+
+
+def test_glyph_names() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+
+    assert iondrive.glyph_names(MUTATOR) == sorted(font.keys())
+    assert iondrive.glyph_names(MUTATOR, "background") == sorted(
+        font.layers["background"].keys()
+    )
+    with pytest.raises(KeyError):
+        iondrive.glyph_names(MUTATOR, "nonexistent")
+
+
+def test_advance_widths() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+
+    widths = iondrive.advance_widths(MUTATOR)
+    assert widths == {glyph.name: glyph.width for glyph in font}
+    assert list(widths) == sorted(font.keys())
+    background = font.layers["background"]
+    assert iondrive.advance_widths(MUTATOR, "background") == {
+        glyph.name: glyph.width for glyph in background
+    }
+    with pytest.raises(KeyError):
+        iondrive.advance_widths(MUTATOR, "nonexistent")
+
+
+def test_advance_widths_without_advance(tmp_path: Path) -> None:
+    ufo = tmp_path / MUTATOR.name
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    glif = ufo / "glyphs" / "A_.glif"
+    text = glif.read_text()
+    start = text.index("<advance")
+    glif.write_text(text[:start] + text[text.index(">", start) + 1 :])
+    (ufo / "glyphs" / "B_.glif").write_text(
+        '<?xml version="1.0" encoding="UTF-8"?>\n'
+        '<glyph name="B" format="2">\n'
+        "  <!-- <advance width='1'/> -->\n"
+        '  <advance height="500" width="612.5"/>\n'
+        "</glyph>\n"
+    )
+
+    widths = iondrive.advance_widths(ufo)
+    assert widths["A"] == 0
+    assert widths["B"] == 612.5
+
+    (ufo / "glyphs" / "B_.glif").write_text('<glyph name="B"><advance width="x"/></glyph>')
+    with pytest.raises(iondrive.IondriveParseError, match="glyphs/B_.glif"):
+        iondrive.advance_widths(ufo)
+
+
+def test_read_options() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, read={"layers", "data", "images"})
+    assert not loaded.lib
+    assert not loaded.groups
+    assert not loaded.kerning
+    assert loaded.features.text == ""
+    assert loaded.info == font.info
+    assert loaded.layers.keys() == font.layers.keys()
+    assert loaded["A"].lib == font["A"].lib
+
+    no_layers = iondrive.load(ufoLib2.objects, MUTATOR, read=["groups", "kerning"])
+    assert list(no_layers.layers.keys()) == ["public.default"]
+    assert not no_layers.keys()
+    assert no_layers.kerning == font.kerning
+
+    with pytest.raises(iondrive.IondriveError, match="unknown part 'glyphs'"):
+        iondrive.load(ufoLib2.objects, MUTATOR, read=["glyphs"])
+
+
+def test_read_options_cached() -> None:
+    without_groups = iondrive.load(
+        ufoLib2.objects, MUTATOR, use_cache=True, read={"layers", "kerning"}
+    )
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, use_cache=True)
+
+    assert not without_groups.groups
+    assert loaded.groups == ufoLib2.Font.open(MUTATOR).groups
+
+
+def test_read_stores_options(ufo_with_data: Path) -> None:
+    loaded = iondrive.load(ufoLib2.objects, ufo_with_data, read=["images"])
+
+    assert not loaded.data.keys()
+    assert not iondrive.load(ufoLib2.objects, ufo_with_data, read=["data"]).images
+
+
+def test_iter_glyphs() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    glyphs = iondrive.iter_glyphs(ufoLib2.objects, MUTATOR)
+
+    assert iter(glyphs) is glyphs
+    assert [glyph.name for glyph in glyphs] == sorted(font.keys())
+    with pytest.raises(StopIteration):
+        next(glyphs)
+    for glyph in iondrive.iter_glyphs(ufoLib2.objects, MUTATOR, "background"):
+        assert glyph == font.layers["background"][glyph.name]
+    with pytest.raises(KeyError):
+        iondrive.iter_glyphs(ufoLib2.objects, MUTATOR, "nonexistent")
+
+
+def test_glyph_names_bad_contents(tmp_path: Path) -> None:
+    ufo = tmp_path / "Broken.ufo"
+    ufoLib2.Font().save(ufo)
+    (ufo / "glyphs" / "contents.plist").write_text("<plist><dict>")
+
+    with pytest.raises(iondrive.IondriveError):
+        iondrive.glyph_names(ufo)
+
+    (ufo / "glyphs" / "contents.plist").unlink()
+
+    with pytest.raises(iondrive.IondriveError, match="contents.plist"):
+        iondrive.glyph_names(ufo)
+
+
+def test_layer_color_and_lib(tmp_path: Path) -> None:
+    font = ufoLib2.Font()
+    font.layers.defaultLayer.color = "0,0,0,1"
+    font.layers.defaultLayer.lib["com.example.default"] = 1
+    for index, name in enumerate(["background", "mark.red", "mark.blue"]):
+        layer = font.newLayer(name)
+        layer.color = f"{index / 4},0.5,1,1"
+        layer.lib["com.example.z"] = name
+        layer.lib["com.example.a"] = [index]
+        layer.lib["com.example.m"] = {"index": index}
+    font.save(tmp_path / "Layers.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Layers.ufo")
+    reference = ufoLib2.Font.open(tmp_path / "Layers.ufo")
+
+    assert list(loaded.layers.keys()) == list(font.layers.keys())
+    for layer in reference.layers:
+        assert loaded.layers[layer.name].color == layer.color
+        assert loaded.layers[layer.name].lib == layer.lib
+        assert list(loaded.layers[layer.name].lib) == list(layer.lib)
+
+
+def test_load_without_lib() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    assert font.lib and any(glyph.lib for glyph in font)
+    loaded = iondrive.load(ufoLib2.objects, MUTATOR, include_lib=False)
+
+    assert loaded.lib == {}
+    for layer in loaded.layers:
+        assert layer.lib == {}
+        for glyph in layer:
+            assert glyph.lib == {}
+            reference = font.layers[layer.name][glyph.name]
+            assert glyph.contours == reference.contours
+            assert glyph.anchors == reference.anchors
+    assert loaded.info == font.info
+    assert loaded.kerning == font.kerning
+
+
+def test_load_without_lib_cached() -> None:
+    iondrive.clear_cache()
+    with_lib = iondrive.load(ufoLib2.objects, MUTATOR, use_cache=True)
+    without_lib = iondrive.load(
+        ufoLib2.objects, MUTATOR, use_cache=True, include_lib=False
+    )
+    assert with_lib.lib and without_lib.lib == {}
+    iondrive.clear_cache()
+
+
+def test_reload_glyph(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    font = iondrive.load(ufoLib2.objects, ufo)
+    order = list(font.keys())
+
+    edited = ufoLib2.Font.open(ufo)
+    edited["A"].width = 1234
+    edited["A"].clearContours()
+    edited.save(ufo, overwrite=True)
+
+    glyph = iondrive.reload_glyph(ufoLib2.objects, font, ufo, "A")
+    assert font["A"] is glyph
+    assert glyph == edited["A"]
+    assert list(font.keys()) == order
+
+
+def test_reload_glyph_added_and_deleted(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    font = iondrive.load(ufoLib2.objects, ufo)
+
+    edited = ufoLib2.Font.open(ufo)
+    edited.newGlyph("new").width = 100
+    del edited["B"]
+    edited.save(ufo, overwrite=True)
+
+    assert iondrive.reload_glyph(ufoLib2.objects, font, ufo, "new").width == 100
+    assert font["new"] == edited["new"]
+    assert iondrive.reload_glyph(ufoLib2.objects, font, ufo, "B") is None
+    assert "B" not in font
+    assert iondrive.reload_glyph(ufoLib2.objects, font, ufo, "missing") is None
+
+
+def test_reload_glyph_in_layer(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    font = iondrive.load(ufoLib2.objects, ufo)
+    default_a = font["A"]
+
+    edited = ufoLib2.Font.open(ufo)
+    edited.layers["background"]["S.closed"].width = 77
+    edited.save(ufo, overwrite=True)
+
+    glyph = iondrive.reload_glyph(ufoLib2.objects, font, ufo, "S.closed", "background")
+    assert font.layers["background"]["S.closed"] is glyph
+    assert glyph.width == 77
+    assert font["A"] is default_a
+
+    with pytest.raises(KeyError):
+        iondrive.reload_glyph(ufoLib2.objects, font, ufo, "A", "nonexistent")
+
+
+def test_save_glyph(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    b_glif = (ufo / "glyphs" / "B_.glif").read_bytes()
+    glyph = iondrive.load_glyph(ufoLib2.objects, ufo, "A")
+    glyph.width = 1234
+
+    iondrive.save_glyph(ufoLib2.objects, ufo, glyph)
+
+    reloaded = ufoLib2.Font.open(ufo)
+    assert reloaded["A"] == glyph
+    assert reloaded.keys() == ufoLib2.Font.open(MUTATOR).keys()
+    assert (ufo / "glyphs" / "B_.glif").read_bytes() == b_glif
+
+
+def test_save_new_glyph(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+
+    iondrive.save_glyph(ufoLib2.objects, ufo, ufoLib2.objects.Glyph("a.alt", width=10))
+    iondrive.save_glyph(ufoLib2.objects, ufo, ufoLib2.objects.Glyph("A.alt", width=20))
+
+    reloaded = ufoLib2.Font.open(ufo)
+    assert reloaded["a.alt"].width == 10
+    assert reloaded["A.alt"].width == 20
+    with open(ufo / "glyphs" / "contents.plist", "rb") as f:
+        files = [name.lower() for name in plistlib.load(f).values()]
+    assert len(files) == len(set(files))
+
+
+def test_save_glyph_new_layer(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    glyph = iondrive.load_glyph(ufoLib2.objects, ufo, "A")
+
+    iondrive.save_glyph(ufoLib2.objects, ufo, glyph, "sketches")
+    iondrive.save_glyph(ufoLib2.objects, ufo, glyph, "background")
+
+    reloaded = ufoLib2.Font.open(ufo)
+    assert list(reloaded.layers.keys()) == ["foreground", "background", "sketches"]
+    assert list(reloaded.layers["sketches"].keys()) == ["A"]
+    assert reloaded.layers["background"]["A"] == glyph
+
+
+def test_save_glyph_from_threads(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    glyphs = [ufoLib2.objects.Glyph(f"new{i}", width=i) for i in range(50)]
+
+    with ThreadPoolExecutor(max_workers=8) as pool:
+        list(pool.map(lambda g: iondrive.save_glyph(ufoLib2.objects, ufo, g), glyphs))
+
+    reloaded = ufoLib2.Font.open(ufo)
+    assert all(reloaded[g.name].width == g.width for g in glyphs)
+
+
+def test_save_same_glyph_from_threads(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    glyphs = [ufoLib2.objects.Glyph("A", width=i) for i in range(50)]
+
+    with ThreadPoolExecutor(max_workers=8) as pool:
+        list(pool.map(lambda g: iondrive.save_glyph(ufoLib2.objects, ufo, g), glyphs))
+
+    assert ufoLib2.Font.open(ufo)["A"].width in range(50)
+    assert not [p for p in (ufo / "glyphs").iterdir() if p.name.startswith(".")]
+
+
+def test_load_changed(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    cutoff = time.time() - 10
+    for file in ufo.glob("**/*"):
+        os.utime(file, (cutoff - 100, cutoff - 100))
+    (ufo / "glyphs" / "A_.glif").touch()
+    (ufo / "glyphs.background" / "S_.closed.glif").touch()
+
+    font = iondrive.load_changed(ufoLib2.objects, ufo, cutoff)
+
+    assert list(font.keys()) == ["A"]
+    assert list(font.layers["background"].keys()) == ["S.closed"]
+    assert font["A"] == ufoLib2.Font.open(ufo)["A"]
+    assert font.info == ufoLib2.Font.open(ufo).info
+    assert font.kerning == ufoLib2.Font.open(ufo).kerning
+
+
+def test_load_changed_nothing_newer(tmp_path: Path) -> None:
+    font = iondrive.load_changed(ufoLib2.objects, MUTATOR, time.time() + 3600)
+
+    assert len(font) == 0
+    assert font.layers.keys() == ufoLib2.Font.open(MUTATOR).layers.keys()
+    assert font.info.familyName == "MutatorMathTest"
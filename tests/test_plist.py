@@ -0,0 +1,190 @@
+import datetime
+import plistlib
+from pathlib import Path
+from typing import Any, Dict
+
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+BLOB = bytes(range(256))
+DATE = datetime.datetime(2021, 3, 4, 5, 6, 7)
+
+
+def font_with_lib(tmp_path: Path) -> Path:
+    """A copy of Mutator Sans with `<data>` and `<date>` values in its font lib
+    and in the lib of glyph A."""
+    font = ufoLib2.Font.open(MUTATOR)
+    values = {"com.example.blob": BLOB, "com.example.date": DATE}
+    font.lib.update(values)
+    font["A"].lib.update(values)
+    ufo = tmp_path / "PlistTypes.ufo"
+    font.save(ufo)
+    assert b"<data>" in (ufo / "lib.plist").read_bytes()
+    assert b"<date>" in (ufo / "lib.plist").read_bytes()
+    return ufo
+
+
+def test_data_is_bytes(tmp_path: Path) -> None:
+    loaded = iondrive.load(ufoLib2.objects, font_with_lib(tmp_path))
+
+    for lib in (loaded.lib, loaded["A"].lib):
+        assert type(lib["com.example.blob"]) is bytes
+        assert lib["com.example.blob"] == BLOB
+
+
+def test_date_is_datetime(tmp_path: Path) -> None:
+    ufo = font_with_lib(tmp_path)
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+
+    for lib in (loaded.lib, loaded["A"].lib):
+        assert type(lib["com.example.date"]) is datetime.datetime
+        assert lib["com.example.date"] == DATE
+    with open(ufo / "lib.plist", "rb") as f:
+        assert loaded.lib == plistlib.load(f)
+
+
+def test_date_round_trip(tmp_path: Path) -> None:
+    loaded = iondrive.load(ufoLib2.objects, font_with_lib(tmp_path))
+    loaded.lib["com.example.aware"] = datetime.datetime(
+        2021, 3, 4, 7, 6, 7, tzinfo=datetime.timezone(datetime.timedelta(hours=2))
+    )
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+
+    reloaded = iondrive.load(ufoLib2.objects, tmp_path / "Saved.ufo")
+    date = reloaded.lib["com.example.date"]
+    assert type(date) is datetime.datetime
+    assert date.tzinfo is None
+    assert date == DATE
+    # Aware datetimes are stored in UTC and come back naive.
+    assert reloaded.lib["com.example.aware"] == DATE.replace(hour=5)
+    assert reloaded["A"].lib["com.example.date"] == DATE
+
+
+def test_head_created_stays_a_string() -> None:
+    # fontinfo.plist stores openTypeHeadCreated as a string, not a <date>, and
+    # ufoLib2 keeps it one.
+    full_info = Path("tests/data/FullInfo.ufo")
+    font = ufoLib2.Font.open(full_info)
+    loaded = iondrive.load(ufoLib2.objects, full_info)
+    assert type(loaded.info.openTypeHeadCreated) is str
+    assert loaded.info.openTypeHeadCreated == font.info.openTypeHeadCreated
+
+
+def test_number_types(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    numbers = {
+        "someInt": 400,
+        "someFloat": 400.0,
+        "someFraction": 0.5,
+        "negative": -12,
+        "large": 2**64 - 1,
+        "nested": {"values": [1, 1.0, 2]},
+    }
+    font.lib.update(numbers)
+    font["A"].lib.update(numbers)
+    font.save(tmp_path / "Numbers.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Numbers.ufo")
+    for lib in (loaded.lib, loaded["A"].lib):
+        assert type(lib["someInt"]) is int
+        assert type(lib["someFloat"]) is float
+        assert type(lib["someFraction"]) is float
+        assert type(lib["negative"]) is int
+        assert lib["large"] == 2**64 - 1
+        assert [type(v) for v in lib["nested"]["values"]] == [int, float, int]
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    with open(tmp_path / "Saved.ufo" / "lib.plist", "rb") as f:
+        saved = plistlib.load(f)
+    for key, value in numbers.items():
+        assert saved[key] == value
+    assert type(saved["someInt"]) is int
+    assert type(saved["someFloat"]) is float
+
+
+INTERPOLATION_LIB = {
+    "public.markColor": "1,0,0,1",
+    "com.schriftgestaltung.Glyphs.lastChange": "2021-03-04 05:06:07 +0000",
+    "com.example.masters": [
+        {"name": "Light", "location": {"wght": 300}, "tags": ["a", {"b": [1, 2.5]}]},
+        {"name": "Bold", "location": {"wght": 700}, "tags": []},
+    ],
+}
+
+
+def test_nested_glyph_lib(tmp_path: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    font["A"].lib.update(INTERPOLATION_LIB)
+    font.save(tmp_path / "Nested.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "Nested.ufo")
+    with open(tmp_path / "Nested.ufo" / "glyphs" / "A_.glif", "rb") as f:
+        assert b"com.example.masters" in f.read()
+    assert loaded["A"].lib == ufoLib2.Font.open(tmp_path / "Nested.ufo")["A"].lib
+    for key, value in INTERPOLATION_LIB.items():
+        assert loaded["A"].lib[key] == value
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    assert ufoLib2.Font.open(tmp_path / "Saved.ufo")["A"].lib == loaded["A"].lib
+
+
+def test_object_libs(tmp_path: Path) -> None:
+    # norad keeps the libs in a glyph's public.objectLibs on the objects they
+    # belong to, but ufoLib2 keeps them in the glyph lib.
+    font = ufoLib2.Font.open(MUTATOR)
+    glyph = font["A"]
+    glyph.contours[0].points[0].identifier = "point-0"
+    glyph.lib["public.objectLibs"] = {"point-0": {"com.example.nested": [{"a": 1}]}}
+    font.save(tmp_path / "ObjectLibs.ufo")
+
+    loaded = iondrive.load(ufoLib2.objects, tmp_path / "ObjectLibs.ufo")
+    assert loaded["A"].lib == ufoLib2.Font.open(tmp_path / "ObjectLibs.ufo")["A"].lib
+    assert loaded["A"].lib["public.objectLibs"] == glyph.lib["public.objectLibs"]
+
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    saved = ufoLib2.Font.open(tmp_path / "Saved.ufo")["A"]
+    assert saved.lib["public.objectLibs"] == glyph.lib["public.objectLibs"]
+    assert saved.contours[0].points[0].identifier == "point-0"
+
+
+HINTED_GLIF = Path("tests/data/hinted.glif")
+
+
+def typed(value: Any) -> Any:
+    """`value` with every leaf paired with its type, so that `1` and `1.0` or
+    `0` and `False` compare unequal."""
+    if isinstance(value, dict):
+        return {key: typed(v) for key, v in value.items()}
+    if isinstance(value, list):
+        return [typed(v) for v in value]
+    return (type(value), value)
+
+
+def hinted_lib() -> Dict[str, Any]:
+    """The lib of the hinted glyph, as plistlib reads it."""
+    glif = HINTED_GLIF.read_bytes()
+    lib = glif[glif.index(b"<lib>") + 5 : glif.index(b"</lib>")]
+    return plistlib.loads(b'<plist version="1.0">' + lib + b"</plist>")
+
+
+def test_postscript_hints(tmp_path: Path) -> None:
+    expected = typed(hinted_lib())
+    ufo = tmp_path / "Hinted.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    (ufo / "glyphs" / "I_.glif").write_bytes(HINTED_GLIF.read_bytes())
+
+    glyph = iondrive.parse_glif(ufoLib2.objects, HINTED_GLIF.read_text())
+    assert typed(glyph.lib) == expected
+    reparsed = iondrive.parse_glif(ufoLib2.objects, iondrive.dump_glif(glyph))
+    assert typed(reparsed.lib) == expected
+
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+    assert typed(loaded["I"].lib) == expected
+    packed = iondrive.load(ufoLib2.objects, ufo, as_bytes=True)
+    assert typed(iondrive.from_bytes(ufoLib2.objects, packed)["I"].lib) == expected
+    assert typed(iondrive.load_raw(ufo)["layers"][0]["glyphs"]["I"]["lib"]) == expected
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Saved.ufo")
+    assert typed(ufoLib2.Font.open(tmp_path / "Saved.ufo")["I"].lib) == expected
@@ -0,0 +1,41 @@
+import threading
+from pathlib import Path
+from typing import List, Tuple
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+NOTO = Path("tests/data/NotoSans-Regular.ufo")
+
+
+def test_progress() -> None:
+    calls: List[Tuple[int, int]] = []
+    threads = set()
+
+    def progress(done: int, total: int) -> None:
+        calls.append((done, total))
+        threads.add(threading.get_ident())
+
+    font = iondrive.load(ufoLib2.objects, NOTO, progress=progress)
+
+    total = sum(len(layer) for layer in font.layers)
+    assert calls[0] == (0, total)
+    assert calls[-1] == (total, total)
+    assert len(calls) < total
+    assert [done for done, _ in calls] == sorted(done for done, _ in calls)
+    assert threads == {threading.get_ident()}
+    assert font.layers.keys() == iondrive.load(ufoLib2.objects, NOTO).layers.keys()
+
+
+def test_progress_raises() -> None:
+    class Stop(Exception):
+        pass
+
+    def progress(done: int, total: int) -> None:
+        if done > 0:
+            raise Stop()
+
+    with pytest.raises(Stop):
+        iondrive.load(ufoLib2.objects, NOTO, progress=progress)
@@ -0,0 +1,127 @@
+import plistlib
+from pathlib import Path
+from typing import Any, Dict
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+FULL_INFO = Path("tests/data/FullInfo.ufo")
+
+
+def raw_glyph(glyph: ufoLib2.objects.Glyph) -> Dict[str, Any]:
+    """The glyph dict `load_raw` gives for `glyph`, built from its attributes."""
+
+    def without_none(**items: Any) -> Dict[str, Any]:
+        return {key: value for key, value in items.items() if value is not None}
+
+    raw = without_none(
+        name=glyph.name, width=glyph.width, height=glyph.height, note=glyph.note
+    )
+    raw["unicodes"] = glyph.unicodes
+    raw["lib"] = dict(glyph.lib)
+    raw["anchors"] = [
+        without_none(
+            x=a.x, y=a.y, name=a.name, color=a.color, identifier=a.identifier
+        )
+        for a in glyph.anchors
+    ]
+    raw["guidelines"] = [
+        without_none(
+            x=g.x,
+            y=g.y,
+            angle=g.angle,
+            name=g.name,
+            color=g.color,
+            identifier=g.identifier,
+        )
+        for g in glyph.guidelines
+    ]
+    raw["contours"] = [
+        {
+            **without_none(identifier=c.identifier),
+            "points": [
+                without_none(
+                    x=p.x,
+                    y=p.y,
+                    type=p.type,
+                    smooth=p.smooth,
+                    name=p.name,
+                    identifier=p.identifier,
+                )
+                for p in c.points
+            ],
+        }
+        for c in glyph.contours
+    ]
+    raw["components"] = [
+        without_none(
+            baseGlyph=c.baseGlyph,
+            transformation=list(c.transformation),
+            identifier=c.identifier,
+        )
+        for c in glyph.components
+    ]
+    if glyph.image.fileName is not None:
+        image = glyph.image
+        xScale, xyScale, yxScale, yScale, xOffset, yOffset = image.transformation
+        raw["image"] = without_none(
+            fileName=image.fileName,
+            xScale=xScale,
+            xyScale=xyScale,
+            yxScale=yxScale,
+            yScale=yScale,
+            xOffset=xOffset,
+            yOffset=yOffset,
+            color=image.color,
+        )
+    return raw
+
+
+def read_plist(path: Path) -> Any:
+    if not path.exists():
+        return {}
+    with open(path, "rb") as f:
+        return plistlib.load(f)
+
+
+@pytest.mark.parametrize("path", [MUTATOR, FULL_INFO])
+def test_load_raw(path: Path) -> None:
+    font = ufoLib2.Font.open(path)
+    raw = iondrive.load_raw(path)
+
+    assert [layer["name"] for layer in raw["layers"]] == list(font.layers.keys())
+    assert raw["layers"][0]["name"] == font.layers.defaultLayer.name
+    for raw_layer in raw["layers"]:
+        layer = font.layers[raw_layer["name"]]
+        assert raw_layer.get("color") == layer.color
+        assert raw_layer["lib"] == layer.lib
+        assert list(raw_layer["glyphs"]) == sorted(layer.keys())
+        for name, raw_glyph_dict in raw_layer["glyphs"].items():
+            assert raw_glyph_dict == raw_glyph(layer[name])
+
+    assert raw["info"] == read_plist(path / "fontinfo.plist")
+    assert raw["groups"] == read_plist(path / "groups.plist")
+    assert raw["kerning"] == read_plist(path / "kerning.plist")
+    assert raw["lib"] == read_plist(path / "lib.plist")
+    assert raw["features"] == font.features.text
+
+
+def test_load_raw_stores(ufo_with_data: Path) -> None:
+    raw = iondrive.load_raw(ufo_with_data)
+
+    assert raw["data"] == {
+        "com.example/foo.bin": b"\x00\x01\x02binary",
+        "manifest.txt": b"build 42\n",
+    }
+    assert raw["images"] == {}
+
+
+def test_load_raw_ufoz(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Mutator.ufoz")
+
+    assert iondrive.load_raw(tmp_path / "Mutator.ufoz") == iondrive.load_raw(MUTATOR)
@@ -0,0 +1,130 @@
+import plistlib
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+from test_ufoz import make_ufoz
+
+
+def test_reader_partial_reads() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    reader = iondrive.UfoReader(ufoLib2.objects, MUTATOR)
+
+    assert reader.format_version == 3
+    assert reader.layer_names() == list(font.layers.keys())
+    assert reader.glyph_names() == sorted(font.keys())
+    assert reader.glyph_names("background") == sorted(font.layers["background"].keys())
+    assert reader.glyph("A") == font["A"]
+    assert reader.glyph("S.closed", "background") == font.layers["background"]["S.closed"]
+    assert reader.layer() == font.layers.defaultLayer
+    assert reader.layer("background") == font.layers["background"]
+    assert reader.info() == font.info
+    assert reader.kerning() == font.kerning
+    assert type(reader.kerning()) is type(font.kerning)
+    assert reader.groups() == font.groups
+
+
+def test_reader_missing() -> None:
+    reader = iondrive.UfoReader(ufoLib2.objects, MUTATOR)
+
+    with pytest.raises(KeyError):
+        reader.glyph("nonexistent")
+    with pytest.raises(KeyError):
+        reader.glyph("A", "nonexistent")
+    with pytest.raises(KeyError):
+        reader.layer("nonexistent")
+    with pytest.raises(KeyError):
+        reader.glyph_names("nonexistent")
+    with pytest.raises(iondrive.IondriveIOError):
+        iondrive.UfoReader(ufoLib2.objects, "nonexistent.ufo")
+
+
+def test_reader_indexes_once(tmp_path: Path) -> None:
+    ufo = tmp_path / "Mutator.ufo"
+    ufoLib2.Font.open(MUTATOR).save(ufo)
+    reader = iondrive.UfoReader(ufoLib2.objects, ufo)
+
+    edited = ufoLib2.Font.open(ufo)
+    edited.newGlyph("new")
+    edited.save(ufo, overwrite=True)
+
+    assert "new" not in reader.glyph_names()
+    assert "new" in iondrive.UfoReader(ufoLib2.objects, ufo).glyph_names()
+
+
+def test_reader_ufo2() -> None:
+    reader = iondrive.UfoReader(ufoLib2.objects, Path("tests/data/UFO2.ufo"))
+
+    assert reader.format_version == 2
+    assert "public.kern1.round" in reader.groups()
+    assert reader.kerning()[("public.kern1.round", "public.kern2.round")] == -10
+
+
+def test_reader_context_manager() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    with iondrive.UfoReader(ufoLib2.objects, MUTATOR) as reader:
+        assert not reader.closed
+        assert reader.glyph("A") == font["A"]
+    assert reader.closed
+
+    with pytest.raises(iondrive.IondriveError, match="closed"):
+        reader.glyph("A")
+    with pytest.raises(iondrive.IondriveError, match="closed"):
+        reader.info()
+    with pytest.raises(iondrive.IondriveError, match="closed"):
+        reader.layer_names()
+    with pytest.raises(iondrive.IondriveError, match="closed"):
+        with reader:
+            pass
+    reader.close()
+
+
+def test_reader_ufoz_cleanup(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    ufoz = make_ufoz(MUTATOR, tmp_path)
+    temp = tmp_path / "temp"
+    temp.mkdir()
+    monkeypatch.setattr("tempfile.tempdir", str(temp))
+    font = ufoLib2.Font.open(MUTATOR)
+
+    with iondrive.UfoReader(ufoLib2.objects, ufoz) as reader:
+        assert len(list(temp.iterdir())) == 1
+        assert reader.glyph("A") == font["A"]
+        assert reader.layer("background") == font.layers["background"]
+        assert reader.kerning() == font.kerning
+    assert list(temp.iterdir()) == []
+
+    reader = iondrive.UfoReader(ufoLib2.objects, ufoz)
+    reader.close()
+    assert list(temp.iterdir()) == []
+
+
+def test_load_metainfo(tmp_path: Path) -> None:
+    expected = {"formatVersion": 3, "creator": "org.linebender.norad"}
+    assert iondrive.load_metainfo(MUTATOR) == expected
+    assert iondrive.load_metainfo(make_ufoz(MUTATOR, tmp_path)) == expected
+    assert iondrive.load_metainfo(Path("tests/data/UFO2.ufo")) == {
+        "formatVersion": 2,
+        "creator": "com.github.fonttools.ufoLib",
+    }
+
+    ufo = tmp_path / "NoCreator.ufo"
+    ufo.mkdir()
+    (ufo / "metainfo.plist").write_bytes(plistlib.dumps({"formatVersion": 4}))
+    assert iondrive.load_metainfo(ufo) == {"formatVersion": 4, "creator": None}
+
+
+def test_load_metainfo_missing(tmp_path: Path) -> None:
+    ufo = tmp_path / "Empty.ufo"
+    ufo.mkdir()
+    with pytest.raises(iondrive.IondriveIOError, match="metainfo.plist"):
+        iondrive.load_metainfo(ufo)
+    with pytest.raises(iondrive.IondriveIOError, match="metainfo.plist"):
+        iondrive.load_metainfo(make_ufoz(ufo, tmp_path))
+
+    (ufo / "metainfo.plist").write_bytes(plistlib.dumps({"creator": "me"}))
+    with pytest.raises(iondrive.IondriveParseError, match="has no formatVersion"):
+        iondrive.load_metainfo(ufo)
@@ -0,0 +1,176 @@
+import plistlib
+from pathlib import Path
+from typing import Dict
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from test_equivalence import UFOS
+
+
+@pytest.mark.parametrize("path", UFOS, ids=lambda p: p.name)
+def test_roundtrip(path: Path, tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, path)
+    iondrive.save(ufoLib2.objects, font, tmp_path / path.name)
+    reloaded = iondrive.load(ufoLib2.objects, tmp_path / path.name)
+
+    assert font.lib == reloaded.lib
+    assert font.groups == reloaded.groups
+    assert font.kerning == reloaded.kerning
+    assert font.features == reloaded.features
+    assert font.info == reloaded.info
+    assert font.layers.defaultLayer.name == reloaded.layers.defaultLayer.name
+    assert font.layers.keys() == reloaded.layers.keys()
+    for layer_name in font.layers.keys():
+        assert font.layers[layer_name] == reloaded.layers[layer_name]
+
+
+def test_malformed_point(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+    font["A"].contours[0].points[0] = object()
+
+    with pytest.raises(iondrive.IondriveError, match="x"):
+        iondrive.save(ufoLib2.objects, font, tmp_path / "Malformed.ufo")
+
+
+def test_font_path(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+
+    assert font.path == UFOS[0]
+    assert isinstance(font.path, Path)
+
+
+def test_save_in_place(tmp_path: Path) -> None:
+    ufo = tmp_path / UFOS[0].name
+    iondrive.save(ufoLib2.objects, iondrive.load(ufoLib2.objects, UFOS[0]), ufo)
+    font = iondrive.load(ufoLib2.objects, ufo)
+    font.info.familyName = "Saved In Place"
+    font.save()
+
+    assert ufoLib2.Font.open(ufo).info.familyName == "Saved In Place"
+
+
+def test_failed_save_keeps_existing(tmp_path: Path) -> None:
+    ufo = tmp_path / UFOS[0].name
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+    iondrive.save(ufoLib2.objects, font, ufo)
+    font.info.familyName = "Half Written"
+    # The data is written last, after the rest of the UFO.
+    font.data["../outside"] = b""
+
+    with pytest.raises(iondrive.IondriveError, match="not a valid data file name"):
+        iondrive.save(ufoLib2.objects, font, ufo)
+
+    assert ufoLib2.Font.open(ufo).info.familyName != "Half Written"
+    assert [p.name for p in tmp_path.iterdir()] == [ufo.name]
+
+
+@pytest.mark.parametrize("name", ["Atomic.ufo", "Atomic.ufoz"])
+def test_save_replaces_existing(tmp_path: Path, name: str) -> None:
+    path = tmp_path / name
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+    iondrive.save(ufoLib2.objects, font, path)
+    font.info.familyName = "Replaced"
+
+    iondrive.save(ufoLib2.objects, font, path)
+
+    assert iondrive.load(ufoLib2.objects, path).info.familyName == "Replaced"
+    assert [p.name for p in tmp_path.iterdir()] == [name]
+
+
+def test_save_not_atomic(tmp_path: Path) -> None:
+    ufo = tmp_path / UFOS[0].name
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+    iondrive.save(ufoLib2.objects, font, ufo, atomic=False)
+    font.info.familyName = "Half Written"
+    font.data["../outside"] = b""
+
+    with pytest.raises(iondrive.IondriveError):
+        iondrive.save(ufoLib2.objects, font, ufo, atomic=False)
+
+    # Written in place, the UFO is left as far as the save got.
+    assert ufoLib2.Font.open(ufo).info.familyName == "Half Written"
+
+
+def ufo_files(ufo: Path) -> Dict[str, bytes]:
+    return {
+        str(path.relative_to(ufo)): path.read_bytes()
+        for path in sorted(ufo.glob("**/*"))
+        if path.is_file()
+    }
+
+
+def test_save_is_reproducible(tmp_path: Path) -> None:
+    lib = {"com.example.b": {"z": 1, "a": [{"y": 2, "x": 3}]}, "com.example.a": 0}
+    fonts = [iondrive.load(ufoLib2.objects, UFOS[0]) for _ in range(2)]
+    for font, items in zip(fonts, [lib.items(), reversed(lib.items())]):
+        for key, value in items:
+            font.lib[key] = value
+            font["A"].lib[key] = value
+            font.layers["background"].lib[key] = value
+    # The second font's libs have their keys in the reverse order.
+    fonts[1].lib["com.example.b"] = {"a": [{"x": 3, "y": 2}], "z": 1}
+
+    for i, font in enumerate(fonts):
+        iondrive.save(ufoLib2.objects, font, tmp_path / f"Saved{i}.ufo")
+    iondrive.save(ufoLib2.objects, fonts[0], tmp_path / "Again.ufo")
+
+    first = ufo_files(tmp_path / "Saved0.ufo")
+    assert first == ufo_files(tmp_path / "Saved1.ufo")
+    assert first == ufo_files(tmp_path / "Again.ufo")
+    lib_plist = first["lib.plist"]
+    assert lib_plist.index(b"com.example.a") < lib_plist.index(b"com.example.b")
+
+
+def test_save_format_2(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0], layers=[])
+    font.info.guidelines = None
+    font.layers.defaultLayer.color = None
+    font.layers.defaultLayer.lib.clear()
+
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo", format_version=2)
+
+    ufo = tmp_path / "Saved.ufo"
+    with open(ufo / "metainfo.plist", "rb") as f:
+        assert plistlib.load(f)["formatVersion"] == 2
+    assert not (ufo / "layercontents.plist").exists()
+    glif = (ufo / "glyphs" / "E_.glif").read_text()
+    assert 'format="1"' in glif
+    assert "<anchor" not in glif
+    reloaded = iondrive.load(ufoLib2.objects, ufo)
+    assert reloaded.info == font.info
+    assert reloaded.groups == font.groups
+    assert reloaded.kerning == font.kerning
+    assert reloaded.features == font.features
+    assert reloaded.lib == font.lib
+    assert list(reloaded.layers.keys()) == ["public.default"]
+    assert reloaded.keys() == font.keys()
+    for glyph in font:
+        assert reloaded[glyph.name] == glyph
+    assert reloaded["E"].anchors == font["E"].anchors
+
+
+def test_save_format_2_layers(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+
+    with pytest.raises(iondrive.IondriveError, match="layer 'background'"):
+        iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo", format_version=2)
+    assert not (tmp_path / "Saved.ufo").exists()
+
+
+def test_save_format_2_guidelines(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0], layers=[])
+    font.layers.defaultLayer.color = None
+    font.layers.defaultLayer.lib.clear()
+
+    with pytest.raises(iondrive.IondriveError, match="fontinfo has guidelines"):
+        iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo", format_version=2)
+
+
+def test_save_unknown_format(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, UFOS[0])
+
+    with pytest.raises(iondrive.IondriveError, match="UFO 1"):
+        iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo", format_version=1)
@@ -0,0 +1,60 @@
+import plistlib
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def test_load_stats() -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+    loaded, stats = iondrive.load(ufoLib2.objects, MUTATOR, stats=True)
+
+    assert isinstance(loaded, ufoLib2.Font)
+    assert loaded.keys() == font.keys()
+    assert set(stats) == {
+        "parse_time",
+        "wrap_time",
+        "glyph_count",
+        "layer_count",
+        "format_version",
+    }
+    assert stats["layer_count"] == len(font.layers)
+    assert stats["glyph_count"] == sum(len(layer) for layer in font.layers)
+    assert stats["parse_time"] > 0
+    assert stats["wrap_time"] > 0
+
+
+def test_load_without_stats() -> None:
+    assert isinstance(iondrive.load(ufoLib2.objects, MUTATOR), ufoLib2.Font)
+    assert isinstance(iondrive.load(ufoLib2.objects, MUTATOR, stats=False), ufoLib2.Font)
+
+
+def test_load_stats_selected_layers() -> None:
+    _, stats = iondrive.load(ufoLib2.objects, MUTATOR, layers=[], stats=True)
+    assert stats["layer_count"] == 1
+
+
+@pytest.fixture
+def minor_version(ufo: Path) -> Path:
+    """The `ufo` with a `formatVersionMinor` of 1 in its `metainfo.plist`."""
+    with open(ufo / "metainfo.plist", "rb") as f:
+        metainfo = plistlib.load(f)
+    metainfo["formatVersionMinor"] = 1
+    with open(ufo / "metainfo.plist", "wb") as f:
+        plistlib.dump(metainfo, f)
+    return ufo
+
+
+@pytest.mark.parametrize("as_bytes", [False, True])
+def test_load_stats_format_version(minor_version: Path, as_bytes: bool) -> None:
+    def format_version(path: Path) -> tuple:
+        _, stats = iondrive.load(ufoLib2.objects, path, stats=True, as_bytes=as_bytes)
+        return stats["format_version"]
+
+    assert format_version(MUTATOR) == (3, 0)
+    assert format_version(minor_version) == (3, 1)
+    assert format_version(Path("tests/data/UFO2.ufo")) == (2, 0)
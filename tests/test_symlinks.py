@@ -0,0 +1,49 @@
+import os
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def symlink(target: Path, link: Path) -> None:
+    """Link `link` to `target`, skipping the test where symbolic links cannot be
+    created, as on Windows without the privilege."""
+    try:
+        os.symlink(target.resolve(), link, target_is_directory=target.is_dir())
+    except OSError as error:
+        pytest.skip(f"cannot create symbolic links: {error}")
+
+
+@pytest.fixture
+def linked_ufo(tmp_path: Path) -> Path:
+    """A copy of MutatorSans whose default layer directory and a data file are
+    symbolic links to outside the UFO."""
+    ufo = tmp_path / "Linked.ufo"
+    shutil.copytree(MUTATOR, ufo)
+    shutil.move(str(ufo / "glyphs"), str(tmp_path / "shared_glyphs"))
+    symlink(tmp_path / "shared_glyphs", ufo / "glyphs")
+    (tmp_path / "shared").mkdir()
+    (tmp_path / "shared" / "build.txt").write_bytes(b"shared\n")
+    (ufo / "data").mkdir()
+    symlink(tmp_path / "shared", ufo / "data" / "com.example")
+    return ufo
+
+
+def test_follows_symlinks(linked_ufo: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, linked_ufo)
+
+    assert font.layers == iondrive.load(ufoLib2.objects, MUTATOR).layers
+    assert font.data["com.example/build.txt"] == b"shared\n"
+    assert iondrive.glyph_names(linked_ufo) == iondrive.glyph_names(MUTATOR)
+
+
+def test_forbid_symlinks(linked_ufo: Path) -> None:
+    with pytest.raises(iondrive.IondriveIOError, match="is a symbolic link"):
+        iondrive.load(ufoLib2.objects, linked_ufo, follow_symlinks=False)
+
+    iondrive.load(ufoLib2.objects, MUTATOR, follow_symlinks=False)
@@ -0,0 +1,44 @@
+from pathlib import Path
+
+import ufoLib2
+
+import iondrive
+
+UFO2 = Path("tests/data/UFO2.ufo")
+
+
+def test_load_ufo2() -> None:
+    font = ufoLib2.Font.open(UFO2)
+    loaded = iondrive.load(ufoLib2.objects, UFO2)
+
+    assert loaded.info == font.info
+    assert loaded.groups == font.groups
+    assert loaded.kerning == font.kerning
+    assert loaded.lib == font.lib
+    assert loaded.layers.keys() == font.layers.keys()
+    assert loaded.layers.defaultLayer.name == font.layers.defaultLayer.name
+    for glyph in font:
+        assert loaded[glyph.name] == glyph
+
+
+def test_ufo2_upconversion() -> None:
+    loaded = iondrive.load(ufoLib2.objects, UFO2)
+
+    assert loaded.groups["public.kern1.round"] == ["O", "D"]
+    assert loaded.groups["public.kern2.round"] == ["O", "C"]
+    assert loaded.kerning[("public.kern1.round", "public.kern2.round")] == -10
+    assert loaded.kerning[("A", "V")] == -60
+
+    # A format 1 glyph stores its anchors as named single-point contours.
+    glyph = loaded["A"]
+    assert [(a.name, a.x, a.y) for a in glyph.anchors] == [("top", 300, 700)]
+    assert len(glyph.contours) == 1
+
+
+def test_save_ufo2_as_ufo3(tmp_path: Path) -> None:
+    loaded = iondrive.load(ufoLib2.objects, UFO2)
+    iondrive.save(ufoLib2.objects, loaded, tmp_path / "Upconverted.ufo")
+
+    reloaded = ufoLib2.Font.open(tmp_path / "Upconverted.ufo")
+    assert reloaded.kerning == loaded.kerning
+    assert reloaded["A"] == loaded["A"]
@@ -0,0 +1,154 @@
+import io
+import pickle
+import shutil
+import zipfile
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+def make_ufoz(ufo: Path, tmp_path: Path) -> Path:
+    archive = shutil.make_archive(
+        str(tmp_path / ufo.stem), "zip", root_dir=ufo.parent, base_dir=ufo.name
+    )
+    ufoz = tmp_path / f"{ufo.stem}.ufoz"
+    Path(archive).rename(ufoz)
+    return ufoz
+
+
+def test_load_ufoz(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+    zipped = iondrive.load(ufoLib2.objects, make_ufoz(MUTATOR, tmp_path))
+
+    assert font.info == zipped.info
+    assert font.kerning == zipped.kerning
+    assert font.layers.keys() == zipped.layers.keys()
+    for layer_name in font.layers.keys():
+        assert font.layers[layer_name] == zipped.layers[layer_name]
+
+
+def test_load_corrupt_ufoz(tmp_path: Path) -> None:
+    path = tmp_path / "Corrupt.ufoz"
+    path.write_bytes(b"not a zip file")
+
+    with pytest.raises(iondrive.IondriveError, match="not a valid .ufoz"):
+        iondrive.load(ufoLib2.objects, path)
+
+
+def test_load_ufoz_without_metainfo(tmp_path: Path) -> None:
+    path = tmp_path / "Empty.ufoz"
+    with zipfile.ZipFile(path, "w") as archive:
+        archive.writestr("Empty.ufo/fontinfo.plist", "")
+
+    with pytest.raises(iondrive.IondriveError, match="metainfo.plist"):
+        iondrive.load(ufoLib2.objects, path)
+
+
+def test_load_bytes(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+    data = make_ufoz(MUTATOR, tmp_path).read_bytes()
+    loaded = iondrive.load_bytes(ufoLib2.objects, data)
+
+    assert font.info == loaded.info
+    assert font.kerning == loaded.kerning
+    for layer_name in font.layers.keys():
+        assert font.layers[layer_name] == loaded.layers[layer_name]
+
+
+def test_load_invalid_bytes() -> None:
+    with pytest.raises(iondrive.IondriveError, match="^<bytes> is not a valid .ufoz"):
+        iondrive.load_bytes(ufoLib2.objects, b"not a zip file")
+
+
+def test_ufoz_font_path(tmp_path: Path) -> None:
+    ufoz = make_ufoz(MUTATOR, tmp_path)
+
+    assert iondrive.load(ufoLib2.objects, ufoz).path == ufoz
+
+
+def test_load_fileobj(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+    data = make_ufoz(MUTATOR, tmp_path).read_bytes()
+    loaded = iondrive.load_fileobj(ufoLib2.objects, io.BytesIO(data))
+
+    assert font.info == loaded.info
+    assert font.kerning == loaded.kerning
+    for layer_name in font.layers.keys():
+        assert font.layers[layer_name] == loaded.layers[layer_name]
+
+
+def test_load_fileobj_invalid() -> None:
+    with pytest.raises(TypeError, match="no read method"):
+        iondrive.load_fileobj(ufoLib2.objects, b"not a file")
+    with pytest.raises(iondrive.IondriveError, match="^<file object> is not a valid"):
+        iondrive.load_fileobj(ufoLib2.objects, io.BytesIO(b"not a zip file"))
+
+
+def test_load_fileobj_invalid_names_file(tmp_path: Path) -> None:
+    path = tmp_path / "Broken.ufoz"
+    path.write_bytes(b"not a zip file")
+
+    with open(path, "rb") as f, pytest.raises(iondrive.IondriveError) as excinfo:
+        iondrive.load_fileobj(ufoLib2.objects, f)
+
+    assert str(excinfo.value).startswith(f"{path} is not a valid .ufoz")
+
+
+def test_save_ufoz(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, make_ufoz(MUTATOR, tmp_path))
+    saved = tmp_path / "Saved.ufoz"
+
+    iondrive.save(ufoLib2.objects, font, saved)
+
+    # The UFO's files are all in one top-level directory, which fontTools'
+    # ufoLib requires.
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Saved.ufo")
+    files = [
+        "Saved.ufo/" + path.relative_to(tmp_path / "Saved.ufo").as_posix()
+        for path in (tmp_path / "Saved.ufo").glob("**/*")
+        if path.is_file()
+    ]
+    with zipfile.ZipFile(saved) as archive:
+        names = archive.namelist()
+    assert sorted(names) == sorted(files)
+    assert ufoLib2.Font.open(saved).layers == font.layers
+    reloaded = iondrive.load(ufoLib2.objects, saved)
+    assert reloaded.info == font.info
+    assert reloaded.kerning == font.kerning
+    assert reloaded.layers == font.layers
+
+
+def test_as_bytes_round_trip() -> None:
+    ubu = Path("tests/data/UbuTestData.ufo")
+    font = iondrive.load(ufoLib2.objects, ubu)
+
+    data = iondrive.load(ufoLib2.objects, ubu, as_bytes=True)
+
+    assert isinstance(data, bytes)
+    rehydrated = iondrive.from_bytes(ufoLib2.objects, pickle.loads(pickle.dumps(data)))
+    assert rehydrated.info == font.info
+    assert rehydrated.kerning == font.kerning
+    assert rehydrated.lib == font.lib
+    assert rehydrated.layers == font.layers
+    assert dict(rehydrated.data.items()) == dict(font.data.items())
+
+
+def test_as_bytes_applies_options() -> None:
+    data = iondrive.load(
+        ufoLib2.objects, MUTATOR, glyph_filter=lambda name: name == "A", as_bytes=True
+    )
+
+    font = iondrive.from_bytes(ufoLib2.objects, data)
+    assert font.keys() == {"A"}
+
+
+def test_from_invalid_bytes() -> None:
+    data = iondrive.load(ufoLib2.objects, MUTATOR, as_bytes=True)
+
+    with pytest.raises(iondrive.IondriveParseError, match="not a font packed"):
+        iondrive.from_bytes(ufoLib2.objects, data[:-1])
@@ -0,0 +1,58 @@
+import plistlib
+import warnings
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+@pytest.fixture
+def ufo(ufo: Path) -> Path:
+    (ufo / "notes.txt").write_text("not part of a UFO")
+    (ufo / "glyphs" / "stray.glif").write_text("")
+    with open(ufo / "glyphs.background" / "layerinfo.plist", "wb") as f:
+        plistlib.dump({"color": "1,0,0,1", "com.example.future": 1}, f)
+    return ufo
+
+
+UNKNOWN = [
+    "file 'notes.txt'",
+    "file 'glyphs/stray.glif'",
+    "glyphs.background/layerinfo.plist key 'com.example.future'",
+]
+
+
+def test_ignore_unknown(ufo: Path) -> None:
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        iondrive.load(ufoLib2.objects, ufo)
+        iondrive.load(ufoLib2.objects, ufo, on_unknown="ignore")
+
+
+def test_warn_unknown(ufo: Path) -> None:
+    with pytest.warns(UserWarning) as record:
+        font = iondrive.load(ufoLib2.objects, ufo, on_unknown="warn")
+
+    messages = [str(w.message) for w in record]
+    assert messages == [f"{ufo}: not loaded: {item}" for item in UNKNOWN]
+    assert font.layers["background"].color == "1,0,0,1"
+
+
+def test_error_unknown(ufo: Path) -> None:
+    with pytest.raises(iondrive.IondriveError, match="not loaded: file 'notes.txt'; "):
+        iondrive.load(ufoLib2.objects, ufo, on_unknown="error")
+
+
+def test_nothing_unknown() -> None:
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        iondrive.load(ufoLib2.objects, MUTATOR, on_unknown="error")
+
+
+def test_invalid_on_unknown() -> None:
+    with pytest.raises(iondrive.IondriveError, match="expected 'ignore'"):
+        iondrive.load(ufoLib2.objects, MUTATOR, on_unknown="raise")
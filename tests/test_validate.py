@@ -0,0 +1,242 @@
+import plistlib
+import shutil
+import warnings
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+
+
+@pytest.fixture
+def broken_glyph(ufo: Path) -> Path:
+    (ufo / "glyphs" / "A_.glif").write_text("not a glif")
+    return ufo
+
+
+def test_validate_by_default(broken_glyph: Path) -> None:
+    with pytest.raises(iondrive.IondriveError, match="A_.glif"):
+        iondrive.load(ufoLib2.objects, broken_glyph)
+
+
+def test_lenient_skips_broken_glyph(broken_glyph: Path) -> None:
+    font = ufoLib2.Font.open(MUTATOR)
+
+    with pytest.warns(UserWarning, match="A_.glif"):
+        loaded = iondrive.load(ufoLib2.objects, broken_glyph, validate=False)
+
+    assert "A" not in loaded
+    assert set(loaded.keys()) == set(font.keys()) - {"A"}
+    assert loaded["B"] == font["B"]
+    assert loaded.layers.keys() == font.layers.keys()
+    assert loaded.kerning == font.kerning
+
+
+def test_lenient_skips_invalid_groups(ufo: Path) -> None:
+    font = ufoLib2.Font.open(ufo)
+    groups = dict(font.groups)
+    groups["public.kern1.first"] = ["A"]
+    groups["public.kern1.second"] = ["A"]
+    # Written directly, as ufoLib2 refuses to save groups like these.
+    with open(ufo / "groups.plist", "wb") as f:
+        plistlib.dump(groups, f)
+
+    with pytest.raises(iondrive.IondriveError):
+        iondrive.load(ufoLib2.objects, ufo)
+
+    with pytest.warns(UserWarning, match="groups and kerning"):
+        loaded = iondrive.load(ufoLib2.objects, ufo, validate=False)
+
+    assert not loaded.groups
+    assert not loaded.kerning
+    assert set(loaded.keys()) == set(font.keys())
+
+
+def test_plists_with_byte_order_mark(ufo: Path) -> None:
+    for name in ("fontinfo.plist", "lib.plist", "groups.plist", "kerning.plist"):
+        (ufo / name).write_bytes(b"\xef\xbb\xbf" + (ufo / name).read_bytes())
+
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+
+    font = ufoLib2.Font.open(MUTATOR)
+    assert loaded.info == font.info
+    assert loaded.lib == font.lib
+    assert loaded.groups == font.groups
+    assert loaded.kerning == font.kerning
+
+
+@pytest.fixture
+def latin1_info(ufo: Path) -> Path:
+    with open(ufo / "fontinfo.plist", "rb") as f:
+        info = plistlib.load(f)
+    info["familyName"] = "Mutat\u00f6r"
+    xml = plistlib.dumps(info).replace(b'encoding="UTF-8"', b'encoding="ISO-8859-1"')
+    (ufo / "fontinfo.plist").write_bytes(xml.decode().encode("latin-1"))
+    return ufo
+
+
+def test_latin1_plist_by_default(latin1_info: Path) -> None:
+    with pytest.raises(iondrive.IondriveParseError):
+        iondrive.load(ufoLib2.objects, latin1_info)
+
+
+def test_lenient_reads_latin1_plist(latin1_info: Path) -> None:
+    with pytest.warns(UserWarning, match="fontinfo.plist, reading it as latin-1"):
+        loaded = iondrive.load(ufoLib2.objects, latin1_info, validate=False)
+
+    assert loaded.info.familyName == "Mutat\u00f6r"
+    assert loaded.info.styleName == ufoLib2.Font.open(MUTATOR).info.styleName
+    assert loaded.kerning == ufoLib2.Font.open(MUTATOR).kerning
+
+
+@pytest.fixture
+def duplicate_glyph(ufo: Path) -> Path:
+    """A copy of Mutator Sans whose `contents.plist` lists glyph A twice, the
+    second time with a file of a different width."""
+    glyphs = ufo / "glyphs"
+    glif = (glyphs / "A_.glif").read_text()
+    (glyphs / "A_copy.glif").write_text(
+        glif.replace('<advance width="740"/>', '<advance width="1234"/>')
+    )
+    # Written directly, as plistlib cannot write a key twice.
+    contents = (glyphs / "contents.plist").read_text()
+    contents = contents.replace(
+        "<string>A_.glif</string>",
+        "<string>A_.glif</string>\n\t<key>A</key>\n\t<string>A_copy.glif</string>",
+        1,
+    )
+    (glyphs / "contents.plist").write_text(contents)
+    return ufo
+
+
+def test_duplicate_glyph_name(duplicate_glyph: Path) -> None:
+    with pytest.raises(iondrive.IondriveStructureError) as info:
+        iondrive.load(ufoLib2.objects, duplicate_glyph)
+
+    message = str(info.value)
+    assert "glyph 'A'" in message
+    assert "glyphs/A_.glif" in message
+    assert "glyphs/A_copy.glif" in message
+
+
+def test_lenient_keeps_first_duplicate(duplicate_glyph: Path) -> None:
+    with pytest.warns(UserWarning, match="glyph 'A' is listed more than once"):
+        loaded = iondrive.load(ufoLib2.objects, duplicate_glyph, validate=False)
+
+    assert loaded["A"] == ufoLib2.Font.open(MUTATOR)["A"]
+
+
+def test_collect_errors(broken_glyph: Path) -> None:
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        loaded, errors = iondrive.load(
+            ufoLib2.objects, broken_glyph, collect_errors=True
+        )
+
+    assert "A" not in loaded
+    assert "B" in loaded
+    assert [(e["kind"], e["file"]) for e in errors] == [
+        ("InvalidGlif", "glyphs/A_.glif")
+    ]
+    assert errors[0]["message"]
+
+
+def test_collect_errors_duplicate(duplicate_glyph: Path) -> None:
+    _, errors = iondrive.load(ufoLib2.objects, duplicate_glyph, collect_errors=True)
+
+    assert [(e["kind"], e["file"]) for e in errors] == [
+        ("DuplicateGlyph", "glyphs/contents.plist")
+    ]
+
+
+def test_collect_errors_none() -> None:
+    font, errors = iondrive.load(ufoLib2.objects, MUTATOR, collect_errors=True)
+
+    assert errors == []
+    assert font == ufoLib2.Font.open(MUTATOR)
+
+
+def test_collect_errors_with_stats(broken_glyph: Path) -> None:
+    _, stats, errors = iondrive.load(
+        ufoLib2.objects, broken_glyph, collect_errors=True, stats=True
+    )
+
+    assert stats["layer_count"] == 2
+    assert len(errors) == 1
+
+
+
+def with_contour(tmp_path: Path, types: tuple) -> Path:
+    """A copy of MutatorSans whose glyph A has a valid contour followed by one
+    with points of `types`, `None` being an off-curve point."""
+    ufo = tmp_path / MUTATOR.name
+    shutil.copytree(MUTATOR, ufo)
+    points = "".join(
+        f'      <point x="{x}" y="0" type="{t}"/>\n' if t else f'      <point x="{x}" y="0"/>\n'
+        for x, t in enumerate(types)
+    )
+    (ufo / "glyphs" / "A_.glif").write_text(
+        '<?xml version="1.0" encoding="UTF-8"?>\n'
+        '<glyph name="A" format="2">\n'
+        '  <advance width="500"/>\n'
+        "  <outline>\n"
+        "    <contour>\n"
+        '      <point x="0" y="0" type="line"/>\n'
+        '      <point x="0" y="10" type="line"/>\n'
+        "    </contour>\n"
+        f"    <contour>\n{points}    </contour>\n"
+        "  </outline>\n"
+        "</glyph>\n"
+    )
+    return ufo
+
+
+INVALID_POINT_TYPES = [
+    ("line", "move", "line"),
+    ("line", None, "line"),
+    ("line", None, None, None, "curve"),
+    ("move", "line", None),
+    (None, "line", "line"),
+]
+
+
+@pytest.mark.parametrize("types", INVALID_POINT_TYPES)
+def test_invalid_point_types(tmp_path: Path, types: tuple) -> None:
+    ufo = with_contour(tmp_path, types)
+
+    # norad checks the point types as it parses, so these are parse errors.
+    with pytest.raises(
+        iondrive.IondriveParseError, match=r"^glyphs/A_\.glif:\d+: glyph 'A', contour 1: "
+    ):
+        iondrive.load(ufoLib2.objects, ufo)
+
+
+@pytest.mark.parametrize("types", INVALID_POINT_TYPES)
+def test_lenient_skips_invalid_point_types(tmp_path: Path, types: tuple) -> None:
+    ufo = with_contour(tmp_path, types)
+
+    with pytest.warns(UserWarning, match="glyph 'A', contour 1"):
+        loaded = iondrive.load(ufoLib2.objects, ufo, validate=False)
+
+    assert "A" not in loaded
+
+
+@pytest.mark.parametrize(
+    "types",
+    [
+        ("move", "line", "line"),
+        ("move", None, None, "curve"),
+        ("line", None, None, None, "qcurve"),
+        ("curve", "line", None, None),
+        (None, None, None),
+    ],
+)
+def test_valid_point_types(tmp_path: Path, types: tuple) -> None:
+    ufo = with_contour(tmp_path, types)
+
+    loaded = iondrive.load(ufoLib2.objects, ufo)
+
+    assert [p.segmentType for p in loaded["A"].contours[1]] == list(types)
@@ -0,0 +1,65 @@
+import plistlib
+import shutil
+from pathlib import Path
+
+import pytest
+import ufoLib2
+
+import iondrive
+
+from paths import MUTATOR
+from test_layer_order import LAYER_ORDER
+
+
+@pytest.mark.parametrize("path", [MUTATOR, LAYER_ORDER])
+def test_verify(path: Path) -> None:
+    assert iondrive.verify(path) == []
+
+
+def test_verify_ufoz(tmp_path: Path) -> None:
+    font = iondrive.load(ufoLib2.objects, MUTATOR)
+    iondrive.save(ufoLib2.objects, font, tmp_path / "Mutator.ufoz")
+
+    assert iondrive.verify(tmp_path / "Mutator.ufoz") == []
+
+
+def test_verify_missing_ufo(tmp_path: Path) -> None:
+    (problem,) = iondrive.verify(tmp_path / "Missing.ufo")
+
+    assert problem["kind"] == "MissingUfoDir"
+
+
+def test_verify_problems(ufo: Path) -> None:
+    (ufo / "glyphs" / "A_.glif").write_text("<glyph")
+    (ufo / "glyphs" / "B_.glif").unlink()
+    glif = ufo / "glyphs" / "C_.glif"
+    glif.write_text(
+        glif.read_text().replace("<outline>", '<image fileName="sketch.png"/><outline>')
+    )
+    (ufo / "lib.plist").write_text("<plist")
+    with open(ufo / "kerning.plist", "wb") as f:
+        plistlib.dump([], f)
+    shutil.rmtree(ufo / "glyphs.background")
+
+    problems = {
+        (problem["kind"], problem["file"]) for problem in iondrive.verify(ufo)
+    }
+
+    assert problems == {
+        ("InvalidGlif", "glyphs/A_.glif"),
+        ("MissingFile", "glyphs/contents.plist"),
+        ("MissingFile", "glyphs/C_.glif"),
+        ("InvalidPlist", "lib.plist"),
+        ("ExpectedPlistDictionary", "kerning.plist"),
+        ("MissingFile", "layercontents.plist"),
+    }
+
+
+def test_verify_missing_default_layer(ufo: Path) -> None:
+    with open(ufo / "layercontents.plist", "wb") as f:
+        plistlib.dump([["background", "glyphs.background"]], f)
+
+    (problem,) = iondrive.verify(ufo)
+
+    assert problem["kind"] == "MissingDefaultLayer"
+    assert problem["file"] == "layercontents.plist"